@@ -0,0 +1,210 @@
+use super::json::{Json, ToJson, FromJson};
+use super::game_state::{GameState, GameMove};
+use super::game_logic::{get_possible_moves, make_move};
+use super::search::{Search, TimeControl};
+use super::board_rating;
+use super::cli;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::time::Instant;
+
+//JSON lines analysis service: one request object per line, one reply object per line.
+//Every request has a "cmd", an optional "id" is copied into the reply. Replies carry "ok": true or "ok": false with an "error".
+//Commands (positions and moves as in json.rs):
+//  {"cmd":"position","position":{...},"moves":[...]}  both optional, the standard start with random kraken if no position is given
+//  {"cmd":"legal_moves"}, {"cmd":"make_move","move":{...}}, {"cmd":"evaluate"},
+//  {"cmd":"search","depth":d,"nodes":n,"movetime":ms}  limits as with go in the local protocol, {"cmd":"quit"}
+pub struct AnalysisConfig {
+    //Serve 127.0.0.1:port instead of stdin/stdout
+    pub port: Option<u16>,
+    //Time per search if a request gives no limit
+    pub move_time: u64,
+}
+
+impl AnalysisConfig {
+    pub fn from_args(args: &[String]) -> Result<AnalysisConfig, String> {
+        Ok(AnalysisConfig {
+            port: cli::option::<u16>(args, "--port")?,
+            move_time: cli::option::<u64>(args, "--movetime")?.unwrap_or(1000),
+        })
+    }
+}
+
+pub fn go(config: &AnalysisConfig) -> io::Result<()> {
+    let mut search = Search::new(TimeControl::MoveTime(config.move_time));
+    match config.port {
+        None => {
+            let stdin = io::stdin();
+            serve(stdin.lock(), io::stdout(), &mut search, config.move_time)
+        }
+        Some(port) => {
+            let listener = TcpListener::bind(("127.0.0.1", port))?;
+            eprintln!("Listening on {}", listener.local_addr()?);
+            //One client at a time, each starts from the standard position
+            for stream in listener.incoming() {
+                let stream = stream?;
+                if let Err(e) = serve(BufReader::new(stream.try_clone()?), stream, &mut search, config.move_time) {
+                    eprintln!("Connection failed: {}", e);
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+//Answers requests until the input ends or quit is sent
+pub fn serve<R: BufRead, W: Write>(reader: R, mut writer: W, search: &mut Search, move_time: u64) -> io::Result<()> {
+    let mut game_state = analyzed(GameState::standard());
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = Json::parse(&line);
+        let id = request.as_ref().ok().and_then(|request| request.get("id")).cloned();
+        let quit = request.as_ref().ok().and_then(|request| request.get("cmd")) == Some(&Json::String(String::from("quit")));
+        let mut reply = match request.and_then(|request| handle(&request, &mut game_state, search, move_time)) {
+            Ok(mut members) => {
+                members.insert(0, (String::from("ok"), Json::Bool(true)));
+                members
+            }
+            Err(e) => vec![(String::from("ok"), Json::Bool(false)), (String::from("error"), Json::String(e))],
+        };
+        if let Some(id) = id {
+            reply.insert(0, (String::from("id"), id));
+        }
+        writeln!(writer, "{}", Json::Object(reply))?;
+        writer.flush()?;
+        if quit {
+            break;
+        }
+    }
+    Ok(())
+}
+
+//Sets the game status, so the position knows whether the game is over
+fn analyzed(mut game_state: GameState) -> GameState {
+    let moves = get_possible_moves(&game_state, &game_state.move_color, false);
+    game_state.analyze(&moves);
+    game_state
+}
+
+fn legal_move(game_state: &GameState, json: &Json) -> Result<GameMove, String> {
+    let mv = GameMove::from_json(json)?;
+    if game_state.game_over() {
+        return Err(String::from("The game is over"));
+    }
+    if !get_possible_moves(game_state, &game_state.move_color, false).contains(&mv) {
+        return Err(format!("Illegal move {}", mv));
+    }
+    Ok(mv)
+}
+
+fn handle(request: &Json, game_state: &mut GameState, search: &mut Search, move_time: u64) -> Result<Vec<(String, Json)>, String> {
+    let members = match request.field("cmd")?.as_str()? {
+        "position" => {
+            let mut next = analyzed(match request.get("position") {
+                Some(position) => GameState::from_json(position)?,
+                None => GameState::standard(),
+            });
+            if let Some(moves) = request.get("moves") {
+                for mv in moves.as_array()? {
+                    next = analyzed(make_move(&next, &legal_move(&next, mv)?));
+                }
+            }
+            *game_state = next;
+            vec![("position", game_state.to_json())]
+        }
+        "legal_moves" => {
+            let moves = if game_state.game_over() { Vec::new() } else { get_possible_moves(game_state, &game_state.move_color, false) };
+            vec![("moves", Json::Array(moves.iter().map(|mv| mv.to_json()).collect()))]
+        }
+        "make_move" => {
+            let mv = legal_move(game_state, request.field("move")?)?;
+            *game_state = analyzed(make_move(game_state, &mv));
+            vec![("position", game_state.to_json())]
+        }
+        "evaluate" => {
+            let (red, blue) = board_rating::rating_trace(game_state);
+            vec![("rating", Json::Number(red.total() - blue.total())), ("red", red.to_json()), ("blue", blue.to_json())]
+        }
+        "search" => {
+            if game_state.game_over() {
+                return Err(String::from("The game is over"));
+            }
+            let limit = |name: &str| request.get(name).map(|value| value.as_u64()).transpose();
+            let (depth, nodes, time) = (limit("depth")?, limit("nodes")?, limit("movetime")?);
+            search.tc = match time {
+                Some(time) => TimeControl::MoveTime(time),
+                //Only depth or nodes given: no time limit
+                None if depth.is_some() || nodes.is_some() => TimeControl::Infinite,
+                None => TimeControl::MoveTime(move_time),
+            };
+            search.max_nodes = nodes.map(|nodes| nodes as usize);
+            let before = Instant::now();
+            let pv = search.run(depth.unwrap_or(100).clamp(1, 100) as u8, game_state);
+            search.max_nodes = None;
+            vec![
+                ("best_move", pv.stack[0].to_json()),
+                ("result", pv.to_json()),
+                ("nodes", Json::Number(search.nodes_analyzed as f64)),
+                ("time_ms", Json::Number(before.elapsed().as_millis() as f64)),
+            ]
+        }
+        "quit" => Vec::new(),
+        other => return Err(format!("Unknown command {}", other)),
+    };
+    Ok(members.into_iter().map(|(key, value)| (String::from(key), value)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic;
+
+    #[test]
+    fn analysis_session() -> Result<(), String> {
+        let start = GameState::standard_with_kraken(0u128);
+        let first = game_logic::get_possible_moves(&start, &start.move_color, false)[0];
+        let requests = [
+            format!(r#"{{"id":1,"cmd":"position","position":{},"moves":[{}]}}"#, start.to_json(), first.to_json()),
+            String::from(r#"{"cmd":"legal_moves"}"#),
+            String::from(r#"{"cmd":"evaluate"}"#),
+            String::from(r#"{"id":"s","cmd":"search","depth":2}"#),
+            format!(r#"{{"cmd":"make_move","move":{}}}"#, first.to_json()),
+            String::from("{"),
+            String::from(r#"{"cmd":"quit"}"#),
+            String::from(r#"{"cmd":"legal_moves"}"#),
+        ];
+        let mut search = Search::new(TimeControl::Infinite);
+        search.resize_cache(16);
+        search.use_book = false;
+        let mut output = Vec::new();
+        serve(requests.join("\n").as_bytes(), &mut output, &mut search, 1000).map_err(|e| e.to_string())?;
+        let replies = String::from_utf8(output).map_err(|e| e.to_string())?.lines().map(Json::parse).collect::<Result<Vec<Json>, String>>()?;
+        if replies.len() != 7 {
+            return Err(format!("{} replies to 7 requests before quit", replies.len()));
+        }
+        let ok: Vec<bool> = replies.iter().map(|reply| reply.get("ok") == Some(&Json::Bool(true))).collect();
+        if ok != [true, true, true, true, false, false, true] {
+            return Err(format!("Unexpected successes {:?}", ok));
+        }
+        let after = game_logic::make_move(&start, &first);
+        let position = GameState::from_json(replies[0].field("position")?)?;
+        if replies[0].get("id") != Some(&Json::Number(1.0)) || position.hash != after.hash || position.plies_played != 1 {
+            return Err(format!("Position reply {}", replies[0]));
+        }
+        let moves = replies[1].field("moves")?.as_array()?.iter().map(GameMove::from_json).collect::<Result<Vec<GameMove>, String>>()?;
+        if moves != game_logic::get_possible_moves(&after, &after.move_color, false) {
+            return Err(String::from("Legal moves differ from get_possible_moves"));
+        }
+        if replies[2].field("rating")?.as_f64()? != board_rating::rating(&after, false) {
+            return Err(String::from("Evaluation differs from rating"));
+        }
+        let best = GameMove::from_json(replies[3].field("best_move")?)?;
+        if replies[3].get("id") != Some(&Json::String(String::from("s"))) || !moves.contains(&best) || replies[3].field("result")?.field("depth")?.as_u64()? != 2 {
+            return Err(format!("Search reply {}", replies[3]));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use super::constants;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+//Square index 10 * y + x in engine coordinates. The engine's x runs mirrored to the server's:
+//server (x, y) is engine (9 - x, y). Printed boards show the server's view, row 0 on top is y = 9
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Square(u8);
+
+impl Square {
+    pub fn new(index: u8) -> Option<Square> {
+        if index < 100 { Some(Square(index)) } else { None }
+    }
+
+    //For indices taken from bitboards and moves on hot paths, only debug builds check them
+    #[inline(always)]
+    pub fn from_index(index: u8) -> Square {
+        debug_assert!(index < 100, "Square index {} is not on the board", index);
+        Square(index)
+    }
+
+    //Used by the symmetries on every square of a board, only debug builds check the range
+    #[inline(always)]
+    pub fn from_coordinates(x: u8, y: u8) -> Square {
+        debug_assert!(x < 10 && y < 10, "Square ({},{}) is not on the board", x, y);
+        Square(10 * y + x)
+    }
+
+    //Server coordinates come from outside, None if they are not on the board
+    pub fn from_server(x: u8, y: u8) -> Option<Square> {
+        if x < 10 && y < 10 { Some(Square(10 * y + (9 - x))) } else { None }
+    }
+
+    //Row and column of a printed board
+    pub fn from_grid(row: u8, column: u8) -> Square {
+        assert!(row < 10 && column < 10, "Row {} and column {} are not on the board", row, column);
+        Square(99 - (10 * row + column))
+    }
+
+    #[inline(always)]
+    pub fn x(self) -> u8 {
+        self.0 % 10
+    }
+
+    #[inline(always)]
+    pub fn y(self) -> u8 {
+        self.0 / 10
+    }
+
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    pub fn server_coordinates(self) -> (u8, u8) {
+        (9 - self.x(), self.y())
+    }
+
+    #[inline(always)]
+    pub fn bitboard(self) -> Bitboard {
+        Bitboard(1u128 << self.0)
+    }
+
+    //The next square in one of game_state::DIRECTIONS, None at the edge of the board
+    pub fn step(self, direction: i8) -> Option<Square> {
+        let next = self.0 as i8 + direction;
+        if (0..100).contains(&next) && (next % 10 - self.x() as i8).abs() <= 1 && (next / 10 - self.y() as i8).abs() <= 1 {
+            Some(Square(next as u8))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn neighbours(self) -> Bitboard {
+        Bitboard(constants::NACHBARN[self.index()])
+    }
+}
+
+//Server coordinates, as the game's GUI shows them
+impl Display for Square {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let (x, y) = self.server_coordinates();
+        write!(formatter, "({},{})", x, y)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bitboard(pub u128);
+
+impl Bitboard {
+    pub const EMPTY: Bitboard = Bitboard(0u128);
+
+    #[inline(always)]
+    pub fn contains(self, square: Square) -> bool {
+        self.0 & (1u128 << square.0) != 0u128
+    }
+
+    #[inline(always)]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0u128
+    }
+
+    #[inline(always)]
+    pub fn count(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    #[inline(always)]
+    pub fn lowest(self) -> Option<Square> {
+        if self.is_empty() { None } else { Some(Square(self.0.trailing_zeros() as u8)) }
+    }
+
+    #[inline(always)]
+    pub fn iter(self) -> BitboardIter {
+        BitboardIter(self.0)
+    }
+}
+
+//Set squares from the lowest index up
+pub struct BitboardIter(u128);
+
+impl Iterator for BitboardIter {
+    type Item = Square;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Square> {
+        if self.0 == 0u128 {
+            return None;
+        }
+        let square = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(Square(square))
+    }
+}
+
+impl IntoIterator for Bitboard {
+    type Item = Square;
+    type IntoIter = BitboardIter;
+
+    fn into_iter(self) -> BitboardIter {
+        self.iter()
+    }
+}
+
+impl BitAnd for Bitboard {
+    type Output = Bitboard;
+    fn bitand(self, other: Bitboard) -> Bitboard {
+        Bitboard(self.0 & other.0)
+    }
+}
+
+impl BitOr for Bitboard {
+    type Output = Bitboard;
+    fn bitor(self, other: Bitboard) -> Bitboard {
+        Bitboard(self.0 | other.0)
+    }
+}
+
+impl BitXor for Bitboard {
+    type Output = Bitboard;
+    fn bitxor(self, other: Bitboard) -> Bitboard {
+        Bitboard(self.0 ^ other.0)
+    }
+}
+
+impl Not for Bitboard {
+    type Output = Bitboard;
+    fn not(self) -> Bitboard {
+        Bitboard(!self.0 & constants::BOARD)
+    }
+}
+
+impl BitAndAssign for Bitboard {
+    fn bitand_assign(&mut self, other: Bitboard) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitOrAssign for Bitboard {
+    fn bitor_assign(&mut self, other: Bitboard) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitXorAssign for Bitboard {
+    fn bitxor_assign(&mut self, other: Bitboard) {
+        self.0 ^= other.0;
+    }
+}
+
+impl Display for Bitboard {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        for row in 0..10 {
+            for column in 0..10 {
+                write!(formatter, "{}", if self.contains(Square::from_grid(row, column)) { 'X' } else { '.' })?;
+            }
+            writeln!(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::{GameState, DIRECTIONS};
+
+    #[test]
+    fn square_conventions_agree() -> Result<(), String> {
+        for x in 0..10u8 {
+            for y in 0..10u8 {
+                let square = Square::from_server(x, y).ok_or_else(|| format!("Server ({},{}) is not on the board", x, y))?;
+                if square != Square::from_coordinates(9 - x, y) || square != Square::from_grid(9 - y, x) || square.server_coordinates() != (x, y) {
+                    return Err(format!("Server ({},{}) maps to different squares", x, y));
+                }
+                let stepped = DIRECTIONS.iter().filter_map(|direction| square.step(*direction)).fold(Bitboard::EMPTY, |res, next| res | next.bitboard());
+                if stepped != square.neighbours() {
+                    return Err(format!("Steps from {} differ from its neighbours", square));
+                }
+            }
+        }
+        if Square::from_server(10, 0).is_some() || Square::from_server(0, 10).is_some() || Square::new(100).is_some() || Square::new(99).is_none() {
+            return Err(String::from("Checked constructors accept squares off the board"));
+        }
+        //The standard position has red fish in the server's outer columns and blue fish in the outer rows
+        let standard = GameState::standard_with_kraken(0u128);
+        let red: Vec<(u8, u8)> = Bitboard(standard.rote_fische).iter().map(|square| square.server_coordinates()).collect();
+        let blue: Vec<(u8, u8)> = Bitboard(standard.blaue_fische).iter().map(|square| square.server_coordinates()).collect();
+        if red.len() != 16 || red.iter().any(|(x, y)| (*x != 0 && *x != 9) || *y == 0 || *y == 9) {
+            return Err(format!("Red fish at {:?}", red));
+        }
+        if blue.len() != 16 || blue.iter().any(|(x, y)| (*y != 0 && *y != 9) || *x == 0 || *x == 9) {
+            return Err(format!("Blue fish at {:?}", blue));
+        }
+        let board = Bitboard(standard.rote_fische | standard.blaue_fische);
+        if board.iter().count() != board.count() as usize || board.iter().fold(Bitboard::EMPTY, |res, square| res | square.bitboard()) != board {
+            return Err(String::from("Iterating a bitboard does not give its squares"));
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use super::game_state::{GameState, GameColor};
 use super::game_logic;
 use super::constants::RAND;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 pub const MAX_DIST: f64 = 6.36396103068;
 pub const MID_X: f64 = 4.5;
@@ -34,36 +35,73 @@ pub const DISTANCE_TO_ENEMY: f64 = 0.0;
 pub const DISTANCE_TO_ENEMY_PHASE: f64 = -11.0;
 pub const DISTANCE_TO_ENEMY_NEGPHASE: f64 = 0.0;
 
+//Feature weights, changeable at runtime through set_parameter. They start out as the constants above
+pub const PARAMETER_NAMES: [&str; 21] = [
+    "ANZAHL_FISCHE", "ANZAHL_FISCHE_PHASE", "ANZAHL_FISCHE_NEGPHASE",
+    "DISTANCE_TO_MID", "DISTANCE_TO_MID_PHASE", "DISTANCE_TO_MID_NEGPHASE",
+    "DISTANCE_TO_BIGGEST", "DISTANCE_TO_BIGGEST_PHASE", "DISTANCE_TO_BIGGEST_NEGPHASE",
+    "BIGGEST_SCHWARM", "BIGGEST_SCHWARM_PHASE", "BIGGEST_SCHWARM_NEGPHASE",
+    "ABSOLUT_SCHWARM", "ABSOLUT_SCHWARM_PHASE", "ABSOLUT_SCHWARM_NEGPHASE",
+    "RAND_FISCHE", "RAND_FISCHE_PHASE", "RAND_FISCHE_NEGPHASE",
+    "DISTANCE_TO_ENEMY", "DISTANCE_TO_ENEMY_PHASE", "DISTANCE_TO_ENEMY_NEGPHASE",
+];
+
+static PARAMETERS: [AtomicU64; 21] = [
+    AtomicU64::new(ANZAHL_FISCHE.to_bits()), AtomicU64::new(ANZAHL_FISCHE_PHASE.to_bits()), AtomicU64::new(ANZAHL_FISCHE_NEGPHASE.to_bits()),
+    AtomicU64::new(DISTANCE_TO_MID.to_bits()), AtomicU64::new(DISTANCE_TO_MID_PHASE.to_bits()), AtomicU64::new(DISTANCE_TO_MID_NEGPHASE.to_bits()),
+    AtomicU64::new(DISTANCE_TO_BIGGEST.to_bits()), AtomicU64::new(DISTANCE_TO_BIGGEST_PHASE.to_bits()), AtomicU64::new(DISTANCE_TO_BIGGEST_NEGPHASE.to_bits()),
+    AtomicU64::new(BIGGEST_SCHWARM.to_bits()), AtomicU64::new(BIGGEST_SCHWARM_PHASE.to_bits()), AtomicU64::new(BIGGEST_SCHWARM_NEGPHASE.to_bits()),
+    AtomicU64::new(ABSOLUT_SCHWARM.to_bits()), AtomicU64::new(ABSOLUT_SCHWARM_PHASE.to_bits()), AtomicU64::new(ABSOLUT_SCHWARM_NEGPHASE.to_bits()),
+    AtomicU64::new(RAND_FISCHE.to_bits()), AtomicU64::new(RAND_FISCHE_PHASE.to_bits()), AtomicU64::new(RAND_FISCHE_NEGPHASE.to_bits()),
+    AtomicU64::new(DISTANCE_TO_ENEMY.to_bits()), AtomicU64::new(DISTANCE_TO_ENEMY_PHASE.to_bits()), AtomicU64::new(DISTANCE_TO_ENEMY_NEGPHASE.to_bits()),
+];
+
+#[inline(always)]
+pub fn parameter(index: usize) -> f64 {
+    f64::from_bits(PARAMETERS[index].load(Ordering::Relaxed))
+}
+
+//Returns false if there is no parameter with this name. Cached evaluations have to be cleared afterwards
+pub fn set_parameter(name: &str, value: f64) -> bool {
+    match PARAMETER_NAMES.iter().position(|n| n.eq_ignore_ascii_case(name)) {
+        Some(index) => {
+            PARAMETERS[index].store(value.to_bits(), Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
 pub fn calculate_feature(input: f64, phase: f64, no_phase_feature: f64, phase_feature: f64, neg_phase_feature: f64) -> f64 {
     input * no_phase_feature + input * phase * phase_feature + input * (1.0 - phase) * neg_phase_feature
 }
 
 pub fn anzahl_fische_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, ANZAHL_FISCHE, ANZAHL_FISCHE_PHASE, ANZAHL_FISCHE_NEGPHASE)
+    calculate_feature(input, phase, parameter(0), parameter(1), parameter(2))
 }
 
 pub fn distance_to_mid_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, DISTANCE_TO_MID, DISTANCE_TO_MID_PHASE, DISTANCE_TO_MID_NEGPHASE)
+    calculate_feature(input, phase, parameter(3), parameter(4), parameter(5))
 }
 
 pub fn distance_to_biggest_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, DISTANCE_TO_BIGGEST, DISTANCE_TO_BIGGEST_PHASE, DISTANCE_TO_BIGGEST_NEGPHASE)
+    calculate_feature(input, phase, parameter(6), parameter(7), parameter(8))
 }
 
 pub fn biggest_schwarm_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, BIGGEST_SCHWARM, BIGGEST_SCHWARM_PHASE, BIGGEST_SCHWARM_NEGPHASE)
+    calculate_feature(input, phase, parameter(9), parameter(10), parameter(11))
 }
 
 pub fn absolut_schwarm_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, ABSOLUT_SCHWARM, ABSOLUT_SCHWARM_PHASE, ABSOLUT_SCHWARM_NEGPHASE)
+    calculate_feature(input, phase, parameter(12), parameter(13), parameter(14))
 }
 
 pub fn rand_fische_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, RAND_FISCHE, RAND_FISCHE_PHASE, RAND_FISCHE_NEGPHASE)
+    calculate_feature(input, phase, parameter(15), parameter(16), parameter(17))
 }
 
 pub fn distance_to_enemy_feature(input: f64, phase: f64) -> f64 {
-    calculate_feature(input, phase, DISTANCE_TO_ENEMY, DISTANCE_TO_ENEMY_PHASE, DISTANCE_TO_ENEMY_NEGPHASE)
+    calculate_feature(input, phase, parameter(18), parameter(19), parameter(20))
 }
 
 pub fn distance(x1: f64, x2: f64, y1: f64, y2: f64) -> f64 {
@@ -115,34 +153,84 @@ impl Schwarm {
         let distance_to_mid = distance(average_x, MID_X, average_y, MID_Y);
         Schwarm::new(board, board_size, average_x, average_y, distance_to_mid)
     }
-    pub fn berechne_schwaerme(mut fische: u128) -> (Vec<Schwarm>, Schwarm) {
+    pub fn berechne_schwaerme(fische: u128) -> (Vec<Schwarm>, Schwarm) {
+        Schwarm::aus_schwaermen(&game_logic::Schwaerme::new(fische))
+    }
+
+    pub fn aus_schwaermen(schwaerme: &game_logic::Schwaerme) -> (Vec<Schwarm>, Schwarm) {
         let mut res = Vec::with_capacity(5);
-        let mut biggest_schwarm: Schwarm;
-        biggest_schwarm = Schwarm::make_schwarm(game_logic::get_schwarm_board(fische));
-        fische ^= biggest_schwarm.gebiet;
-        while fische != 0u128 {
-            let board = game_logic::get_schwarm_board(fische);
-            let schwarm = Schwarm::make_schwarm(board);
+        let mut boards = schwaerme.iter();
+        let mut biggest_schwarm: Schwarm = Schwarm::make_schwarm(*boards.next().unwrap_or(&0u128));
+        for board in boards {
+            let schwarm = Schwarm::make_schwarm(*board);
             if biggest_schwarm.size < schwarm.size || biggest_schwarm.size == schwarm.size && schwarm.distance_to_mid < biggest_schwarm.distance_to_mid {
                 res.push(biggest_schwarm);
                 biggest_schwarm = schwarm;
             } else {
                 res.push(schwarm);
             }
-            fische ^= board;
         }
         (res, biggest_schwarm)
     }
 }
 
+//With two equally big swarms at the same distance to the middle the first one found is taken as biggest,
+//so the rating of such positions depends on the square enumeration and is not symmetric
+pub fn biggest_schwarm_is_ambiguous(fische: u128) -> bool {
+    let (schwaerme, biggest) = Schwarm::berechne_schwaerme(fische);
+    schwaerme.iter().any(|s| s.size == biggest.size && (s.distance_to_mid - biggest.distance_to_mid).abs() < 1e-9)
+}
+
 pub fn rating(game_state: &GameState, verbose: bool) -> f64 {
-    let (rote_schwaerme, biggest_roter_schwarm) = Schwarm::berechne_schwaerme(game_state.rote_fische);
-    let (blaue_schwaerme, biggest_blauer_schwarm) = Schwarm::berechne_schwaerme(game_state.blaue_fische);
-    eval(game_state.plies_played as usize, game_state.rote_fische, &rote_schwaerme, &biggest_roter_schwarm, GameColor::Red, &biggest_blauer_schwarm, game_state.blaue_fische.count_ones() as usize, verbose)
-        - eval(game_state.plies_played as usize, game_state.blaue_fische, &blaue_schwaerme, &biggest_blauer_schwarm, GameColor::Blue, &biggest_roter_schwarm, game_state.rote_fische.count_ones() as usize, verbose)
+    let (red, blue) = rating_trace(game_state);
+    if verbose {
+        red.print(GameColor::Red);
+        blue.print(GameColor::Blue);
+    }
+    red.total() - blue.total()
 }
 
-pub fn eval(plies_played: usize, meine_fische: u128, meine_schwaerme: &Vec<Schwarm>, my_biggest_schwarm: &Schwarm, my_color: GameColor, biggest_gegner_schwarm: &Schwarm, gegner_fische: usize, verbose: bool) -> f64 {
+//The feature values of both colors, rating is red's total minus blue's
+pub fn rating_trace(game_state: &GameState) -> (EvalTrace, EvalTrace) {
+    let ((rote_schwaerme, biggest_roter_schwarm), (blaue_schwaerme, biggest_blauer_schwarm)) = match &game_state.schwaerme {
+        Some(schwaerme) => (Schwarm::aus_schwaermen(&schwaerme[0]), Schwarm::aus_schwaermen(&schwaerme[1])),
+        None => (Schwarm::berechne_schwaerme(game_state.rote_fische), Schwarm::berechne_schwaerme(game_state.blaue_fische)),
+    };
+    (eval_trace(game_state.plies_played as usize, game_state.rote_fische, &rote_schwaerme, &biggest_roter_schwarm, &biggest_blauer_schwarm, game_state.blaue_fische.count_ones() as usize),
+     eval_trace(game_state.plies_played as usize, game_state.blaue_fische, &blaue_schwaerme, &biggest_blauer_schwarm, &biggest_roter_schwarm, game_state.rote_fische.count_ones() as usize))
+}
+
+pub struct EvalTrace {
+    pub phase: f64,
+    pub fische: f64,
+    pub distance_to_mid: f64,
+    pub distance_to_biggest: f64,
+    pub biggest_schwarm: f64,
+    pub absolut_schwarm: f64,
+    pub rand_fische: f64,
+    pub distance_to_enemy: f64,
+}
+
+impl EvalTrace {
+    #[inline(always)]
+    pub fn total(&self) -> f64 {
+        self.fische + self.distance_to_mid + self.distance_to_biggest + self.biggest_schwarm + self.absolut_schwarm + self.rand_fische + self.distance_to_enemy
+    }
+
+    pub fn print(&self, my_color: GameColor) {
+        println!("Phase: {}", self.phase);
+        println!("Eval for {}", if let GameColor::Red = my_color { "Red" } else { "Blue" });
+        println!("FischEval: {}", self.fische);
+        println!("Abstand zu Mitte: {}", self.distance_to_mid);
+        println!("Abstand zu BS: {}", self.distance_to_biggest);
+        println!("Biggest Schwarm: {}", self.biggest_schwarm);
+        println!("Maximal Schwarm Size: {}", self.absolut_schwarm);
+        println!("Rand Fische: {}", self.rand_fische);
+        println!("Abstand zu Gegner Schwarm: {}", self.distance_to_enemy);
+    }
+}
+
+pub fn eval_trace(plies_played: usize, meine_fische: u128, meine_schwaerme: &Vec<Schwarm>, my_biggest_schwarm: &Schwarm, biggest_gegner_schwarm: &Schwarm, gegner_fische: usize) -> EvalTrace {
     let unskewed_phase = plies_played as f64 / 60.0;
     let phase = 1.0 - (1.0 - unskewed_phase).powf(2.0);
 
@@ -198,17 +286,14 @@ pub fn eval(plies_played: usize, meine_fische: u128, meine_schwaerme: &Vec<Schwa
     let biggest_schwarm_eval = biggest_schwarm_feature(biggest_schwarm_relative_input, phase);
     let absolute_schwarm_eval = absolut_schwarm_feature((my_biggest_schwarm.size as f64 / 16.0 + 0.5).powf(2.0), unskewed_phase.powf(3.0));
     let rand_fisch_eval = rand_fische_feature((meine_fische & RAND).count_ones() as f64, phase);
-    if verbose {
-        println!("Phase: {}", phase);
-        println!("Eval for {}", if let GameColor::Red = my_color { "Red" } else { "Blue" });
-        println!("FischEval: {}", fisch_eval);
-        println!("Abstand zu Mitte: {}", distance_to_mid_eval);
-        println!("Abstand zu BS: {}", distance_to_biggest_schwarm_eval);
-        println!("Biggest Schwarm: {}", biggest_schwarm_eval);
-        println!("Maximal Schwarm Size: {}", absolute_schwarm_eval);
-        println!("Rand Fische: {}", rand_fisch_eval);
-        println!("Abstand zu Gegner Schwarm: {}", gegner_distance_eval);
-    }
-    let res = fisch_eval + distance_to_mid_eval + distance_to_biggest_schwarm_eval + biggest_schwarm_eval + absolute_schwarm_eval + rand_fisch_eval + gegner_distance_eval;
-    res
+    EvalTrace {
+        phase,
+        fische: fisch_eval,
+        distance_to_mid: distance_to_mid_eval,
+        distance_to_biggest: distance_to_biggest_schwarm_eval,
+        biggest_schwarm: biggest_schwarm_eval,
+        absolut_schwarm: absolute_schwarm_eval,
+        rand_fische: rand_fisch_eval,
+        distance_to_enemy: gegner_distance_eval,
+    }
 }
\ No newline at end of file
@@ -0,0 +1,266 @@
+use super::game_state::{GameState, GameMove, StartPositions};
+use super::game_logic::{get_possible_moves, make_move};
+use super::search::{Search, TimeControl};
+use super::cli;
+use std::fs::File;
+use std::io::{self, Read, Write, BufReader, BufWriter};
+use std::sync::OnceLock;
+use crate::random;
+
+pub const MAGIC: &[u8; 4] = b"SWBK";
+//Version 2: generated u64 Zobrist keys
+pub const VERSION: u32 = 2;
+
+static BOOK: OnceLock<Book> = OnceLock::new();
+
+//Binary layout (little endian):
+//magic "SWBK", u32 version, u32 entry count,
+//entries sorted by hash: u64 hash, u8 from, u8 to, u16 weight
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BookEntry {
+    pub hash: u64,
+    pub from: u8,
+    pub to: u8,
+    pub weight: u16,
+}
+
+pub struct Book {
+    pub entries: Vec<BookEntry>,
+}
+
+pub struct BookConfig {
+    //Positions up to this many plies get book moves
+    pub plies: u8,
+    //Depth every root move is searched to
+    pub depth: u8,
+    //Moves scoring at most this much worse than the best move are kept
+    pub margin: f64,
+    //Maximum number of book moves per position
+    pub moves: usize,
+    //Only use the first placements of all_kraken_placements, for quick test books
+    pub placements: Option<usize>,
+}
+
+impl BookConfig {
+    pub fn from_args(args: &[String]) -> Result<BookConfig, String> {
+        let mut config = BookConfig {
+            plies: 2,
+            depth: 5,
+            margin: 0.5,
+            moves: 4,
+            placements: None,
+        };
+        let mut index: usize = 0;
+        while index < args.len() {
+            match &args[index][..] {
+                "--plies" => config.plies = cli::value(args, index)?,
+                "--depth" => config.depth = cli::value(args, index)?,
+                "--margin" => config.margin = cli::value(args, index)?,
+                "--moves" => config.moves = cli::value(args, index)?,
+                "--placements" => config.placements = Some(cli::value(args, index)?),
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            }
+            index += 2;
+        }
+        //Moves are rated by searching the position after them to depth - 1, which needs at least one iteration
+        if config.depth < 2 {
+            return Err(String::from("--depth has to be at least 2"));
+        }
+        Ok(config)
+    }
+}
+
+impl Book {
+    pub fn new(mut entries: Vec<BookEntry>) -> Book {
+        entries.sort_by_key(|e| (e.hash, e.from, e.to));
+        //Transpositions reach the same position more than once while building
+        entries.dedup_by_key(|e| (e.hash, e.from, e.to));
+        Book { entries }
+    }
+
+    pub fn load(path: &str) -> io::Result<Book> {
+        let file = File::open(path)?;
+        let length = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Not a book file"));
+        }
+        let mut buf = [0u8; 4];
+        reader.read_exact(&mut buf)?;
+        if u32::from_le_bytes(buf) != VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Unsupported book version"));
+        }
+        reader.read_exact(&mut buf)?;
+        let count = u32::from_le_bytes(buf) as usize;
+        //Checked before allocating, a corrupt count could ask for gigabytes
+        if length != 12 + 12 * count as u64 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Book size does not match its entry count"));
+        }
+        let mut entries = Vec::with_capacity(count);
+        let mut entry = [0u8; 12];
+        for _ in 0..count {
+            reader.read_exact(&mut entry)?;
+            let mut hash = [0u8; 8];
+            hash.copy_from_slice(&entry[0..8]);
+            entries.push(BookEntry {
+                hash: u64::from_le_bytes(hash),
+                from: entry[8],
+                to: entry[9],
+                weight: u16::from_le_bytes([entry[10], entry[11]]),
+            });
+        }
+        Ok(Book::new(entries))
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+        for entry in &self.entries {
+            writer.write_all(&entry.hash.to_le_bytes())?;
+            writer.write_all(&[entry.from, entry.to])?;
+            writer.write_all(&entry.weight.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    pub fn probe(&self, hash: u64) -> &[BookEntry] {
+        let start = self.entries.partition_point(|e| e.hash < hash);
+        let end = self.entries.partition_point(|e| e.hash <= hash);
+        &self.entries[start..end]
+    }
+
+    //Picks one of the legal book moves of this position, with probability proportional to its weight
+    pub fn choose(&self, game_state: &GameState) -> Option<GameMove> {
+        let move_list = get_possible_moves(game_state, &game_state.move_color, false);
+        let candidates: Vec<(GameMove, u16)> = self.probe(game_state.hash).iter()
+            .map(|e| (GameMove { from: e.from, to: e.to }, e.weight))
+            .filter(|(mv, weight)| *weight > 0 && move_list.contains(mv))
+            .collect();
+        let total: u32 = candidates.iter().map(|(_, weight)| *weight as u32).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut pick = random::gen_range(0, total);
+        for (mv, weight) in candidates {
+            if pick < weight as u32 {
+                return Some(mv);
+            }
+            pick -= weight as u32;
+        }
+        None
+    }
+}
+
+//Activates the book for the whole process. Returns false if a book was already set
+pub fn set_book(book: Book) -> bool {
+    BOOK.set(book).is_ok()
+}
+
+#[inline(always)]
+pub fn book() -> Option<&'static Book> {
+    BOOK.get()
+}
+
+//Searches every root move of the position and returns the good ones with their weights, best first
+pub fn rate_moves(search: &mut Search, game_state: &GameState, config: &BookConfig) -> Vec<(GameMove, u16)> {
+    let move_list = get_possible_moves(game_state, &game_state.move_color, false);
+    let mut scored: Vec<(GameMove, f64)> = Vec::with_capacity(move_list.len());
+    for mv in move_list {
+        let mut child = make_move(game_state, &mv);
+        let child_moves = get_possible_moves(&child, &child.move_color, false);
+        child.analyze(&child_moves);
+        if child.game_over() {
+            //Such early games are decided by the search anyway, leave them out of the book
+            continue;
+        }
+        let pv = search.run(config.depth - 1, &mut child);
+        scored.push((mv, -pv.score));
+    }
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let best = match scored.first() {
+        Some((_, score)) => *score,
+        None => return Vec::new(),
+    };
+    scored.iter()
+        .take(config.moves)
+        .filter(|(_, score)| best - score <= config.margin)
+        //Best move weighs 100, a move at the margin 10
+        .map(|(mv, score)| (*mv, (10.0 + 90.0 * (1.0 - (best - score) / config.margin.max(1e-9))).round() as u16))
+        .collect()
+}
+
+//Builds the book over all kraken placements: the start position and every position reached by book moves
+pub fn build(config: &BookConfig) -> Book {
+    let mut search = Search::new(TimeControl::Infinite);
+    search.use_book = false;
+    let mut entries = Vec::new();
+    let placements = GameState::all_kraken_placements().len().min(config.placements.unwrap_or(usize::MAX));
+    for (index, start) in StartPositions::enumerated().take(placements).enumerate() {
+        let mut positions = vec![start];
+        for _ in 0..config.plies {
+            let mut next_positions = Vec::new();
+            for game_state in &positions {
+                for (mv, weight) in rate_moves(&mut search, game_state, config) {
+                    entries.push(BookEntry { hash: game_state.hash, from: mv.from, to: mv.to, weight });
+                    next_positions.push(make_move(game_state, &mv));
+                }
+            }
+            positions = next_positions;
+        }
+        println!("Placement {}/{} done, {} entries", index + 1, placements, entries.len());
+    }
+    Book::new(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic;
+
+    #[test]
+    fn book_round_trip() -> Result<(), String> {
+        let config = BookConfig { plies: 2, depth: 2, margin: 1.0, moves: 3, placements: Some(2) };
+        let built = build(&config);
+        let path = std::env::temp_dir().join("book_test.bin");
+        built.save(path.to_str().unwrap()).map_err(|e| e.to_string())?;
+        let loaded = Book::load(path.to_str().unwrap()).map_err(|e| e.to_string())?;
+        if loaded.entries != built.entries || loaded.entries.is_empty() {
+            return Err(format!("Saved {} entries, loaded {}", built.entries.len(), loaded.entries.len()));
+        }
+        //A header announcing more entries than the file holds
+        let mut corrupt = std::fs::read(&path).map_err(|e| e.to_string())?;
+        corrupt[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        std::fs::write(&path, &corrupt).map_err(|e| e.to_string())?;
+        if Book::load(path.to_str().unwrap()).is_ok() {
+            return Err(String::from("Book with a wrong entry count is accepted"));
+        }
+        for kraken in GameState::all_kraken_placements().iter().take(2) {
+            let game_state = GameState::standard_with_kraken(*kraken);
+            let moves = game_logic::get_possible_moves(&game_state, &game_state.move_color, false);
+            let entries = loaded.probe(game_state.hash);
+            if entries.is_empty() || entries.len() > config.moves {
+                return Err(format!("{} book moves for the start position with kraken 0x{:x}", entries.len(), kraken));
+            }
+            for _ in 0..20 {
+                match loaded.choose(&game_state) {
+                    Some(mv) if moves.contains(&mv) && entries.iter().any(|e| e.from == mv.from && e.to == mv.to) => {}
+                    other => return Err(format!("Book chose {:?} with kraken 0x{:x}", other.map(|mv| format!("{}", mv)), kraken)),
+                }
+            }
+            //Positions outside of the book
+            let after = game_logic::make_move(&game_state, &moves[0]);
+            let after = game_logic::make_move(&after, &game_logic::get_possible_moves(&after, &after.move_color, false)[0]);
+            if loaded.choose(&after).is_some() {
+                return Err(String::from("Book move found beyond the book plies"));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::online::protocol::EXIT_NO_RESULT;
+use std::str::FromStr;
+
+//Command line options are "<name> <value>" pairs. A missing or unparsable value is an error instead of a panic
+
+//The value after the option at args[index]
+pub fn value<T: FromStr>(args: &[impl AsRef<str>], index: usize) -> Result<T, String> {
+    let name = args[index].as_ref();
+    let value = args.get(index + 1).ok_or_else(|| format!("Missing value after {}", name))?.as_ref();
+    value.parse::<T>().map_err(|_| format!("Invalid value {} for {}", value, name))
+}
+
+//The value of the option with this name, None if it is not given
+pub fn option<T: FromStr>(args: &[String], name: &str) -> Result<Option<T>, String> {
+    match args.iter().position(|arg| arg == name) {
+        Some(index) => value(args, index).map(Some),
+        None => Ok(None),
+    }
+}
+
+//Both exit with EXIT_NO_RESULT, the codes 0 to 2 are the game results of the client
+pub fn exit_with_usage(error: &str, usage: &str) -> ! {
+    eprintln!("{}", error);
+    eprintln!("Usage: {}", usage);
+    std::process::exit(EXIT_NO_RESULT);
+}
+
+pub fn exit_with_error(error: &str) -> ! {
+    eprintln!("{}", error);
+    std::process::exit(EXIT_NO_RESULT);
+}
@@ -0,0 +1,9 @@
+//The geometry tables as they were checked in up to the baseline commit c70a556, written by the former
+//generate_u128_nums.rs. Only compiled for tests, which compare them with the tables constants.rs computes
+#![allow(clippy::large_const_arrays)]
+
+pub const RAND: u128 = 0xffe01806018060180601807ffu128;
+pub const NACHBARN: [u128; 100] = [0xc02u128, 0x1c05u128, 0x380au128, 0x7014u128, 0xe028u128, 0x1c050u128, 0x380a0u128, 0x70140u128, 0xe0280u128, 0xc0100u128, 0x300803u128, 0x701407u128, 0xe0280eu128, 0x1c0501cu128, 0x380a038u128, 0x7014070u128, 0xe0280e0u128, 0x1c0501c0u128, 0x380a0380u128, 0x30040300u128, 0xc0200c00u128, 0x1c0501c00u128, 0x380a03800u128, 0x701407000u128, 0xe0280e000u128, 0x1c0501c000u128, 0x380a038000u128, 0x7014070000u128, 0xe0280e0000u128, 0xc0100c0000u128, 0x30080300000u128, 0x70140700000u128, 0xe0280e00000u128, 0x1c0501c00000u128, 0x380a03800000u128, 0x701407000000u128, 0xe0280e000000u128, 0x1c0501c000000u128, 0x380a038000000u128, 0x3004030000000u128, 0xc0200c0000000u128, 0x1c0501c0000000u128, 0x380a0380000000u128, 0x70140700000000u128, 0xe0280e00000000u128, 0x1c0501c00000000u128, 0x380a03800000000u128, 0x701407000000000u128, 0xe0280e000000000u128, 0xc0100c000000000u128, 0x3008030000000000u128, 0x7014070000000000u128, 0xe0280e0000000000u128, 0x1c0501c0000000000u128, 0x380a0380000000000u128, 0x70140700000000000u128, 0xe0280e00000000000u128, 0x1c0501c00000000000u128, 0x380a03800000000000u128, 0x300403000000000000u128, 0xc0200c000000000000u128, 0x1c0501c000000000000u128, 0x380a038000000000000u128, 0x7014070000000000000u128, 0xe0280e0000000000000u128, 0x1c0501c0000000000000u128, 0x380a0380000000000000u128, 0x70140700000000000000u128, 0xe0280e00000000000000u128, 0xc0100c00000000000000u128, 0x300803000000000000000u128, 0x701407000000000000000u128, 0xe0280e000000000000000u128, 0x1c0501c000000000000000u128, 0x380a038000000000000000u128, 0x7014070000000000000000u128, 0xe0280e0000000000000000u128, 0x1c0501c0000000000000000u128, 0x380a0380000000000000000u128, 0x30040300000000000000000u128, 0xc0200c00000000000000000u128, 0x1c0501c00000000000000000u128, 0x380a03800000000000000000u128, 0x701407000000000000000000u128, 0xe0280e000000000000000000u128, 0x1c0501c000000000000000000u128, 0x380a038000000000000000000u128, 0x7014070000000000000000000u128, 0xe0280e0000000000000000000u128, 0xc0100c0000000000000000000u128, 0x80300000000000000000000u128, 0x140700000000000000000000u128, 0x280e00000000000000000000u128, 0x501c00000000000000000000u128, 0xa03800000000000000000000u128, 0x1407000000000000000000000u128, 0x280e000000000000000000000u128, 0x501c000000000000000000000u128, 0xa038000000000000000000000u128, 0x4030000000000000000000000u128, ];
+pub const ATTACK_TWO_SIDED: [[u128; 4]; 100] = [[0x40100401004010040100401u128, 0x8010020040080100200400801u128, 0x3ffu128, 0x1u128, ], [0x80200802008020080200802u128, 0x20040080100200400801002u128, 0x3ffu128, 0x402u128, ], [0x100401004010040100401004u128, 0x80100200400801002004u128, 0x3ffu128, 0x100804u128, ], [0x200802008020080200802008u128, 0x200400801002004008u128, 0x3ffu128, 0x40201008u128, ], [0x401004010040100401004010u128, 0x801002004008010u128, 0x3ffu128, 0x10080402010u128, ], [0x802008020080200802008020u128, 0x2004008010020u128, 0x3ffu128, 0x4020100804020u128, ], [0x1004010040100401004010040u128, 0x8010020040u128, 0x3ffu128, 0x1008040201008040u128, ], [0x2008020080200802008020080u128, 0x20040080u128, 0x3ffu128, 0x402010080402010080u128, ], [0x4010040100401004010040100u128, 0x80100u128, 0x3ffu128, 0x100804020100804020100u128, ], [0x8020080200802008020080200u128, 0x200u128, 0x3ffu128, 0x40201008040201008040200u128, ], [0x40100401004010040100401u128, 0x4008010020040080100200400u128, 0xffc00u128, 0x402u128, ], [0x80200802008020080200802u128, 0x8010020040080100200400801u128, 0xffc00u128, 0x100804u128, ], [0x100401004010040100401004u128, 0x20040080100200400801002u128, 0xffc00u128, 0x40201008u128, ], [0x200802008020080200802008u128, 0x80100200400801002004u128, 0xffc00u128, 0x10080402010u128, ], [0x401004010040100401004010u128, 0x200400801002004008u128, 0xffc00u128, 0x4020100804020u128, ], [0x802008020080200802008020u128, 0x801002004008010u128, 0xffc00u128, 0x1008040201008040u128, ], [0x1004010040100401004010040u128, 0x2004008010020u128, 0xffc00u128, 0x402010080402010080u128, ], [0x2008020080200802008020080u128, 0x8010020040u128, 0xffc00u128, 0x100804020100804020100u128, ], [0x4010040100401004010040100u128, 0x20040080u128, 0xffc00u128, 0x40201008040201008040200u128, ], [0x8020080200802008020080200u128, 0x80100u128, 0xffc00u128, 0x80402010080402010080000u128, ], [0x40100401004010040100401u128, 0x2004008010020040080100000u128, 0x3ff00000u128, 0x100804u128, ], [0x80200802008020080200802u128, 0x4008010020040080100200400u128, 0x3ff00000u128, 0x40201008u128, ], [0x100401004010040100401004u128, 0x8010020040080100200400801u128, 0x3ff00000u128, 0x10080402010u128, ], [0x200802008020080200802008u128, 0x20040080100200400801002u128, 0x3ff00000u128, 0x4020100804020u128, ], [0x401004010040100401004010u128, 0x80100200400801002004u128, 0x3ff00000u128, 0x1008040201008040u128, ], [0x802008020080200802008020u128, 0x200400801002004008u128, 0x3ff00000u128, 0x402010080402010080u128, ], [0x1004010040100401004010040u128, 0x801002004008010u128, 0x3ff00000u128, 0x100804020100804020100u128, ], [0x2008020080200802008020080u128, 0x2004008010020u128, 0x3ff00000u128, 0x40201008040201008040200u128, ], [0x4010040100401004010040100u128, 0x8010020040u128, 0x3ff00000u128, 0x80402010080402010080000u128, ], [0x8020080200802008020080200u128, 0x20040080u128, 0x3ff00000u128, 0x100804020100804020000000u128, ], [0x40100401004010040100401u128, 0x1002004008010020040000000u128, 0xffc0000000u128, 0x40201008u128, ], [0x80200802008020080200802u128, 0x2004008010020040080100000u128, 0xffc0000000u128, 0x10080402010u128, ], [0x100401004010040100401004u128, 0x4008010020040080100200400u128, 0xffc0000000u128, 0x4020100804020u128, ], [0x200802008020080200802008u128, 0x8010020040080100200400801u128, 0xffc0000000u128, 0x1008040201008040u128, ], [0x401004010040100401004010u128, 0x20040080100200400801002u128, 0xffc0000000u128, 0x402010080402010080u128, ], [0x802008020080200802008020u128, 0x80100200400801002004u128, 0xffc0000000u128, 0x100804020100804020100u128, ], [0x1004010040100401004010040u128, 0x200400801002004008u128, 0xffc0000000u128, 0x40201008040201008040200u128, ], [0x2008020080200802008020080u128, 0x801002004008010u128, 0xffc0000000u128, 0x80402010080402010080000u128, ], [0x4010040100401004010040100u128, 0x2004008010020u128, 0xffc0000000u128, 0x100804020100804020000000u128, ], [0x8020080200802008020080200u128, 0x8010020040u128, 0xffc0000000u128, 0x201008040201008000000000u128, ], [0x40100401004010040100401u128, 0x801002004008010000000000u128, 0x3ff0000000000u128, 0x10080402010u128, ], [0x80200802008020080200802u128, 0x1002004008010020040000000u128, 0x3ff0000000000u128, 0x4020100804020u128, ], [0x100401004010040100401004u128, 0x2004008010020040080100000u128, 0x3ff0000000000u128, 0x1008040201008040u128, ], [0x200802008020080200802008u128, 0x4008010020040080100200400u128, 0x3ff0000000000u128, 0x402010080402010080u128, ], [0x401004010040100401004010u128, 0x8010020040080100200400801u128, 0x3ff0000000000u128, 0x100804020100804020100u128, ], [0x802008020080200802008020u128, 0x20040080100200400801002u128, 0x3ff0000000000u128, 0x40201008040201008040200u128, ], [0x1004010040100401004010040u128, 0x80100200400801002004u128, 0x3ff0000000000u128, 0x80402010080402010080000u128, ], [0x2008020080200802008020080u128, 0x200400801002004008u128, 0x3ff0000000000u128, 0x100804020100804020000000u128, ], [0x4010040100401004010040100u128, 0x801002004008010u128, 0x3ff0000000000u128, 0x201008040201008000000000u128, ], [0x8020080200802008020080200u128, 0x2004008010020u128, 0x3ff0000000000u128, 0x402010080402000000000000u128, ], [0x40100401004010040100401u128, 0x400801002004000000000000u128, 0xffc000000000000u128, 0x4020100804020u128, ], [0x80200802008020080200802u128, 0x801002004008010000000000u128, 0xffc000000000000u128, 0x1008040201008040u128, ], [0x100401004010040100401004u128, 0x1002004008010020040000000u128, 0xffc000000000000u128, 0x402010080402010080u128, ], [0x200802008020080200802008u128, 0x2004008010020040080100000u128, 0xffc000000000000u128, 0x100804020100804020100u128, ], [0x401004010040100401004010u128, 0x4008010020040080100200400u128, 0xffc000000000000u128, 0x40201008040201008040200u128, ], [0x802008020080200802008020u128, 0x8010020040080100200400801u128, 0xffc000000000000u128, 0x80402010080402010080000u128, ], [0x1004010040100401004010040u128, 0x20040080100200400801002u128, 0xffc000000000000u128, 0x100804020100804020000000u128, ], [0x2008020080200802008020080u128, 0x80100200400801002004u128, 0xffc000000000000u128, 0x201008040201008000000000u128, ], [0x4010040100401004010040100u128, 0x200400801002004008u128, 0xffc000000000000u128, 0x402010080402000000000000u128, ], [0x8020080200802008020080200u128, 0x801002004008010u128, 0xffc000000000000u128, 0x804020100800000000000000u128, ], [0x40100401004010040100401u128, 0x200400801000000000000000u128, 0x3ff000000000000000u128, 0x1008040201008040u128, ], [0x80200802008020080200802u128, 0x400801002004000000000000u128, 0x3ff000000000000000u128, 0x402010080402010080u128, ], [0x100401004010040100401004u128, 0x801002004008010000000000u128, 0x3ff000000000000000u128, 0x100804020100804020100u128, ], [0x200802008020080200802008u128, 0x1002004008010020040000000u128, 0x3ff000000000000000u128, 0x40201008040201008040200u128, ], [0x401004010040100401004010u128, 0x2004008010020040080100000u128, 0x3ff000000000000000u128, 0x80402010080402010080000u128, ], [0x802008020080200802008020u128, 0x4008010020040080100200400u128, 0x3ff000000000000000u128, 0x100804020100804020000000u128, ], [0x1004010040100401004010040u128, 0x8010020040080100200400801u128, 0x3ff000000000000000u128, 0x201008040201008000000000u128, ], [0x2008020080200802008020080u128, 0x20040080100200400801002u128, 0x3ff000000000000000u128, 0x402010080402000000000000u128, ], [0x4010040100401004010040100u128, 0x80100200400801002004u128, 0x3ff000000000000000u128, 0x804020100800000000000000u128, ], [0x8020080200802008020080200u128, 0x200400801002004008u128, 0x3ff000000000000000u128, 0x1008040200000000000000000u128, ], [0x40100401004010040100401u128, 0x100200400000000000000000u128, 0xffc00000000000000000u128, 0x402010080402010080u128, ], [0x80200802008020080200802u128, 0x200400801000000000000000u128, 0xffc00000000000000000u128, 0x100804020100804020100u128, ], [0x100401004010040100401004u128, 0x400801002004000000000000u128, 0xffc00000000000000000u128, 0x40201008040201008040200u128, ], [0x200802008020080200802008u128, 0x801002004008010000000000u128, 0xffc00000000000000000u128, 0x80402010080402010080000u128, ], [0x401004010040100401004010u128, 0x1002004008010020040000000u128, 0xffc00000000000000000u128, 0x100804020100804020000000u128, ], [0x802008020080200802008020u128, 0x2004008010020040080100000u128, 0xffc00000000000000000u128, 0x201008040201008000000000u128, ], [0x1004010040100401004010040u128, 0x4008010020040080100200400u128, 0xffc00000000000000000u128, 0x402010080402000000000000u128, ], [0x2008020080200802008020080u128, 0x8010020040080100200400801u128, 0xffc00000000000000000u128, 0x804020100800000000000000u128, ], [0x4010040100401004010040100u128, 0x20040080100200400801002u128, 0xffc00000000000000000u128, 0x1008040200000000000000000u128, ], [0x8020080200802008020080200u128, 0x80100200400801002004u128, 0xffc00000000000000000u128, 0x2010080000000000000000000u128, ], [0x40100401004010040100401u128, 0x80100000000000000000000u128, 0x3ff00000000000000000000u128, 0x100804020100804020100u128, ], [0x80200802008020080200802u128, 0x100200400000000000000000u128, 0x3ff00000000000000000000u128, 0x40201008040201008040200u128, ], [0x100401004010040100401004u128, 0x200400801000000000000000u128, 0x3ff00000000000000000000u128, 0x80402010080402010080000u128, ], [0x200802008020080200802008u128, 0x400801002004000000000000u128, 0x3ff00000000000000000000u128, 0x100804020100804020000000u128, ], [0x401004010040100401004010u128, 0x801002004008010000000000u128, 0x3ff00000000000000000000u128, 0x201008040201008000000000u128, ], [0x802008020080200802008020u128, 0x1002004008010020040000000u128, 0x3ff00000000000000000000u128, 0x402010080402000000000000u128, ], [0x1004010040100401004010040u128, 0x2004008010020040080100000u128, 0x3ff00000000000000000000u128, 0x804020100800000000000000u128, ], [0x2008020080200802008020080u128, 0x4008010020040080100200400u128, 0x3ff00000000000000000000u128, 0x1008040200000000000000000u128, ], [0x4010040100401004010040100u128, 0x8010020040080100200400801u128, 0x3ff00000000000000000000u128, 0x2010080000000000000000000u128, ], [0x8020080200802008020080200u128, 0x20040080100200400801002u128, 0x3ff00000000000000000000u128, 0x4020000000000000000000000u128, ], [0x40100401004010040100401u128, 0x40000000000000000000000u128, 0xffc0000000000000000000000u128, 0x40201008040201008040200u128, ], [0x80200802008020080200802u128, 0x80100000000000000000000u128, 0xffc0000000000000000000000u128, 0x80402010080402010080000u128, ], [0x100401004010040100401004u128, 0x100200400000000000000000u128, 0xffc0000000000000000000000u128, 0x100804020100804020000000u128, ], [0x200802008020080200802008u128, 0x200400801000000000000000u128, 0xffc0000000000000000000000u128, 0x201008040201008000000000u128, ], [0x401004010040100401004010u128, 0x400801002004000000000000u128, 0xffc0000000000000000000000u128, 0x402010080402000000000000u128, ], [0x802008020080200802008020u128, 0x801002004008010000000000u128, 0xffc0000000000000000000000u128, 0x804020100800000000000000u128, ], [0x1004010040100401004010040u128, 0x1002004008010020040000000u128, 0xffc0000000000000000000000u128, 0x1008040200000000000000000u128, ], [0x2008020080200802008020080u128, 0x2004008010020040080100000u128, 0xffc0000000000000000000000u128, 0x2010080000000000000000000u128, ], [0x4010040100401004010040100u128, 0x4008010020040080100200400u128, 0xffc0000000000000000000000u128, 0x4020000000000000000000000u128, ], [0x8020080200802008020080200u128, 0x8010020040080100200400801u128, 0xffc0000000000000000000000u128, 0x8000000000000000000000000u128, ], ];
+pub const ATTACK_ONE_SIDED_SKIPPED_SQUARES: [[[u128; 8]; 8]; 100] = [[[0x400u128, 0x100400u128, 0x40100400u128, 0x10040100400u128, 0x4010040100400u128, 0x1004010040100400u128, 0x401004010040100400u128, 0x100401004010040100400u128, ], [0x800u128, 0x400800u128, 0x200400800u128, 0x100200400800u128, 0x80100200400800u128, 0x40080100200400800u128, 0x20040080100200400800u128, 0x10020040080100200400800u128, ], [0x2u128, 0x6u128, 0xeu128, 0x1eu128, 0x3eu128, 0x7eu128, 0xfeu128, 0x1feu128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x800u128, 0x200800u128, 0x80200800u128, 0x20080200800u128, 0x8020080200800u128, 0x2008020080200800u128, 0x802008020080200800u128, 0x200802008020080200800u128, ], [0x1000u128, 0x801000u128, 0x400801000u128, 0x200400801000u128, 0x100200400801000u128, 0x80100200400801000u128, 0x40080100200400801000u128, 0x20040080100200400801000u128, ], [0x4u128, 0xcu128, 0x1cu128, 0x3cu128, 0x7cu128, 0xfcu128, 0x1fcu128, 0x3fcu128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, ], [0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, ], ], [[0x1000u128, 0x401000u128, 0x100401000u128, 0x40100401000u128, 0x10040100401000u128, 0x4010040100401000u128, 0x1004010040100401000u128, 0x401004010040100401000u128, ], [0x2000u128, 0x1002000u128, 0x801002000u128, 0x400801002000u128, 0x200400801002000u128, 0x100200400801002000u128, 0x80100200400801002000u128, 0x80100200400801002000u128, ], [0x8u128, 0x18u128, 0x38u128, 0x78u128, 0xf8u128, 0x1f8u128, 0x3f8u128, 0x3f8u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x2u128, 0x3u128, 0x3u128, 0x3u128, 0x3u128, 0x3u128, 0x3u128, 0x3u128, ], [0x800u128, 0x100800u128, 0x100800u128, 0x100800u128, 0x100800u128, 0x100800u128, 0x100800u128, 0x100800u128, ], ], [[0x2000u128, 0x802000u128, 0x200802000u128, 0x80200802000u128, 0x20080200802000u128, 0x8020080200802000u128, 0x2008020080200802000u128, 0x802008020080200802000u128, ], [0x4000u128, 0x2004000u128, 0x1002004000u128, 0x801002004000u128, 0x400801002004000u128, 0x200400801002004000u128, 0x200400801002004000u128, 0x200400801002004000u128, ], [0x10u128, 0x30u128, 0x70u128, 0xf0u128, 0x1f0u128, 0x3f0u128, 0x3f0u128, 0x3f0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x4u128, 0x6u128, 0x7u128, 0x7u128, 0x7u128, 0x7u128, 0x7u128, 0x7u128, ], [0x1000u128, 0x201000u128, 0x40201000u128, 0x40201000u128, 0x40201000u128, 0x40201000u128, 0x40201000u128, 0x40201000u128, ], ], [[0x4000u128, 0x1004000u128, 0x401004000u128, 0x100401004000u128, 0x40100401004000u128, 0x10040100401004000u128, 0x4010040100401004000u128, 0x1004010040100401004000u128, ], [0x8000u128, 0x4008000u128, 0x2004008000u128, 0x1002004008000u128, 0x801002004008000u128, 0x801002004008000u128, 0x801002004008000u128, 0x801002004008000u128, ], [0x20u128, 0x60u128, 0xe0u128, 0x1e0u128, 0x3e0u128, 0x3e0u128, 0x3e0u128, 0x3e0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x8u128, 0xcu128, 0xeu128, 0xfu128, 0xfu128, 0xfu128, 0xfu128, 0xfu128, ], [0x2000u128, 0x402000u128, 0x80402000u128, 0x10080402000u128, 0x10080402000u128, 0x10080402000u128, 0x10080402000u128, 0x10080402000u128, ], ], [[0x8000u128, 0x2008000u128, 0x802008000u128, 0x200802008000u128, 0x80200802008000u128, 0x20080200802008000u128, 0x8020080200802008000u128, 0x2008020080200802008000u128, ], [0x10000u128, 0x8010000u128, 0x4008010000u128, 0x2004008010000u128, 0x2004008010000u128, 0x2004008010000u128, 0x2004008010000u128, 0x2004008010000u128, ], [0x40u128, 0xc0u128, 0x1c0u128, 0x3c0u128, 0x3c0u128, 0x3c0u128, 0x3c0u128, 0x3c0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x10u128, 0x18u128, 0x1cu128, 0x1eu128, 0x1fu128, 0x1fu128, 0x1fu128, 0x1fu128, ], [0x4000u128, 0x804000u128, 0x100804000u128, 0x20100804000u128, 0x4020100804000u128, 0x4020100804000u128, 0x4020100804000u128, 0x4020100804000u128, ], ], [[0x10000u128, 0x4010000u128, 0x1004010000u128, 0x401004010000u128, 0x100401004010000u128, 0x40100401004010000u128, 0x10040100401004010000u128, 0x4010040100401004010000u128, ], [0x20000u128, 0x10020000u128, 0x8010020000u128, 0x8010020000u128, 0x8010020000u128, 0x8010020000u128, 0x8010020000u128, 0x8010020000u128, ], [0x80u128, 0x180u128, 0x380u128, 0x380u128, 0x380u128, 0x380u128, 0x380u128, 0x380u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x20u128, 0x30u128, 0x38u128, 0x3cu128, 0x3eu128, 0x3fu128, 0x3fu128, 0x3fu128, ], [0x8000u128, 0x1008000u128, 0x201008000u128, 0x40201008000u128, 0x8040201008000u128, 0x1008040201008000u128, 0x1008040201008000u128, 0x1008040201008000u128, ], ], [[0x20000u128, 0x8020000u128, 0x2008020000u128, 0x802008020000u128, 0x200802008020000u128, 0x80200802008020000u128, 0x20080200802008020000u128, 0x8020080200802008020000u128, ], [0x40000u128, 0x20040000u128, 0x20040000u128, 0x20040000u128, 0x20040000u128, 0x20040000u128, 0x20040000u128, 0x20040000u128, ], [0x100u128, 0x300u128, 0x300u128, 0x300u128, 0x300u128, 0x300u128, 0x300u128, 0x300u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x40u128, 0x60u128, 0x70u128, 0x78u128, 0x7cu128, 0x7eu128, 0x7fu128, 0x7fu128, ], [0x10000u128, 0x2010000u128, 0x402010000u128, 0x80402010000u128, 0x10080402010000u128, 0x2010080402010000u128, 0x402010080402010000u128, 0x402010080402010000u128, ], ], [[0x40000u128, 0x10040000u128, 0x4010040000u128, 0x1004010040000u128, 0x401004010040000u128, 0x100401004010040000u128, 0x40100401004010040000u128, 0x10040100401004010040000u128, ], [0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, ], [0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x80u128, 0xc0u128, 0xe0u128, 0xf0u128, 0xf8u128, 0xfcu128, 0xfeu128, 0xffu128, ], [0x20000u128, 0x4020000u128, 0x804020000u128, 0x100804020000u128, 0x20100804020000u128, 0x4020100804020000u128, 0x804020100804020000u128, 0x100804020100804020000u128, ], ], [[0x80000u128, 0x20080000u128, 0x8020080000u128, 0x2008020080000u128, 0x802008020080000u128, 0x200802008020080000u128, 0x80200802008020080000u128, 0x20080200802008020080000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x100u128, 0x180u128, 0x1c0u128, 0x1e0u128, 0x1f0u128, 0x1f8u128, 0x1fcu128, 0x1feu128, ], [0x40000u128, 0x8040000u128, 0x1008040000u128, 0x201008040000u128, 0x40201008040000u128, 0x8040201008040000u128, 0x1008040201008040000u128, 0x201008040201008040000u128, ], ], [[0x100000u128, 0x40100000u128, 0x10040100000u128, 0x4010040100000u128, 0x1004010040100000u128, 0x401004010040100000u128, 0x100401004010040100000u128, 0x40100401004010040100000u128, ], [0x200000u128, 0x100200000u128, 0x80100200000u128, 0x40080100200000u128, 0x20040080100200000u128, 0x10020040080100200000u128, 0x8010020040080100200000u128, 0x4008010020040080100200000u128, ], [0x800u128, 0x1800u128, 0x3800u128, 0x7800u128, 0xf800u128, 0x1f800u128, 0x3f800u128, 0x7f800u128, ], [0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, ], [0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x200000u128, 0x80200000u128, 0x20080200000u128, 0x8020080200000u128, 0x2008020080200000u128, 0x802008020080200000u128, 0x200802008020080200000u128, 0x80200802008020080200000u128, ], [0x400000u128, 0x200400000u128, 0x100200400000u128, 0x80100200400000u128, 0x40080100200400000u128, 0x20040080100200400000u128, 0x10020040080100200400000u128, 0x8010020040080100200400000u128, ], [0x1000u128, 0x3000u128, 0x7000u128, 0xf000u128, 0x1f000u128, 0x3f000u128, 0x7f000u128, 0xff000u128, ], [0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, ], [0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, ], [0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, 0x1u128, ], [0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, ], [0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, ], ], [[0x400000u128, 0x100400000u128, 0x40100400000u128, 0x10040100400000u128, 0x4010040100400000u128, 0x1004010040100400000u128, 0x401004010040100400000u128, 0x100401004010040100400000u128, ], [0x800000u128, 0x400800000u128, 0x200400800000u128, 0x100200400800000u128, 0x80100200400800000u128, 0x40080100200400800000u128, 0x20040080100200400800000u128, 0x20040080100200400800000u128, ], [0x2000u128, 0x6000u128, 0xe000u128, 0x1e000u128, 0x3e000u128, 0x7e000u128, 0xfe000u128, 0xfe000u128, ], [0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, ], [0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, ], [0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, 0x2u128, ], [0x800u128, 0xc00u128, 0xc00u128, 0xc00u128, 0xc00u128, 0xc00u128, 0xc00u128, 0xc00u128, ], [0x200000u128, 0x40200000u128, 0x40200000u128, 0x40200000u128, 0x40200000u128, 0x40200000u128, 0x40200000u128, 0x40200000u128, ], ], [[0x800000u128, 0x200800000u128, 0x80200800000u128, 0x20080200800000u128, 0x8020080200800000u128, 0x2008020080200800000u128, 0x802008020080200800000u128, 0x200802008020080200800000u128, ], [0x1000000u128, 0x801000000u128, 0x400801000000u128, 0x200400801000000u128, 0x100200400801000000u128, 0x80100200400801000000u128, 0x80100200400801000000u128, 0x80100200400801000000u128, ], [0x4000u128, 0xc000u128, 0x1c000u128, 0x3c000u128, 0x7c000u128, 0xfc000u128, 0xfc000u128, 0xfc000u128, ], [0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, ], [0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, ], [0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, 0x4u128, ], [0x1000u128, 0x1800u128, 0x1c00u128, 0x1c00u128, 0x1c00u128, 0x1c00u128, 0x1c00u128, 0x1c00u128, ], [0x400000u128, 0x80400000u128, 0x10080400000u128, 0x10080400000u128, 0x10080400000u128, 0x10080400000u128, 0x10080400000u128, 0x10080400000u128, ], ], [[0x1000000u128, 0x401000000u128, 0x100401000000u128, 0x40100401000000u128, 0x10040100401000000u128, 0x4010040100401000000u128, 0x1004010040100401000000u128, 0x401004010040100401000000u128, ], [0x2000000u128, 0x1002000000u128, 0x801002000000u128, 0x400801002000000u128, 0x200400801002000000u128, 0x200400801002000000u128, 0x200400801002000000u128, 0x200400801002000000u128, ], [0x8000u128, 0x18000u128, 0x38000u128, 0x78000u128, 0xf8000u128, 0xf8000u128, 0xf8000u128, 0xf8000u128, ], [0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, ], [0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, ], [0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, 0x8u128, ], [0x2000u128, 0x3000u128, 0x3800u128, 0x3c00u128, 0x3c00u128, 0x3c00u128, 0x3c00u128, 0x3c00u128, ], [0x800000u128, 0x100800000u128, 0x20100800000u128, 0x4020100800000u128, 0x4020100800000u128, 0x4020100800000u128, 0x4020100800000u128, 0x4020100800000u128, ], ], [[0x2000000u128, 0x802000000u128, 0x200802000000u128, 0x80200802000000u128, 0x20080200802000000u128, 0x8020080200802000000u128, 0x2008020080200802000000u128, 0x802008020080200802000000u128, ], [0x4000000u128, 0x2004000000u128, 0x1002004000000u128, 0x801002004000000u128, 0x801002004000000u128, 0x801002004000000u128, 0x801002004000000u128, 0x801002004000000u128, ], [0x10000u128, 0x30000u128, 0x70000u128, 0xf0000u128, 0xf0000u128, 0xf0000u128, 0xf0000u128, 0xf0000u128, ], [0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, ], [0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, ], [0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, 0x10u128, ], [0x4000u128, 0x6000u128, 0x7000u128, 0x7800u128, 0x7c00u128, 0x7c00u128, 0x7c00u128, 0x7c00u128, ], [0x1000000u128, 0x201000000u128, 0x40201000000u128, 0x8040201000000u128, 0x1008040201000000u128, 0x1008040201000000u128, 0x1008040201000000u128, 0x1008040201000000u128, ], ], [[0x4000000u128, 0x1004000000u128, 0x401004000000u128, 0x100401004000000u128, 0x40100401004000000u128, 0x10040100401004000000u128, 0x4010040100401004000000u128, 0x1004010040100401004000000u128, ], [0x8000000u128, 0x4008000000u128, 0x2004008000000u128, 0x2004008000000u128, 0x2004008000000u128, 0x2004008000000u128, 0x2004008000000u128, 0x2004008000000u128, ], [0x20000u128, 0x60000u128, 0xe0000u128, 0xe0000u128, 0xe0000u128, 0xe0000u128, 0xe0000u128, 0xe0000u128, ], [0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, ], [0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, ], [0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, 0x20u128, ], [0x8000u128, 0xc000u128, 0xe000u128, 0xf000u128, 0xf800u128, 0xfc00u128, 0xfc00u128, 0xfc00u128, ], [0x2000000u128, 0x402000000u128, 0x80402000000u128, 0x10080402000000u128, 0x2010080402000000u128, 0x402010080402000000u128, 0x402010080402000000u128, 0x402010080402000000u128, ], ], [[0x8000000u128, 0x2008000000u128, 0x802008000000u128, 0x200802008000000u128, 0x80200802008000000u128, 0x20080200802008000000u128, 0x8020080200802008000000u128, 0x2008020080200802008000000u128, ], [0x10000000u128, 0x8010000000u128, 0x8010000000u128, 0x8010000000u128, 0x8010000000u128, 0x8010000000u128, 0x8010000000u128, 0x8010000000u128, ], [0x40000u128, 0xc0000u128, 0xc0000u128, 0xc0000u128, 0xc0000u128, 0xc0000u128, 0xc0000u128, 0xc0000u128, ], [0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, ], [0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, ], [0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, 0x40u128, ], [0x10000u128, 0x18000u128, 0x1c000u128, 0x1e000u128, 0x1f000u128, 0x1f800u128, 0x1fc00u128, 0x1fc00u128, ], [0x4000000u128, 0x804000000u128, 0x100804000000u128, 0x20100804000000u128, 0x4020100804000000u128, 0x804020100804000000u128, 0x100804020100804000000u128, 0x100804020100804000000u128, ], ], [[0x10000000u128, 0x4010000000u128, 0x1004010000000u128, 0x401004010000000u128, 0x100401004010000000u128, 0x40100401004010000000u128, 0x10040100401004010000000u128, 0x4010040100401004010000000u128, ], [0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, ], [0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, ], [0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, ], [0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, ], [0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, 0x80u128, ], [0x20000u128, 0x30000u128, 0x38000u128, 0x3c000u128, 0x3e000u128, 0x3f000u128, 0x3f800u128, 0x3fc00u128, ], [0x8000000u128, 0x1008000000u128, 0x201008000000u128, 0x40201008000000u128, 0x8040201008000000u128, 0x1008040201008000000u128, 0x201008040201008000000u128, 0x40201008040201008000000u128, ], ], [[0x20000000u128, 0x8020000000u128, 0x2008020000000u128, 0x802008020000000u128, 0x200802008020000000u128, 0x80200802008020000000u128, 0x20080200802008020000000u128, 0x8020080200802008020000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, 0x200u128, ], [0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, 0x100u128, ], [0x40000u128, 0x60000u128, 0x70000u128, 0x78000u128, 0x7c000u128, 0x7e000u128, 0x7f000u128, 0x7f800u128, ], [0x10000000u128, 0x2010000000u128, 0x402010000000u128, 0x80402010000000u128, 0x10080402010000000u128, 0x2010080402010000000u128, 0x402010080402010000000u128, 0x80402010080402010000000u128, ], ], [[0x40000000u128, 0x10040000000u128, 0x4010040000000u128, 0x1004010040000000u128, 0x401004010040000000u128, 0x100401004010040000000u128, 0x40100401004010040000000u128, 0x40100401004010040000000u128, ], [0x80000000u128, 0x40080000000u128, 0x20040080000000u128, 0x10020040080000000u128, 0x8010020040080000000u128, 0x4008010020040080000000u128, 0x2004008010020040080000000u128, 0x2004008010020040080000000u128, ], [0x200000u128, 0x600000u128, 0xe00000u128, 0x1e00000u128, 0x3e00000u128, 0x7e00000u128, 0xfe00000u128, 0x1fe00000u128, ], [0x800u128, 0x804u128, 0x804u128, 0x804u128, 0x804u128, 0x804u128, 0x804u128, 0x804u128, ], [0x400u128, 0x401u128, 0x401u128, 0x401u128, 0x401u128, 0x401u128, 0x401u128, 0x401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x80000000u128, 0x20080000000u128, 0x8020080000000u128, 0x2008020080000000u128, 0x802008020080000000u128, 0x200802008020080000000u128, 0x80200802008020080000000u128, 0x80200802008020080000000u128, ], [0x100000000u128, 0x80100000000u128, 0x40080100000000u128, 0x20040080100000000u128, 0x10020040080100000000u128, 0x8010020040080100000000u128, 0x4008010020040080100000000u128, 0x4008010020040080100000000u128, ], [0x400000u128, 0xc00000u128, 0x1c00000u128, 0x3c00000u128, 0x7c00000u128, 0xfc00000u128, 0x1fc00000u128, 0x3fc00000u128, ], [0x1000u128, 0x1008u128, 0x1008u128, 0x1008u128, 0x1008u128, 0x1008u128, 0x1008u128, 0x1008u128, ], [0x800u128, 0x802u128, 0x802u128, 0x802u128, 0x802u128, 0x802u128, 0x802u128, 0x802u128, ], [0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, 0x400u128, ], [0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, ], [0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, ], ], [[0x100000000u128, 0x40100000000u128, 0x10040100000000u128, 0x4010040100000000u128, 0x1004010040100000000u128, 0x401004010040100000000u128, 0x100401004010040100000000u128, 0x100401004010040100000000u128, ], [0x200000000u128, 0x100200000000u128, 0x80100200000000u128, 0x40080100200000000u128, 0x20040080100200000000u128, 0x10020040080100200000000u128, 0x8010020040080100200000000u128, 0x8010020040080100200000000u128, ], [0x800000u128, 0x1800000u128, 0x3800000u128, 0x7800000u128, 0xf800000u128, 0x1f800000u128, 0x3f800000u128, 0x3f800000u128, ], [0x2000u128, 0x2010u128, 0x2010u128, 0x2010u128, 0x2010u128, 0x2010u128, 0x2010u128, 0x2010u128, ], [0x1000u128, 0x1004u128, 0x1004u128, 0x1004u128, 0x1004u128, 0x1004u128, 0x1004u128, 0x1004u128, ], [0x800u128, 0x801u128, 0x801u128, 0x801u128, 0x801u128, 0x801u128, 0x801u128, 0x801u128, ], [0x200000u128, 0x300000u128, 0x300000u128, 0x300000u128, 0x300000u128, 0x300000u128, 0x300000u128, 0x300000u128, ], [0x80000000u128, 0x10080000000u128, 0x10080000000u128, 0x10080000000u128, 0x10080000000u128, 0x10080000000u128, 0x10080000000u128, 0x10080000000u128, ], ], [[0x200000000u128, 0x80200000000u128, 0x20080200000000u128, 0x8020080200000000u128, 0x2008020080200000000u128, 0x802008020080200000000u128, 0x200802008020080200000000u128, 0x200802008020080200000000u128, ], [0x400000000u128, 0x200400000000u128, 0x100200400000000u128, 0x80100200400000000u128, 0x40080100200400000000u128, 0x20040080100200400000000u128, 0x20040080100200400000000u128, 0x20040080100200400000000u128, ], [0x1000000u128, 0x3000000u128, 0x7000000u128, 0xf000000u128, 0x1f000000u128, 0x3f000000u128, 0x3f000000u128, 0x3f000000u128, ], [0x4000u128, 0x4020u128, 0x4020u128, 0x4020u128, 0x4020u128, 0x4020u128, 0x4020u128, 0x4020u128, ], [0x2000u128, 0x2008u128, 0x2008u128, 0x2008u128, 0x2008u128, 0x2008u128, 0x2008u128, 0x2008u128, ], [0x1000u128, 0x1002u128, 0x1002u128, 0x1002u128, 0x1002u128, 0x1002u128, 0x1002u128, 0x1002u128, ], [0x400000u128, 0x600000u128, 0x700000u128, 0x700000u128, 0x700000u128, 0x700000u128, 0x700000u128, 0x700000u128, ], [0x100000000u128, 0x20100000000u128, 0x4020100000000u128, 0x4020100000000u128, 0x4020100000000u128, 0x4020100000000u128, 0x4020100000000u128, 0x4020100000000u128, ], ], [[0x400000000u128, 0x100400000000u128, 0x40100400000000u128, 0x10040100400000000u128, 0x4010040100400000000u128, 0x1004010040100400000000u128, 0x401004010040100400000000u128, 0x401004010040100400000000u128, ], [0x800000000u128, 0x400800000000u128, 0x200400800000000u128, 0x100200400800000000u128, 0x80100200400800000000u128, 0x80100200400800000000u128, 0x80100200400800000000u128, 0x80100200400800000000u128, ], [0x2000000u128, 0x6000000u128, 0xe000000u128, 0x1e000000u128, 0x3e000000u128, 0x3e000000u128, 0x3e000000u128, 0x3e000000u128, ], [0x8000u128, 0x8040u128, 0x8040u128, 0x8040u128, 0x8040u128, 0x8040u128, 0x8040u128, 0x8040u128, ], [0x4000u128, 0x4010u128, 0x4010u128, 0x4010u128, 0x4010u128, 0x4010u128, 0x4010u128, 0x4010u128, ], [0x2000u128, 0x2004u128, 0x2004u128, 0x2004u128, 0x2004u128, 0x2004u128, 0x2004u128, 0x2004u128, ], [0x800000u128, 0xc00000u128, 0xe00000u128, 0xf00000u128, 0xf00000u128, 0xf00000u128, 0xf00000u128, 0xf00000u128, ], [0x200000000u128, 0x40200000000u128, 0x8040200000000u128, 0x1008040200000000u128, 0x1008040200000000u128, 0x1008040200000000u128, 0x1008040200000000u128, 0x1008040200000000u128, ], ], [[0x800000000u128, 0x200800000000u128, 0x80200800000000u128, 0x20080200800000000u128, 0x8020080200800000000u128, 0x2008020080200800000000u128, 0x802008020080200800000000u128, 0x802008020080200800000000u128, ], [0x1000000000u128, 0x801000000000u128, 0x400801000000000u128, 0x200400801000000000u128, 0x200400801000000000u128, 0x200400801000000000u128, 0x200400801000000000u128, 0x200400801000000000u128, ], [0x4000000u128, 0xc000000u128, 0x1c000000u128, 0x3c000000u128, 0x3c000000u128, 0x3c000000u128, 0x3c000000u128, 0x3c000000u128, ], [0x10000u128, 0x10080u128, 0x10080u128, 0x10080u128, 0x10080u128, 0x10080u128, 0x10080u128, 0x10080u128, ], [0x8000u128, 0x8020u128, 0x8020u128, 0x8020u128, 0x8020u128, 0x8020u128, 0x8020u128, 0x8020u128, ], [0x4000u128, 0x4008u128, 0x4008u128, 0x4008u128, 0x4008u128, 0x4008u128, 0x4008u128, 0x4008u128, ], [0x1000000u128, 0x1800000u128, 0x1c00000u128, 0x1e00000u128, 0x1f00000u128, 0x1f00000u128, 0x1f00000u128, 0x1f00000u128, ], [0x400000000u128, 0x80400000000u128, 0x10080400000000u128, 0x2010080400000000u128, 0x402010080400000000u128, 0x402010080400000000u128, 0x402010080400000000u128, 0x402010080400000000u128, ], ], [[0x1000000000u128, 0x401000000000u128, 0x100401000000000u128, 0x40100401000000000u128, 0x10040100401000000000u128, 0x4010040100401000000000u128, 0x1004010040100401000000000u128, 0x1004010040100401000000000u128, ], [0x2000000000u128, 0x1002000000000u128, 0x801002000000000u128, 0x801002000000000u128, 0x801002000000000u128, 0x801002000000000u128, 0x801002000000000u128, 0x801002000000000u128, ], [0x8000000u128, 0x18000000u128, 0x38000000u128, 0x38000000u128, 0x38000000u128, 0x38000000u128, 0x38000000u128, 0x38000000u128, ], [0x20000u128, 0x20100u128, 0x20100u128, 0x20100u128, 0x20100u128, 0x20100u128, 0x20100u128, 0x20100u128, ], [0x10000u128, 0x10040u128, 0x10040u128, 0x10040u128, 0x10040u128, 0x10040u128, 0x10040u128, 0x10040u128, ], [0x8000u128, 0x8010u128, 0x8010u128, 0x8010u128, 0x8010u128, 0x8010u128, 0x8010u128, 0x8010u128, ], [0x2000000u128, 0x3000000u128, 0x3800000u128, 0x3c00000u128, 0x3e00000u128, 0x3f00000u128, 0x3f00000u128, 0x3f00000u128, ], [0x800000000u128, 0x100800000000u128, 0x20100800000000u128, 0x4020100800000000u128, 0x804020100800000000u128, 0x100804020100800000000u128, 0x100804020100800000000u128, 0x100804020100800000000u128, ], ], [[0x2000000000u128, 0x802000000000u128, 0x200802000000000u128, 0x80200802000000000u128, 0x20080200802000000000u128, 0x8020080200802000000000u128, 0x2008020080200802000000000u128, 0x2008020080200802000000000u128, ], [0x4000000000u128, 0x2004000000000u128, 0x2004000000000u128, 0x2004000000000u128, 0x2004000000000u128, 0x2004000000000u128, 0x2004000000000u128, 0x2004000000000u128, ], [0x10000000u128, 0x30000000u128, 0x30000000u128, 0x30000000u128, 0x30000000u128, 0x30000000u128, 0x30000000u128, 0x30000000u128, ], [0x40000u128, 0x40200u128, 0x40200u128, 0x40200u128, 0x40200u128, 0x40200u128, 0x40200u128, 0x40200u128, ], [0x20000u128, 0x20080u128, 0x20080u128, 0x20080u128, 0x20080u128, 0x20080u128, 0x20080u128, 0x20080u128, ], [0x10000u128, 0x10020u128, 0x10020u128, 0x10020u128, 0x10020u128, 0x10020u128, 0x10020u128, 0x10020u128, ], [0x4000000u128, 0x6000000u128, 0x7000000u128, 0x7800000u128, 0x7c00000u128, 0x7e00000u128, 0x7f00000u128, 0x7f00000u128, ], [0x1000000000u128, 0x201000000000u128, 0x40201000000000u128, 0x8040201000000000u128, 0x1008040201000000000u128, 0x201008040201000000000u128, 0x40201008040201000000000u128, 0x40201008040201000000000u128, ], ], [[0x4000000000u128, 0x1004000000000u128, 0x401004000000000u128, 0x100401004000000000u128, 0x40100401004000000000u128, 0x10040100401004000000000u128, 0x4010040100401004000000000u128, 0x4010040100401004000000000u128, ], [0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, ], [0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, ], [0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, 0x80000u128, ], [0x40000u128, 0x40100u128, 0x40100u128, 0x40100u128, 0x40100u128, 0x40100u128, 0x40100u128, 0x40100u128, ], [0x20000u128, 0x20040u128, 0x20040u128, 0x20040u128, 0x20040u128, 0x20040u128, 0x20040u128, 0x20040u128, ], [0x8000000u128, 0xc000000u128, 0xe000000u128, 0xf000000u128, 0xf800000u128, 0xfc00000u128, 0xfe00000u128, 0xff00000u128, ], [0x2000000000u128, 0x402000000000u128, 0x80402000000000u128, 0x10080402000000000u128, 0x2010080402000000000u128, 0x402010080402000000000u128, 0x80402010080402000000000u128, 0x80402010080402000000000u128, ], ], [[0x8000000000u128, 0x2008000000000u128, 0x802008000000000u128, 0x200802008000000000u128, 0x80200802008000000000u128, 0x20080200802008000000000u128, 0x8020080200802008000000000u128, 0x8020080200802008000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x80000u128, 0x80200u128, 0x80200u128, 0x80200u128, 0x80200u128, 0x80200u128, 0x80200u128, 0x80200u128, ], [0x40000u128, 0x40080u128, 0x40080u128, 0x40080u128, 0x40080u128, 0x40080u128, 0x40080u128, 0x40080u128, ], [0x10000000u128, 0x18000000u128, 0x1c000000u128, 0x1e000000u128, 0x1f000000u128, 0x1f800000u128, 0x1fc00000u128, 0x1fe00000u128, ], [0x4000000000u128, 0x804000000000u128, 0x100804000000000u128, 0x20100804000000000u128, 0x4020100804000000000u128, 0x804020100804000000000u128, 0x100804020100804000000000u128, 0x100804020100804000000000u128, ], ], [[0x10000000000u128, 0x4010000000000u128, 0x1004010000000000u128, 0x401004010000000000u128, 0x100401004010000000000u128, 0x40100401004010000000000u128, 0x40100401004010000000000u128, 0x40100401004010000000000u128, ], [0x20000000000u128, 0x10020000000000u128, 0x8010020000000000u128, 0x4008010020000000000u128, 0x2004008010020000000000u128, 0x1002004008010020000000000u128, 0x1002004008010020000000000u128, 0x1002004008010020000000000u128, ], [0x80000000u128, 0x180000000u128, 0x380000000u128, 0x780000000u128, 0xf80000000u128, 0x1f80000000u128, 0x3f80000000u128, 0x7f80000000u128, ], [0x200000u128, 0x201000u128, 0x201008u128, 0x201008u128, 0x201008u128, 0x201008u128, 0x201008u128, 0x201008u128, ], [0x100000u128, 0x100400u128, 0x100401u128, 0x100401u128, 0x100401u128, 0x100401u128, 0x100401u128, 0x100401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x20000000000u128, 0x8020000000000u128, 0x2008020000000000u128, 0x802008020000000000u128, 0x200802008020000000000u128, 0x80200802008020000000000u128, 0x80200802008020000000000u128, 0x80200802008020000000000u128, ], [0x40000000000u128, 0x20040000000000u128, 0x10020040000000000u128, 0x8010020040000000000u128, 0x4008010020040000000000u128, 0x2004008010020040000000000u128, 0x2004008010020040000000000u128, 0x2004008010020040000000000u128, ], [0x100000000u128, 0x300000000u128, 0x700000000u128, 0xf00000000u128, 0x1f00000000u128, 0x3f00000000u128, 0x7f00000000u128, 0xff00000000u128, ], [0x400000u128, 0x402000u128, 0x402010u128, 0x402010u128, 0x402010u128, 0x402010u128, 0x402010u128, 0x402010u128, ], [0x200000u128, 0x200800u128, 0x200802u128, 0x200802u128, 0x200802u128, 0x200802u128, 0x200802u128, 0x200802u128, ], [0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, 0x100000u128, ], [0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, ], [0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, ], ], [[0x40000000000u128, 0x10040000000000u128, 0x4010040000000000u128, 0x1004010040000000000u128, 0x401004010040000000000u128, 0x100401004010040000000000u128, 0x100401004010040000000000u128, 0x100401004010040000000000u128, ], [0x80000000000u128, 0x40080000000000u128, 0x20040080000000000u128, 0x10020040080000000000u128, 0x8010020040080000000000u128, 0x4008010020040080000000000u128, 0x4008010020040080000000000u128, 0x4008010020040080000000000u128, ], [0x200000000u128, 0x600000000u128, 0xe00000000u128, 0x1e00000000u128, 0x3e00000000u128, 0x7e00000000u128, 0xfe00000000u128, 0xfe00000000u128, ], [0x800000u128, 0x804000u128, 0x804020u128, 0x804020u128, 0x804020u128, 0x804020u128, 0x804020u128, 0x804020u128, ], [0x400000u128, 0x401000u128, 0x401004u128, 0x401004u128, 0x401004u128, 0x401004u128, 0x401004u128, 0x401004u128, ], [0x200000u128, 0x200400u128, 0x200400u128, 0x200400u128, 0x200400u128, 0x200400u128, 0x200400u128, 0x200400u128, ], [0x80000000u128, 0xc0000000u128, 0xc0000000u128, 0xc0000000u128, 0xc0000000u128, 0xc0000000u128, 0xc0000000u128, 0xc0000000u128, ], [0x20000000000u128, 0x4020000000000u128, 0x4020000000000u128, 0x4020000000000u128, 0x4020000000000u128, 0x4020000000000u128, 0x4020000000000u128, 0x4020000000000u128, ], ], [[0x80000000000u128, 0x20080000000000u128, 0x8020080000000000u128, 0x2008020080000000000u128, 0x802008020080000000000u128, 0x200802008020080000000000u128, 0x200802008020080000000000u128, 0x200802008020080000000000u128, ], [0x100000000000u128, 0x80100000000000u128, 0x40080100000000000u128, 0x20040080100000000000u128, 0x10020040080100000000000u128, 0x8010020040080100000000000u128, 0x8010020040080100000000000u128, 0x8010020040080100000000000u128, ], [0x400000000u128, 0xc00000000u128, 0x1c00000000u128, 0x3c00000000u128, 0x7c00000000u128, 0xfc00000000u128, 0xfc00000000u128, 0xfc00000000u128, ], [0x1000000u128, 0x1008000u128, 0x1008040u128, 0x1008040u128, 0x1008040u128, 0x1008040u128, 0x1008040u128, 0x1008040u128, ], [0x800000u128, 0x802000u128, 0x802008u128, 0x802008u128, 0x802008u128, 0x802008u128, 0x802008u128, 0x802008u128, ], [0x400000u128, 0x400800u128, 0x400801u128, 0x400801u128, 0x400801u128, 0x400801u128, 0x400801u128, 0x400801u128, ], [0x100000000u128, 0x180000000u128, 0x1c0000000u128, 0x1c0000000u128, 0x1c0000000u128, 0x1c0000000u128, 0x1c0000000u128, 0x1c0000000u128, ], [0x40000000000u128, 0x8040000000000u128, 0x1008040000000000u128, 0x1008040000000000u128, 0x1008040000000000u128, 0x1008040000000000u128, 0x1008040000000000u128, 0x1008040000000000u128, ], ], [[0x100000000000u128, 0x40100000000000u128, 0x10040100000000000u128, 0x4010040100000000000u128, 0x1004010040100000000000u128, 0x401004010040100000000000u128, 0x401004010040100000000000u128, 0x401004010040100000000000u128, ], [0x200000000000u128, 0x100200000000000u128, 0x80100200000000000u128, 0x40080100200000000000u128, 0x20040080100200000000000u128, 0x20040080100200000000000u128, 0x20040080100200000000000u128, 0x20040080100200000000000u128, ], [0x800000000u128, 0x1800000000u128, 0x3800000000u128, 0x7800000000u128, 0xf800000000u128, 0xf800000000u128, 0xf800000000u128, 0xf800000000u128, ], [0x2000000u128, 0x2010000u128, 0x2010080u128, 0x2010080u128, 0x2010080u128, 0x2010080u128, 0x2010080u128, 0x2010080u128, ], [0x1000000u128, 0x1004000u128, 0x1004010u128, 0x1004010u128, 0x1004010u128, 0x1004010u128, 0x1004010u128, 0x1004010u128, ], [0x800000u128, 0x801000u128, 0x801002u128, 0x801002u128, 0x801002u128, 0x801002u128, 0x801002u128, 0x801002u128, ], [0x200000000u128, 0x300000000u128, 0x380000000u128, 0x3c0000000u128, 0x3c0000000u128, 0x3c0000000u128, 0x3c0000000u128, 0x3c0000000u128, ], [0x80000000000u128, 0x10080000000000u128, 0x2010080000000000u128, 0x402010080000000000u128, 0x402010080000000000u128, 0x402010080000000000u128, 0x402010080000000000u128, 0x402010080000000000u128, ], ], [[0x200000000000u128, 0x80200000000000u128, 0x20080200000000000u128, 0x8020080200000000000u128, 0x2008020080200000000000u128, 0x802008020080200000000000u128, 0x802008020080200000000000u128, 0x802008020080200000000000u128, ], [0x400000000000u128, 0x200400000000000u128, 0x100200400000000000u128, 0x80100200400000000000u128, 0x80100200400000000000u128, 0x80100200400000000000u128, 0x80100200400000000000u128, 0x80100200400000000000u128, ], [0x1000000000u128, 0x3000000000u128, 0x7000000000u128, 0xf000000000u128, 0xf000000000u128, 0xf000000000u128, 0xf000000000u128, 0xf000000000u128, ], [0x4000000u128, 0x4020000u128, 0x4020100u128, 0x4020100u128, 0x4020100u128, 0x4020100u128, 0x4020100u128, 0x4020100u128, ], [0x2000000u128, 0x2008000u128, 0x2008020u128, 0x2008020u128, 0x2008020u128, 0x2008020u128, 0x2008020u128, 0x2008020u128, ], [0x1000000u128, 0x1002000u128, 0x1002004u128, 0x1002004u128, 0x1002004u128, 0x1002004u128, 0x1002004u128, 0x1002004u128, ], [0x400000000u128, 0x600000000u128, 0x700000000u128, 0x780000000u128, 0x7c0000000u128, 0x7c0000000u128, 0x7c0000000u128, 0x7c0000000u128, ], [0x100000000000u128, 0x20100000000000u128, 0x4020100000000000u128, 0x804020100000000000u128, 0x100804020100000000000u128, 0x100804020100000000000u128, 0x100804020100000000000u128, 0x100804020100000000000u128, ], ], [[0x400000000000u128, 0x100400000000000u128, 0x40100400000000000u128, 0x10040100400000000000u128, 0x4010040100400000000000u128, 0x1004010040100400000000000u128, 0x1004010040100400000000000u128, 0x1004010040100400000000000u128, ], [0x800000000000u128, 0x400800000000000u128, 0x200400800000000000u128, 0x200400800000000000u128, 0x200400800000000000u128, 0x200400800000000000u128, 0x200400800000000000u128, 0x200400800000000000u128, ], [0x2000000000u128, 0x6000000000u128, 0xe000000000u128, 0xe000000000u128, 0xe000000000u128, 0xe000000000u128, 0xe000000000u128, 0xe000000000u128, ], [0x8000000u128, 0x8040000u128, 0x8040200u128, 0x8040200u128, 0x8040200u128, 0x8040200u128, 0x8040200u128, 0x8040200u128, ], [0x4000000u128, 0x4010000u128, 0x4010040u128, 0x4010040u128, 0x4010040u128, 0x4010040u128, 0x4010040u128, 0x4010040u128, ], [0x2000000u128, 0x2004000u128, 0x2004008u128, 0x2004008u128, 0x2004008u128, 0x2004008u128, 0x2004008u128, 0x2004008u128, ], [0x800000000u128, 0xc00000000u128, 0xe00000000u128, 0xf00000000u128, 0xf80000000u128, 0xfc0000000u128, 0xfc0000000u128, 0xfc0000000u128, ], [0x200000000000u128, 0x40200000000000u128, 0x8040200000000000u128, 0x1008040200000000000u128, 0x201008040200000000000u128, 0x40201008040200000000000u128, 0x40201008040200000000000u128, 0x40201008040200000000000u128, ], ], [[0x800000000000u128, 0x200800000000000u128, 0x80200800000000000u128, 0x20080200800000000000u128, 0x8020080200800000000000u128, 0x2008020080200800000000000u128, 0x2008020080200800000000000u128, 0x2008020080200800000000000u128, ], [0x1000000000000u128, 0x801000000000000u128, 0x801000000000000u128, 0x801000000000000u128, 0x801000000000000u128, 0x801000000000000u128, 0x801000000000000u128, 0x801000000000000u128, ], [0x4000000000u128, 0xc000000000u128, 0xc000000000u128, 0xc000000000u128, 0xc000000000u128, 0xc000000000u128, 0xc000000000u128, 0xc000000000u128, ], [0x10000000u128, 0x10080000u128, 0x10080000u128, 0x10080000u128, 0x10080000u128, 0x10080000u128, 0x10080000u128, 0x10080000u128, ], [0x8000000u128, 0x8020000u128, 0x8020080u128, 0x8020080u128, 0x8020080u128, 0x8020080u128, 0x8020080u128, 0x8020080u128, ], [0x4000000u128, 0x4008000u128, 0x4008010u128, 0x4008010u128, 0x4008010u128, 0x4008010u128, 0x4008010u128, 0x4008010u128, ], [0x1000000000u128, 0x1800000000u128, 0x1c00000000u128, 0x1e00000000u128, 0x1f00000000u128, 0x1f80000000u128, 0x1fc0000000u128, 0x1fc0000000u128, ], [0x400000000000u128, 0x80400000000000u128, 0x10080400000000000u128, 0x2010080400000000000u128, 0x402010080400000000000u128, 0x80402010080400000000000u128, 0x80402010080400000000000u128, 0x80402010080400000000000u128, ], ], [[0x1000000000000u128, 0x401000000000000u128, 0x100401000000000000u128, 0x40100401000000000000u128, 0x10040100401000000000000u128, 0x4010040100401000000000000u128, 0x4010040100401000000000000u128, 0x4010040100401000000000000u128, ], [0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, ], [0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, ], [0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, 0x20000000u128, ], [0x10000000u128, 0x10040000u128, 0x10040100u128, 0x10040100u128, 0x10040100u128, 0x10040100u128, 0x10040100u128, 0x10040100u128, ], [0x8000000u128, 0x8010000u128, 0x8010020u128, 0x8010020u128, 0x8010020u128, 0x8010020u128, 0x8010020u128, 0x8010020u128, ], [0x2000000000u128, 0x3000000000u128, 0x3800000000u128, 0x3c00000000u128, 0x3e00000000u128, 0x3f00000000u128, 0x3f80000000u128, 0x3fc0000000u128, ], [0x800000000000u128, 0x100800000000000u128, 0x20100800000000000u128, 0x4020100800000000000u128, 0x804020100800000000000u128, 0x100804020100800000000000u128, 0x100804020100800000000000u128, 0x100804020100800000000000u128, ], ], [[0x2000000000000u128, 0x802000000000000u128, 0x200802000000000000u128, 0x80200802000000000000u128, 0x20080200802000000000000u128, 0x8020080200802000000000000u128, 0x8020080200802000000000000u128, 0x8020080200802000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x20000000u128, 0x20080000u128, 0x20080200u128, 0x20080200u128, 0x20080200u128, 0x20080200u128, 0x20080200u128, 0x20080200u128, ], [0x10000000u128, 0x10020000u128, 0x10020040u128, 0x10020040u128, 0x10020040u128, 0x10020040u128, 0x10020040u128, 0x10020040u128, ], [0x4000000000u128, 0x6000000000u128, 0x7000000000u128, 0x7800000000u128, 0x7c00000000u128, 0x7e00000000u128, 0x7f00000000u128, 0x7f80000000u128, ], [0x1000000000000u128, 0x201000000000000u128, 0x40201000000000000u128, 0x8040201000000000000u128, 0x1008040201000000000000u128, 0x201008040201000000000000u128, 0x201008040201000000000000u128, 0x201008040201000000000000u128, ], ], [[0x4000000000000u128, 0x1004000000000000u128, 0x401004000000000000u128, 0x100401004000000000000u128, 0x40100401004000000000000u128, 0x40100401004000000000000u128, 0x40100401004000000000000u128, 0x40100401004000000000000u128, ], [0x8000000000000u128, 0x4008000000000000u128, 0x2004008000000000000u128, 0x1002004008000000000000u128, 0x801002004008000000000000u128, 0x801002004008000000000000u128, 0x801002004008000000000000u128, 0x801002004008000000000000u128, ], [0x20000000000u128, 0x60000000000u128, 0xe0000000000u128, 0x1e0000000000u128, 0x3e0000000000u128, 0x7e0000000000u128, 0xfe0000000000u128, 0x1fe0000000000u128, ], [0x80000000u128, 0x80400000u128, 0x80402000u128, 0x80402010u128, 0x80402010u128, 0x80402010u128, 0x80402010u128, 0x80402010u128, ], [0x40000000u128, 0x40100000u128, 0x40100400u128, 0x40100401u128, 0x40100401u128, 0x40100401u128, 0x40100401u128, 0x40100401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x8000000000000u128, 0x2008000000000000u128, 0x802008000000000000u128, 0x200802008000000000000u128, 0x80200802008000000000000u128, 0x80200802008000000000000u128, 0x80200802008000000000000u128, 0x80200802008000000000000u128, ], [0x10000000000000u128, 0x8010000000000000u128, 0x4008010000000000000u128, 0x2004008010000000000000u128, 0x1002004008010000000000000u128, 0x1002004008010000000000000u128, 0x1002004008010000000000000u128, 0x1002004008010000000000000u128, ], [0x40000000000u128, 0xc0000000000u128, 0x1c0000000000u128, 0x3c0000000000u128, 0x7c0000000000u128, 0xfc0000000000u128, 0x1fc0000000000u128, 0x3fc0000000000u128, ], [0x100000000u128, 0x100800000u128, 0x100804000u128, 0x100804020u128, 0x100804020u128, 0x100804020u128, 0x100804020u128, 0x100804020u128, ], [0x80000000u128, 0x80200000u128, 0x80200800u128, 0x80200802u128, 0x80200802u128, 0x80200802u128, 0x80200802u128, 0x80200802u128, ], [0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, 0x40000000u128, ], [0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, ], [0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, ], ], [[0x10000000000000u128, 0x4010000000000000u128, 0x1004010000000000000u128, 0x401004010000000000000u128, 0x100401004010000000000000u128, 0x100401004010000000000000u128, 0x100401004010000000000000u128, 0x100401004010000000000000u128, ], [0x20000000000000u128, 0x10020000000000000u128, 0x8010020000000000000u128, 0x4008010020000000000000u128, 0x2004008010020000000000000u128, 0x2004008010020000000000000u128, 0x2004008010020000000000000u128, 0x2004008010020000000000000u128, ], [0x80000000000u128, 0x180000000000u128, 0x380000000000u128, 0x780000000000u128, 0xf80000000000u128, 0x1f80000000000u128, 0x3f80000000000u128, 0x3f80000000000u128, ], [0x200000000u128, 0x201000000u128, 0x201008000u128, 0x201008040u128, 0x201008040u128, 0x201008040u128, 0x201008040u128, 0x201008040u128, ], [0x100000000u128, 0x100400000u128, 0x100401000u128, 0x100401004u128, 0x100401004u128, 0x100401004u128, 0x100401004u128, 0x100401004u128, ], [0x80000000u128, 0x80100000u128, 0x80100000u128, 0x80100000u128, 0x80100000u128, 0x80100000u128, 0x80100000u128, 0x80100000u128, ], [0x20000000000u128, 0x30000000000u128, 0x30000000000u128, 0x30000000000u128, 0x30000000000u128, 0x30000000000u128, 0x30000000000u128, 0x30000000000u128, ], [0x8000000000000u128, 0x1008000000000000u128, 0x1008000000000000u128, 0x1008000000000000u128, 0x1008000000000000u128, 0x1008000000000000u128, 0x1008000000000000u128, 0x1008000000000000u128, ], ], [[0x20000000000000u128, 0x8020000000000000u128, 0x2008020000000000000u128, 0x802008020000000000000u128, 0x200802008020000000000000u128, 0x200802008020000000000000u128, 0x200802008020000000000000u128, 0x200802008020000000000000u128, ], [0x40000000000000u128, 0x20040000000000000u128, 0x10020040000000000000u128, 0x8010020040000000000000u128, 0x4008010020040000000000000u128, 0x4008010020040000000000000u128, 0x4008010020040000000000000u128, 0x4008010020040000000000000u128, ], [0x100000000000u128, 0x300000000000u128, 0x700000000000u128, 0xf00000000000u128, 0x1f00000000000u128, 0x3f00000000000u128, 0x3f00000000000u128, 0x3f00000000000u128, ], [0x400000000u128, 0x402000000u128, 0x402010000u128, 0x402010080u128, 0x402010080u128, 0x402010080u128, 0x402010080u128, 0x402010080u128, ], [0x200000000u128, 0x200800000u128, 0x200802000u128, 0x200802008u128, 0x200802008u128, 0x200802008u128, 0x200802008u128, 0x200802008u128, ], [0x100000000u128, 0x100200000u128, 0x100200400u128, 0x100200400u128, 0x100200400u128, 0x100200400u128, 0x100200400u128, 0x100200400u128, ], [0x40000000000u128, 0x60000000000u128, 0x70000000000u128, 0x70000000000u128, 0x70000000000u128, 0x70000000000u128, 0x70000000000u128, 0x70000000000u128, ], [0x10000000000000u128, 0x2010000000000000u128, 0x402010000000000000u128, 0x402010000000000000u128, 0x402010000000000000u128, 0x402010000000000000u128, 0x402010000000000000u128, 0x402010000000000000u128, ], ], [[0x40000000000000u128, 0x10040000000000000u128, 0x4010040000000000000u128, 0x1004010040000000000000u128, 0x401004010040000000000000u128, 0x401004010040000000000000u128, 0x401004010040000000000000u128, 0x401004010040000000000000u128, ], [0x80000000000000u128, 0x40080000000000000u128, 0x20040080000000000000u128, 0x10020040080000000000000u128, 0x8010020040080000000000000u128, 0x8010020040080000000000000u128, 0x8010020040080000000000000u128, 0x8010020040080000000000000u128, ], [0x200000000000u128, 0x600000000000u128, 0xe00000000000u128, 0x1e00000000000u128, 0x3e00000000000u128, 0x3e00000000000u128, 0x3e00000000000u128, 0x3e00000000000u128, ], [0x800000000u128, 0x804000000u128, 0x804020000u128, 0x804020100u128, 0x804020100u128, 0x804020100u128, 0x804020100u128, 0x804020100u128, ], [0x400000000u128, 0x401000000u128, 0x401004000u128, 0x401004010u128, 0x401004010u128, 0x401004010u128, 0x401004010u128, 0x401004010u128, ], [0x200000000u128, 0x200400000u128, 0x200400800u128, 0x200400801u128, 0x200400801u128, 0x200400801u128, 0x200400801u128, 0x200400801u128, ], [0x80000000000u128, 0xc0000000000u128, 0xe0000000000u128, 0xf0000000000u128, 0xf0000000000u128, 0xf0000000000u128, 0xf0000000000u128, 0xf0000000000u128, ], [0x20000000000000u128, 0x4020000000000000u128, 0x804020000000000000u128, 0x100804020000000000000u128, 0x100804020000000000000u128, 0x100804020000000000000u128, 0x100804020000000000000u128, 0x100804020000000000000u128, ], ], [[0x80000000000000u128, 0x20080000000000000u128, 0x8020080000000000000u128, 0x2008020080000000000000u128, 0x802008020080000000000000u128, 0x802008020080000000000000u128, 0x802008020080000000000000u128, 0x802008020080000000000000u128, ], [0x100000000000000u128, 0x80100000000000000u128, 0x40080100000000000000u128, 0x20040080100000000000000u128, 0x20040080100000000000000u128, 0x20040080100000000000000u128, 0x20040080100000000000000u128, 0x20040080100000000000000u128, ], [0x400000000000u128, 0xc00000000000u128, 0x1c00000000000u128, 0x3c00000000000u128, 0x3c00000000000u128, 0x3c00000000000u128, 0x3c00000000000u128, 0x3c00000000000u128, ], [0x1000000000u128, 0x1008000000u128, 0x1008040000u128, 0x1008040200u128, 0x1008040200u128, 0x1008040200u128, 0x1008040200u128, 0x1008040200u128, ], [0x800000000u128, 0x802000000u128, 0x802008000u128, 0x802008020u128, 0x802008020u128, 0x802008020u128, 0x802008020u128, 0x802008020u128, ], [0x400000000u128, 0x400800000u128, 0x400801000u128, 0x400801002u128, 0x400801002u128, 0x400801002u128, 0x400801002u128, 0x400801002u128, ], [0x100000000000u128, 0x180000000000u128, 0x1c0000000000u128, 0x1e0000000000u128, 0x1f0000000000u128, 0x1f0000000000u128, 0x1f0000000000u128, 0x1f0000000000u128, ], [0x40000000000000u128, 0x8040000000000000u128, 0x1008040000000000000u128, 0x201008040000000000000u128, 0x40201008040000000000000u128, 0x40201008040000000000000u128, 0x40201008040000000000000u128, 0x40201008040000000000000u128, ], ], [[0x100000000000000u128, 0x40100000000000000u128, 0x10040100000000000000u128, 0x4010040100000000000000u128, 0x1004010040100000000000000u128, 0x1004010040100000000000000u128, 0x1004010040100000000000000u128, 0x1004010040100000000000000u128, ], [0x200000000000000u128, 0x100200000000000000u128, 0x80100200000000000000u128, 0x80100200000000000000u128, 0x80100200000000000000u128, 0x80100200000000000000u128, 0x80100200000000000000u128, 0x80100200000000000000u128, ], [0x800000000000u128, 0x1800000000000u128, 0x3800000000000u128, 0x3800000000000u128, 0x3800000000000u128, 0x3800000000000u128, 0x3800000000000u128, 0x3800000000000u128, ], [0x2000000000u128, 0x2010000000u128, 0x2010080000u128, 0x2010080000u128, 0x2010080000u128, 0x2010080000u128, 0x2010080000u128, 0x2010080000u128, ], [0x1000000000u128, 0x1004000000u128, 0x1004010000u128, 0x1004010040u128, 0x1004010040u128, 0x1004010040u128, 0x1004010040u128, 0x1004010040u128, ], [0x800000000u128, 0x801000000u128, 0x801002000u128, 0x801002004u128, 0x801002004u128, 0x801002004u128, 0x801002004u128, 0x801002004u128, ], [0x200000000000u128, 0x300000000000u128, 0x380000000000u128, 0x3c0000000000u128, 0x3e0000000000u128, 0x3f0000000000u128, 0x3f0000000000u128, 0x3f0000000000u128, ], [0x80000000000000u128, 0x10080000000000000u128, 0x2010080000000000000u128, 0x402010080000000000000u128, 0x80402010080000000000000u128, 0x80402010080000000000000u128, 0x80402010080000000000000u128, 0x80402010080000000000000u128, ], ], [[0x200000000000000u128, 0x80200000000000000u128, 0x20080200000000000000u128, 0x8020080200000000000000u128, 0x2008020080200000000000000u128, 0x2008020080200000000000000u128, 0x2008020080200000000000000u128, 0x2008020080200000000000000u128, ], [0x400000000000000u128, 0x200400000000000000u128, 0x200400000000000000u128, 0x200400000000000000u128, 0x200400000000000000u128, 0x200400000000000000u128, 0x200400000000000000u128, 0x200400000000000000u128, ], [0x1000000000000u128, 0x3000000000000u128, 0x3000000000000u128, 0x3000000000000u128, 0x3000000000000u128, 0x3000000000000u128, 0x3000000000000u128, 0x3000000000000u128, ], [0x4000000000u128, 0x4020000000u128, 0x4020000000u128, 0x4020000000u128, 0x4020000000u128, 0x4020000000u128, 0x4020000000u128, 0x4020000000u128, ], [0x2000000000u128, 0x2008000000u128, 0x2008020000u128, 0x2008020080u128, 0x2008020080u128, 0x2008020080u128, 0x2008020080u128, 0x2008020080u128, ], [0x1000000000u128, 0x1002000000u128, 0x1002004000u128, 0x1002004008u128, 0x1002004008u128, 0x1002004008u128, 0x1002004008u128, 0x1002004008u128, ], [0x400000000000u128, 0x600000000000u128, 0x700000000000u128, 0x780000000000u128, 0x7c0000000000u128, 0x7e0000000000u128, 0x7f0000000000u128, 0x7f0000000000u128, ], [0x100000000000000u128, 0x20100000000000000u128, 0x4020100000000000000u128, 0x804020100000000000000u128, 0x100804020100000000000000u128, 0x100804020100000000000000u128, 0x100804020100000000000000u128, 0x100804020100000000000000u128, ], ], [[0x400000000000000u128, 0x100400000000000000u128, 0x40100400000000000000u128, 0x10040100400000000000000u128, 0x4010040100400000000000000u128, 0x4010040100400000000000000u128, 0x4010040100400000000000000u128, 0x4010040100400000000000000u128, ], [0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, ], [0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, ], [0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, 0x8000000000u128, ], [0x4000000000u128, 0x4010000000u128, 0x4010040000u128, 0x4010040100u128, 0x4010040100u128, 0x4010040100u128, 0x4010040100u128, 0x4010040100u128, ], [0x2000000000u128, 0x2004000000u128, 0x2004008000u128, 0x2004008010u128, 0x2004008010u128, 0x2004008010u128, 0x2004008010u128, 0x2004008010u128, ], [0x800000000000u128, 0xc00000000000u128, 0xe00000000000u128, 0xf00000000000u128, 0xf80000000000u128, 0xfc0000000000u128, 0xfe0000000000u128, 0xff0000000000u128, ], [0x200000000000000u128, 0x40200000000000000u128, 0x8040200000000000000u128, 0x1008040200000000000000u128, 0x201008040200000000000000u128, 0x201008040200000000000000u128, 0x201008040200000000000000u128, 0x201008040200000000000000u128, ], ], [[0x800000000000000u128, 0x200800000000000000u128, 0x80200800000000000000u128, 0x20080200800000000000000u128, 0x8020080200800000000000000u128, 0x8020080200800000000000000u128, 0x8020080200800000000000000u128, 0x8020080200800000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x8000000000u128, 0x8020000000u128, 0x8020080000u128, 0x8020080200u128, 0x8020080200u128, 0x8020080200u128, 0x8020080200u128, 0x8020080200u128, ], [0x4000000000u128, 0x4008000000u128, 0x4008010000u128, 0x4008010020u128, 0x4008010020u128, 0x4008010020u128, 0x4008010020u128, 0x4008010020u128, ], [0x1000000000000u128, 0x1800000000000u128, 0x1c00000000000u128, 0x1e00000000000u128, 0x1f00000000000u128, 0x1f80000000000u128, 0x1fc0000000000u128, 0x1fe0000000000u128, ], [0x400000000000000u128, 0x80400000000000000u128, 0x10080400000000000000u128, 0x2010080400000000000000u128, 0x402010080400000000000000u128, 0x402010080400000000000000u128, 0x402010080400000000000000u128, 0x402010080400000000000000u128, ], ], [[0x1000000000000000u128, 0x401000000000000000u128, 0x100401000000000000000u128, 0x40100401000000000000000u128, 0x40100401000000000000000u128, 0x40100401000000000000000u128, 0x40100401000000000000000u128, 0x40100401000000000000000u128, ], [0x2000000000000000u128, 0x1002000000000000000u128, 0x801002000000000000000u128, 0x400801002000000000000000u128, 0x400801002000000000000000u128, 0x400801002000000000000000u128, 0x400801002000000000000000u128, 0x400801002000000000000000u128, ], [0x8000000000000u128, 0x18000000000000u128, 0x38000000000000u128, 0x78000000000000u128, 0xf8000000000000u128, 0x1f8000000000000u128, 0x3f8000000000000u128, 0x7f8000000000000u128, ], [0x20000000000u128, 0x20100000000u128, 0x20100800000u128, 0x20100804000u128, 0x20100804020u128, 0x20100804020u128, 0x20100804020u128, 0x20100804020u128, ], [0x10000000000u128, 0x10040000000u128, 0x10040100000u128, 0x10040100400u128, 0x10040100401u128, 0x10040100401u128, 0x10040100401u128, 0x10040100401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x2000000000000000u128, 0x802000000000000000u128, 0x200802000000000000000u128, 0x80200802000000000000000u128, 0x80200802000000000000000u128, 0x80200802000000000000000u128, 0x80200802000000000000000u128, 0x80200802000000000000000u128, ], [0x4000000000000000u128, 0x2004000000000000000u128, 0x1002004000000000000000u128, 0x801002004000000000000000u128, 0x801002004000000000000000u128, 0x801002004000000000000000u128, 0x801002004000000000000000u128, 0x801002004000000000000000u128, ], [0x10000000000000u128, 0x30000000000000u128, 0x70000000000000u128, 0xf0000000000000u128, 0x1f0000000000000u128, 0x3f0000000000000u128, 0x7f0000000000000u128, 0xff0000000000000u128, ], [0x40000000000u128, 0x40200000000u128, 0x40201000000u128, 0x40201008000u128, 0x40201008040u128, 0x40201008040u128, 0x40201008040u128, 0x40201008040u128, ], [0x20000000000u128, 0x20080000000u128, 0x20080200000u128, 0x20080200800u128, 0x20080200802u128, 0x20080200802u128, 0x20080200802u128, 0x20080200802u128, ], [0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, 0x10000000000u128, ], [0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, ], [0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, ], ], [[0x4000000000000000u128, 0x1004000000000000000u128, 0x401004000000000000000u128, 0x100401004000000000000000u128, 0x100401004000000000000000u128, 0x100401004000000000000000u128, 0x100401004000000000000000u128, 0x100401004000000000000000u128, ], [0x8000000000000000u128, 0x4008000000000000000u128, 0x2004008000000000000000u128, 0x1002004008000000000000000u128, 0x1002004008000000000000000u128, 0x1002004008000000000000000u128, 0x1002004008000000000000000u128, 0x1002004008000000000000000u128, ], [0x20000000000000u128, 0x60000000000000u128, 0xe0000000000000u128, 0x1e0000000000000u128, 0x3e0000000000000u128, 0x7e0000000000000u128, 0xfe0000000000000u128, 0xfe0000000000000u128, ], [0x80000000000u128, 0x80400000000u128, 0x80402000000u128, 0x80402010000u128, 0x80402010080u128, 0x80402010080u128, 0x80402010080u128, 0x80402010080u128, ], [0x40000000000u128, 0x40100000000u128, 0x40100400000u128, 0x40100401000u128, 0x40100401004u128, 0x40100401004u128, 0x40100401004u128, 0x40100401004u128, ], [0x20000000000u128, 0x20040000000u128, 0x20040000000u128, 0x20040000000u128, 0x20040000000u128, 0x20040000000u128, 0x20040000000u128, 0x20040000000u128, ], [0x8000000000000u128, 0xc000000000000u128, 0xc000000000000u128, 0xc000000000000u128, 0xc000000000000u128, 0xc000000000000u128, 0xc000000000000u128, 0xc000000000000u128, ], [0x2000000000000000u128, 0x402000000000000000u128, 0x402000000000000000u128, 0x402000000000000000u128, 0x402000000000000000u128, 0x402000000000000000u128, 0x402000000000000000u128, 0x402000000000000000u128, ], ], [[0x8000000000000000u128, 0x2008000000000000000u128, 0x802008000000000000000u128, 0x200802008000000000000000u128, 0x200802008000000000000000u128, 0x200802008000000000000000u128, 0x200802008000000000000000u128, 0x200802008000000000000000u128, ], [0x10000000000000000u128, 0x8010000000000000000u128, 0x4008010000000000000000u128, 0x2004008010000000000000000u128, 0x2004008010000000000000000u128, 0x2004008010000000000000000u128, 0x2004008010000000000000000u128, 0x2004008010000000000000000u128, ], [0x40000000000000u128, 0xc0000000000000u128, 0x1c0000000000000u128, 0x3c0000000000000u128, 0x7c0000000000000u128, 0xfc0000000000000u128, 0xfc0000000000000u128, 0xfc0000000000000u128, ], [0x100000000000u128, 0x100800000000u128, 0x100804000000u128, 0x100804020000u128, 0x100804020100u128, 0x100804020100u128, 0x100804020100u128, 0x100804020100u128, ], [0x80000000000u128, 0x80200000000u128, 0x80200800000u128, 0x80200802000u128, 0x80200802008u128, 0x80200802008u128, 0x80200802008u128, 0x80200802008u128, ], [0x40000000000u128, 0x40080000000u128, 0x40080100000u128, 0x40080100000u128, 0x40080100000u128, 0x40080100000u128, 0x40080100000u128, 0x40080100000u128, ], [0x10000000000000u128, 0x18000000000000u128, 0x1c000000000000u128, 0x1c000000000000u128, 0x1c000000000000u128, 0x1c000000000000u128, 0x1c000000000000u128, 0x1c000000000000u128, ], [0x4000000000000000u128, 0x804000000000000000u128, 0x100804000000000000000u128, 0x100804000000000000000u128, 0x100804000000000000000u128, 0x100804000000000000000u128, 0x100804000000000000000u128, 0x100804000000000000000u128, ], ], [[0x10000000000000000u128, 0x4010000000000000000u128, 0x1004010000000000000000u128, 0x401004010000000000000000u128, 0x401004010000000000000000u128, 0x401004010000000000000000u128, 0x401004010000000000000000u128, 0x401004010000000000000000u128, ], [0x20000000000000000u128, 0x10020000000000000000u128, 0x8010020000000000000000u128, 0x4008010020000000000000000u128, 0x4008010020000000000000000u128, 0x4008010020000000000000000u128, 0x4008010020000000000000000u128, 0x4008010020000000000000000u128, ], [0x80000000000000u128, 0x180000000000000u128, 0x380000000000000u128, 0x780000000000000u128, 0xf80000000000000u128, 0xf80000000000000u128, 0xf80000000000000u128, 0xf80000000000000u128, ], [0x200000000000u128, 0x201000000000u128, 0x201008000000u128, 0x201008040000u128, 0x201008040200u128, 0x201008040200u128, 0x201008040200u128, 0x201008040200u128, ], [0x100000000000u128, 0x100400000000u128, 0x100401000000u128, 0x100401004000u128, 0x100401004010u128, 0x100401004010u128, 0x100401004010u128, 0x100401004010u128, ], [0x80000000000u128, 0x80100000000u128, 0x80100200000u128, 0x80100200400u128, 0x80100200400u128, 0x80100200400u128, 0x80100200400u128, 0x80100200400u128, ], [0x20000000000000u128, 0x30000000000000u128, 0x38000000000000u128, 0x3c000000000000u128, 0x3c000000000000u128, 0x3c000000000000u128, 0x3c000000000000u128, 0x3c000000000000u128, ], [0x8000000000000000u128, 0x1008000000000000000u128, 0x201008000000000000000u128, 0x40201008000000000000000u128, 0x40201008000000000000000u128, 0x40201008000000000000000u128, 0x40201008000000000000000u128, 0x40201008000000000000000u128, ], ], [[0x20000000000000000u128, 0x8020000000000000000u128, 0x2008020000000000000000u128, 0x802008020000000000000000u128, 0x802008020000000000000000u128, 0x802008020000000000000000u128, 0x802008020000000000000000u128, 0x802008020000000000000000u128, ], [0x40000000000000000u128, 0x20040000000000000000u128, 0x10020040000000000000000u128, 0x8010020040000000000000000u128, 0x8010020040000000000000000u128, 0x8010020040000000000000000u128, 0x8010020040000000000000000u128, 0x8010020040000000000000000u128, ], [0x100000000000000u128, 0x300000000000000u128, 0x700000000000000u128, 0xf00000000000000u128, 0xf00000000000000u128, 0xf00000000000000u128, 0xf00000000000000u128, 0xf00000000000000u128, ], [0x400000000000u128, 0x402000000000u128, 0x402010000000u128, 0x402010080000u128, 0x402010080000u128, 0x402010080000u128, 0x402010080000u128, 0x402010080000u128, ], [0x200000000000u128, 0x200800000000u128, 0x200802000000u128, 0x200802008000u128, 0x200802008020u128, 0x200802008020u128, 0x200802008020u128, 0x200802008020u128, ], [0x100000000000u128, 0x100200000000u128, 0x100200400000u128, 0x100200400800u128, 0x100200400801u128, 0x100200400801u128, 0x100200400801u128, 0x100200400801u128, ], [0x40000000000000u128, 0x60000000000000u128, 0x70000000000000u128, 0x78000000000000u128, 0x7c000000000000u128, 0x7c000000000000u128, 0x7c000000000000u128, 0x7c000000000000u128, ], [0x10000000000000000u128, 0x2010000000000000000u128, 0x402010000000000000000u128, 0x80402010000000000000000u128, 0x80402010000000000000000u128, 0x80402010000000000000000u128, 0x80402010000000000000000u128, 0x80402010000000000000000u128, ], ], [[0x40000000000000000u128, 0x10040000000000000000u128, 0x4010040000000000000000u128, 0x1004010040000000000000000u128, 0x1004010040000000000000000u128, 0x1004010040000000000000000u128, 0x1004010040000000000000000u128, 0x1004010040000000000000000u128, ], [0x80000000000000000u128, 0x40080000000000000000u128, 0x20040080000000000000000u128, 0x20040080000000000000000u128, 0x20040080000000000000000u128, 0x20040080000000000000000u128, 0x20040080000000000000000u128, 0x20040080000000000000000u128, ], [0x200000000000000u128, 0x600000000000000u128, 0xe00000000000000u128, 0xe00000000000000u128, 0xe00000000000000u128, 0xe00000000000000u128, 0xe00000000000000u128, 0xe00000000000000u128, ], [0x800000000000u128, 0x804000000000u128, 0x804020000000u128, 0x804020000000u128, 0x804020000000u128, 0x804020000000u128, 0x804020000000u128, 0x804020000000u128, ], [0x400000000000u128, 0x401000000000u128, 0x401004000000u128, 0x401004010000u128, 0x401004010040u128, 0x401004010040u128, 0x401004010040u128, 0x401004010040u128, ], [0x200000000000u128, 0x200400000000u128, 0x200400800000u128, 0x200400801000u128, 0x200400801002u128, 0x200400801002u128, 0x200400801002u128, 0x200400801002u128, ], [0x80000000000000u128, 0xc0000000000000u128, 0xe0000000000000u128, 0xf0000000000000u128, 0xf8000000000000u128, 0xfc000000000000u128, 0xfc000000000000u128, 0xfc000000000000u128, ], [0x20000000000000000u128, 0x4020000000000000000u128, 0x804020000000000000000u128, 0x100804020000000000000000u128, 0x100804020000000000000000u128, 0x100804020000000000000000u128, 0x100804020000000000000000u128, 0x100804020000000000000000u128, ], ], [[0x80000000000000000u128, 0x20080000000000000000u128, 0x8020080000000000000000u128, 0x2008020080000000000000000u128, 0x2008020080000000000000000u128, 0x2008020080000000000000000u128, 0x2008020080000000000000000u128, 0x2008020080000000000000000u128, ], [0x100000000000000000u128, 0x80100000000000000000u128, 0x80100000000000000000u128, 0x80100000000000000000u128, 0x80100000000000000000u128, 0x80100000000000000000u128, 0x80100000000000000000u128, 0x80100000000000000000u128, ], [0x400000000000000u128, 0xc00000000000000u128, 0xc00000000000000u128, 0xc00000000000000u128, 0xc00000000000000u128, 0xc00000000000000u128, 0xc00000000000000u128, 0xc00000000000000u128, ], [0x1000000000000u128, 0x1008000000000u128, 0x1008000000000u128, 0x1008000000000u128, 0x1008000000000u128, 0x1008000000000u128, 0x1008000000000u128, 0x1008000000000u128, ], [0x800000000000u128, 0x802000000000u128, 0x802008000000u128, 0x802008020000u128, 0x802008020080u128, 0x802008020080u128, 0x802008020080u128, 0x802008020080u128, ], [0x400000000000u128, 0x400800000000u128, 0x400801000000u128, 0x400801002000u128, 0x400801002004u128, 0x400801002004u128, 0x400801002004u128, 0x400801002004u128, ], [0x100000000000000u128, 0x180000000000000u128, 0x1c0000000000000u128, 0x1e0000000000000u128, 0x1f0000000000000u128, 0x1f8000000000000u128, 0x1fc000000000000u128, 0x1fc000000000000u128, ], [0x40000000000000000u128, 0x8040000000000000000u128, 0x1008040000000000000000u128, 0x201008040000000000000000u128, 0x201008040000000000000000u128, 0x201008040000000000000000u128, 0x201008040000000000000000u128, 0x201008040000000000000000u128, ], ], [[0x100000000000000000u128, 0x40100000000000000000u128, 0x10040100000000000000000u128, 0x4010040100000000000000000u128, 0x4010040100000000000000000u128, 0x4010040100000000000000000u128, 0x4010040100000000000000000u128, 0x4010040100000000000000000u128, ], [0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, ], [0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, ], [0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, 0x2000000000000u128, ], [0x1000000000000u128, 0x1004000000000u128, 0x1004010000000u128, 0x1004010040000u128, 0x1004010040100u128, 0x1004010040100u128, 0x1004010040100u128, 0x1004010040100u128, ], [0x800000000000u128, 0x801000000000u128, 0x801002000000u128, 0x801002004000u128, 0x801002004008u128, 0x801002004008u128, 0x801002004008u128, 0x801002004008u128, ], [0x200000000000000u128, 0x300000000000000u128, 0x380000000000000u128, 0x3c0000000000000u128, 0x3e0000000000000u128, 0x3f0000000000000u128, 0x3f8000000000000u128, 0x3fc000000000000u128, ], [0x80000000000000000u128, 0x10080000000000000000u128, 0x2010080000000000000000u128, 0x402010080000000000000000u128, 0x402010080000000000000000u128, 0x402010080000000000000000u128, 0x402010080000000000000000u128, 0x402010080000000000000000u128, ], ], [[0x200000000000000000u128, 0x80200000000000000000u128, 0x20080200000000000000000u128, 0x8020080200000000000000000u128, 0x8020080200000000000000000u128, 0x8020080200000000000000000u128, 0x8020080200000000000000000u128, 0x8020080200000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x2000000000000u128, 0x2008000000000u128, 0x2008020000000u128, 0x2008020080000u128, 0x2008020080200u128, 0x2008020080200u128, 0x2008020080200u128, 0x2008020080200u128, ], [0x1000000000000u128, 0x1002000000000u128, 0x1002004000000u128, 0x1002004008000u128, 0x1002004008010u128, 0x1002004008010u128, 0x1002004008010u128, 0x1002004008010u128, ], [0x400000000000000u128, 0x600000000000000u128, 0x700000000000000u128, 0x780000000000000u128, 0x7c0000000000000u128, 0x7e0000000000000u128, 0x7f0000000000000u128, 0x7f8000000000000u128, ], [0x100000000000000000u128, 0x20100000000000000000u128, 0x4020100000000000000000u128, 0x804020100000000000000000u128, 0x804020100000000000000000u128, 0x804020100000000000000000u128, 0x804020100000000000000000u128, 0x804020100000000000000000u128, ], ], [[0x400000000000000000u128, 0x100400000000000000000u128, 0x40100400000000000000000u128, 0x40100400000000000000000u128, 0x40100400000000000000000u128, 0x40100400000000000000000u128, 0x40100400000000000000000u128, 0x40100400000000000000000u128, ], [0x800000000000000000u128, 0x400800000000000000000u128, 0x200400800000000000000000u128, 0x200400800000000000000000u128, 0x200400800000000000000000u128, 0x200400800000000000000000u128, 0x200400800000000000000000u128, 0x200400800000000000000000u128, ], [0x2000000000000000u128, 0x6000000000000000u128, 0xe000000000000000u128, 0x1e000000000000000u128, 0x3e000000000000000u128, 0x7e000000000000000u128, 0xfe000000000000000u128, 0x1fe000000000000000u128, ], [0x8000000000000u128, 0x8040000000000u128, 0x8040200000000u128, 0x8040201000000u128, 0x8040201008000u128, 0x8040201008040u128, 0x8040201008040u128, 0x8040201008040u128, ], [0x4000000000000u128, 0x4010000000000u128, 0x4010040000000u128, 0x4010040100000u128, 0x4010040100400u128, 0x4010040100401u128, 0x4010040100401u128, 0x4010040100401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x800000000000000000u128, 0x200800000000000000000u128, 0x80200800000000000000000u128, 0x80200800000000000000000u128, 0x80200800000000000000000u128, 0x80200800000000000000000u128, 0x80200800000000000000000u128, 0x80200800000000000000000u128, ], [0x1000000000000000000u128, 0x801000000000000000000u128, 0x400801000000000000000000u128, 0x400801000000000000000000u128, 0x400801000000000000000000u128, 0x400801000000000000000000u128, 0x400801000000000000000000u128, 0x400801000000000000000000u128, ], [0x4000000000000000u128, 0xc000000000000000u128, 0x1c000000000000000u128, 0x3c000000000000000u128, 0x7c000000000000000u128, 0xfc000000000000000u128, 0x1fc000000000000000u128, 0x3fc000000000000000u128, ], [0x10000000000000u128, 0x10080000000000u128, 0x10080400000000u128, 0x10080402000000u128, 0x10080402010000u128, 0x10080402010080u128, 0x10080402010080u128, 0x10080402010080u128, ], [0x8000000000000u128, 0x8020000000000u128, 0x8020080000000u128, 0x8020080200000u128, 0x8020080200800u128, 0x8020080200802u128, 0x8020080200802u128, 0x8020080200802u128, ], [0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, 0x4000000000000u128, ], [0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, ], [0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, ], ], [[0x1000000000000000000u128, 0x401000000000000000000u128, 0x100401000000000000000000u128, 0x100401000000000000000000u128, 0x100401000000000000000000u128, 0x100401000000000000000000u128, 0x100401000000000000000000u128, 0x100401000000000000000000u128, ], [0x2000000000000000000u128, 0x1002000000000000000000u128, 0x801002000000000000000000u128, 0x801002000000000000000000u128, 0x801002000000000000000000u128, 0x801002000000000000000000u128, 0x801002000000000000000000u128, 0x801002000000000000000000u128, ], [0x8000000000000000u128, 0x18000000000000000u128, 0x38000000000000000u128, 0x78000000000000000u128, 0xf8000000000000000u128, 0x1f8000000000000000u128, 0x3f8000000000000000u128, 0x3f8000000000000000u128, ], [0x20000000000000u128, 0x20100000000000u128, 0x20100800000000u128, 0x20100804000000u128, 0x20100804020000u128, 0x20100804020100u128, 0x20100804020100u128, 0x20100804020100u128, ], [0x10000000000000u128, 0x10040000000000u128, 0x10040100000000u128, 0x10040100400000u128, 0x10040100401000u128, 0x10040100401004u128, 0x10040100401004u128, 0x10040100401004u128, ], [0x8000000000000u128, 0x8010000000000u128, 0x8010000000000u128, 0x8010000000000u128, 0x8010000000000u128, 0x8010000000000u128, 0x8010000000000u128, 0x8010000000000u128, ], [0x2000000000000000u128, 0x3000000000000000u128, 0x3000000000000000u128, 0x3000000000000000u128, 0x3000000000000000u128, 0x3000000000000000u128, 0x3000000000000000u128, 0x3000000000000000u128, ], [0x800000000000000000u128, 0x100800000000000000000u128, 0x100800000000000000000u128, 0x100800000000000000000u128, 0x100800000000000000000u128, 0x100800000000000000000u128, 0x100800000000000000000u128, 0x100800000000000000000u128, ], ], [[0x2000000000000000000u128, 0x802000000000000000000u128, 0x200802000000000000000000u128, 0x200802000000000000000000u128, 0x200802000000000000000000u128, 0x200802000000000000000000u128, 0x200802000000000000000000u128, 0x200802000000000000000000u128, ], [0x4000000000000000000u128, 0x2004000000000000000000u128, 0x1002004000000000000000000u128, 0x1002004000000000000000000u128, 0x1002004000000000000000000u128, 0x1002004000000000000000000u128, 0x1002004000000000000000000u128, 0x1002004000000000000000000u128, ], [0x10000000000000000u128, 0x30000000000000000u128, 0x70000000000000000u128, 0xf0000000000000000u128, 0x1f0000000000000000u128, 0x3f0000000000000000u128, 0x3f0000000000000000u128, 0x3f0000000000000000u128, ], [0x40000000000000u128, 0x40200000000000u128, 0x40201000000000u128, 0x40201008000000u128, 0x40201008040000u128, 0x40201008040200u128, 0x40201008040200u128, 0x40201008040200u128, ], [0x20000000000000u128, 0x20080000000000u128, 0x20080200000000u128, 0x20080200800000u128, 0x20080200802000u128, 0x20080200802008u128, 0x20080200802008u128, 0x20080200802008u128, ], [0x10000000000000u128, 0x10020000000000u128, 0x10020040000000u128, 0x10020040000000u128, 0x10020040000000u128, 0x10020040000000u128, 0x10020040000000u128, 0x10020040000000u128, ], [0x4000000000000000u128, 0x6000000000000000u128, 0x7000000000000000u128, 0x7000000000000000u128, 0x7000000000000000u128, 0x7000000000000000u128, 0x7000000000000000u128, 0x7000000000000000u128, ], [0x1000000000000000000u128, 0x201000000000000000000u128, 0x40201000000000000000000u128, 0x40201000000000000000000u128, 0x40201000000000000000000u128, 0x40201000000000000000000u128, 0x40201000000000000000000u128, 0x40201000000000000000000u128, ], ], [[0x4000000000000000000u128, 0x1004000000000000000000u128, 0x401004000000000000000000u128, 0x401004000000000000000000u128, 0x401004000000000000000000u128, 0x401004000000000000000000u128, 0x401004000000000000000000u128, 0x401004000000000000000000u128, ], [0x8000000000000000000u128, 0x4008000000000000000000u128, 0x2004008000000000000000000u128, 0x2004008000000000000000000u128, 0x2004008000000000000000000u128, 0x2004008000000000000000000u128, 0x2004008000000000000000000u128, 0x2004008000000000000000000u128, ], [0x20000000000000000u128, 0x60000000000000000u128, 0xe0000000000000000u128, 0x1e0000000000000000u128, 0x3e0000000000000000u128, 0x3e0000000000000000u128, 0x3e0000000000000000u128, 0x3e0000000000000000u128, ], [0x80000000000000u128, 0x80400000000000u128, 0x80402000000000u128, 0x80402010000000u128, 0x80402010080000u128, 0x80402010080000u128, 0x80402010080000u128, 0x80402010080000u128, ], [0x40000000000000u128, 0x40100000000000u128, 0x40100400000000u128, 0x40100401000000u128, 0x40100401004000u128, 0x40100401004010u128, 0x40100401004010u128, 0x40100401004010u128, ], [0x20000000000000u128, 0x20040000000000u128, 0x20040080000000u128, 0x20040080100000u128, 0x20040080100000u128, 0x20040080100000u128, 0x20040080100000u128, 0x20040080100000u128, ], [0x8000000000000000u128, 0xc000000000000000u128, 0xe000000000000000u128, 0xf000000000000000u128, 0xf000000000000000u128, 0xf000000000000000u128, 0xf000000000000000u128, 0xf000000000000000u128, ], [0x2000000000000000000u128, 0x402000000000000000000u128, 0x80402000000000000000000u128, 0x80402000000000000000000u128, 0x80402000000000000000000u128, 0x80402000000000000000000u128, 0x80402000000000000000000u128, 0x80402000000000000000000u128, ], ], [[0x8000000000000000000u128, 0x2008000000000000000000u128, 0x802008000000000000000000u128, 0x802008000000000000000000u128, 0x802008000000000000000000u128, 0x802008000000000000000000u128, 0x802008000000000000000000u128, 0x802008000000000000000000u128, ], [0x10000000000000000000u128, 0x8010000000000000000000u128, 0x4008010000000000000000000u128, 0x4008010000000000000000000u128, 0x4008010000000000000000000u128, 0x4008010000000000000000000u128, 0x4008010000000000000000000u128, 0x4008010000000000000000000u128, ], [0x40000000000000000u128, 0xc0000000000000000u128, 0x1c0000000000000000u128, 0x3c0000000000000000u128, 0x3c0000000000000000u128, 0x3c0000000000000000u128, 0x3c0000000000000000u128, 0x3c0000000000000000u128, ], [0x100000000000000u128, 0x100800000000000u128, 0x100804000000000u128, 0x100804020000000u128, 0x100804020000000u128, 0x100804020000000u128, 0x100804020000000u128, 0x100804020000000u128, ], [0x80000000000000u128, 0x80200000000000u128, 0x80200800000000u128, 0x80200802000000u128, 0x80200802008000u128, 0x80200802008020u128, 0x80200802008020u128, 0x80200802008020u128, ], [0x40000000000000u128, 0x40080000000000u128, 0x40080100000000u128, 0x40080100200000u128, 0x40080100200400u128, 0x40080100200400u128, 0x40080100200400u128, 0x40080100200400u128, ], [0x10000000000000000u128, 0x18000000000000000u128, 0x1c000000000000000u128, 0x1e000000000000000u128, 0x1f000000000000000u128, 0x1f000000000000000u128, 0x1f000000000000000u128, 0x1f000000000000000u128, ], [0x4000000000000000000u128, 0x804000000000000000000u128, 0x100804000000000000000000u128, 0x100804000000000000000000u128, 0x100804000000000000000000u128, 0x100804000000000000000000u128, 0x100804000000000000000000u128, 0x100804000000000000000000u128, ], ], [[0x10000000000000000000u128, 0x4010000000000000000000u128, 0x1004010000000000000000000u128, 0x1004010000000000000000000u128, 0x1004010000000000000000000u128, 0x1004010000000000000000000u128, 0x1004010000000000000000000u128, 0x1004010000000000000000000u128, ], [0x20000000000000000000u128, 0x10020000000000000000000u128, 0x8010020000000000000000000u128, 0x8010020000000000000000000u128, 0x8010020000000000000000000u128, 0x8010020000000000000000000u128, 0x8010020000000000000000000u128, 0x8010020000000000000000000u128, ], [0x80000000000000000u128, 0x180000000000000000u128, 0x380000000000000000u128, 0x380000000000000000u128, 0x380000000000000000u128, 0x380000000000000000u128, 0x380000000000000000u128, 0x380000000000000000u128, ], [0x200000000000000u128, 0x201000000000000u128, 0x201008000000000u128, 0x201008000000000u128, 0x201008000000000u128, 0x201008000000000u128, 0x201008000000000u128, 0x201008000000000u128, ], [0x100000000000000u128, 0x100400000000000u128, 0x100401000000000u128, 0x100401004000000u128, 0x100401004010000u128, 0x100401004010040u128, 0x100401004010040u128, 0x100401004010040u128, ], [0x80000000000000u128, 0x80100000000000u128, 0x80100200000000u128, 0x80100200400000u128, 0x80100200400800u128, 0x80100200400801u128, 0x80100200400801u128, 0x80100200400801u128, ], [0x20000000000000000u128, 0x30000000000000000u128, 0x38000000000000000u128, 0x3c000000000000000u128, 0x3e000000000000000u128, 0x3f000000000000000u128, 0x3f000000000000000u128, 0x3f000000000000000u128, ], [0x8000000000000000000u128, 0x1008000000000000000000u128, 0x201008000000000000000000u128, 0x201008000000000000000000u128, 0x201008000000000000000000u128, 0x201008000000000000000000u128, 0x201008000000000000000000u128, 0x201008000000000000000000u128, ], ], [[0x20000000000000000000u128, 0x8020000000000000000000u128, 0x2008020000000000000000000u128, 0x2008020000000000000000000u128, 0x2008020000000000000000000u128, 0x2008020000000000000000000u128, 0x2008020000000000000000000u128, 0x2008020000000000000000000u128, ], [0x40000000000000000000u128, 0x20040000000000000000000u128, 0x20040000000000000000000u128, 0x20040000000000000000000u128, 0x20040000000000000000000u128, 0x20040000000000000000000u128, 0x20040000000000000000000u128, 0x20040000000000000000000u128, ], [0x100000000000000000u128, 0x300000000000000000u128, 0x300000000000000000u128, 0x300000000000000000u128, 0x300000000000000000u128, 0x300000000000000000u128, 0x300000000000000000u128, 0x300000000000000000u128, ], [0x400000000000000u128, 0x402000000000000u128, 0x402000000000000u128, 0x402000000000000u128, 0x402000000000000u128, 0x402000000000000u128, 0x402000000000000u128, 0x402000000000000u128, ], [0x200000000000000u128, 0x200800000000000u128, 0x200802000000000u128, 0x200802008000000u128, 0x200802008020000u128, 0x200802008020080u128, 0x200802008020080u128, 0x200802008020080u128, ], [0x100000000000000u128, 0x100200000000000u128, 0x100200400000000u128, 0x100200400800000u128, 0x100200400801000u128, 0x100200400801002u128, 0x100200400801002u128, 0x100200400801002u128, ], [0x40000000000000000u128, 0x60000000000000000u128, 0x70000000000000000u128, 0x78000000000000000u128, 0x7c000000000000000u128, 0x7e000000000000000u128, 0x7f000000000000000u128, 0x7f000000000000000u128, ], [0x10000000000000000000u128, 0x2010000000000000000000u128, 0x402010000000000000000000u128, 0x402010000000000000000000u128, 0x402010000000000000000000u128, 0x402010000000000000000000u128, 0x402010000000000000000000u128, 0x402010000000000000000000u128, ], ], [[0x40000000000000000000u128, 0x10040000000000000000000u128, 0x4010040000000000000000000u128, 0x4010040000000000000000000u128, 0x4010040000000000000000000u128, 0x4010040000000000000000000u128, 0x4010040000000000000000000u128, 0x4010040000000000000000000u128, ], [0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, ], [0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, ], [0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, 0x800000000000000u128, ], [0x400000000000000u128, 0x401000000000000u128, 0x401004000000000u128, 0x401004010000000u128, 0x401004010040000u128, 0x401004010040100u128, 0x401004010040100u128, 0x401004010040100u128, ], [0x200000000000000u128, 0x200400000000000u128, 0x200400800000000u128, 0x200400801000000u128, 0x200400801002000u128, 0x200400801002004u128, 0x200400801002004u128, 0x200400801002004u128, ], [0x80000000000000000u128, 0xc0000000000000000u128, 0xe0000000000000000u128, 0xf0000000000000000u128, 0xf8000000000000000u128, 0xfc000000000000000u128, 0xfe000000000000000u128, 0xff000000000000000u128, ], [0x20000000000000000000u128, 0x4020000000000000000000u128, 0x804020000000000000000000u128, 0x804020000000000000000000u128, 0x804020000000000000000000u128, 0x804020000000000000000000u128, 0x804020000000000000000000u128, 0x804020000000000000000000u128, ], ], [[0x80000000000000000000u128, 0x20080000000000000000000u128, 0x8020080000000000000000000u128, 0x8020080000000000000000000u128, 0x8020080000000000000000000u128, 0x8020080000000000000000000u128, 0x8020080000000000000000000u128, 0x8020080000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x800000000000000u128, 0x802000000000000u128, 0x802008000000000u128, 0x802008020000000u128, 0x802008020080000u128, 0x802008020080200u128, 0x802008020080200u128, 0x802008020080200u128, ], [0x400000000000000u128, 0x400800000000000u128, 0x400801000000000u128, 0x400801002000000u128, 0x400801002004000u128, 0x400801002004008u128, 0x400801002004008u128, 0x400801002004008u128, ], [0x100000000000000000u128, 0x180000000000000000u128, 0x1c0000000000000000u128, 0x1e0000000000000000u128, 0x1f0000000000000000u128, 0x1f8000000000000000u128, 0x1fc000000000000000u128, 0x1fe000000000000000u128, ], [0x40000000000000000000u128, 0x8040000000000000000000u128, 0x1008040000000000000000000u128, 0x1008040000000000000000000u128, 0x1008040000000000000000000u128, 0x1008040000000000000000000u128, 0x1008040000000000000000000u128, 0x1008040000000000000000000u128, ], ], [[0x100000000000000000000u128, 0x40100000000000000000000u128, 0x40100000000000000000000u128, 0x40100000000000000000000u128, 0x40100000000000000000000u128, 0x40100000000000000000000u128, 0x40100000000000000000000u128, 0x40100000000000000000000u128, ], [0x200000000000000000000u128, 0x100200000000000000000000u128, 0x100200000000000000000000u128, 0x100200000000000000000000u128, 0x100200000000000000000000u128, 0x100200000000000000000000u128, 0x100200000000000000000000u128, 0x100200000000000000000000u128, ], [0x800000000000000000u128, 0x1800000000000000000u128, 0x3800000000000000000u128, 0x7800000000000000000u128, 0xf800000000000000000u128, 0x1f800000000000000000u128, 0x3f800000000000000000u128, 0x7f800000000000000000u128, ], [0x2000000000000000u128, 0x2010000000000000u128, 0x2010080000000000u128, 0x2010080400000000u128, 0x2010080402000000u128, 0x2010080402010000u128, 0x2010080402010080u128, 0x2010080402010080u128, ], [0x1000000000000000u128, 0x1004000000000000u128, 0x1004010000000000u128, 0x1004010040000000u128, 0x1004010040100000u128, 0x1004010040100400u128, 0x1004010040100401u128, 0x1004010040100401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x200000000000000000000u128, 0x80200000000000000000000u128, 0x80200000000000000000000u128, 0x80200000000000000000000u128, 0x80200000000000000000000u128, 0x80200000000000000000000u128, 0x80200000000000000000000u128, 0x80200000000000000000000u128, ], [0x400000000000000000000u128, 0x200400000000000000000000u128, 0x200400000000000000000000u128, 0x200400000000000000000000u128, 0x200400000000000000000000u128, 0x200400000000000000000000u128, 0x200400000000000000000000u128, 0x200400000000000000000000u128, ], [0x1000000000000000000u128, 0x3000000000000000000u128, 0x7000000000000000000u128, 0xf000000000000000000u128, 0x1f000000000000000000u128, 0x3f000000000000000000u128, 0x7f000000000000000000u128, 0xff000000000000000000u128, ], [0x4000000000000000u128, 0x4020000000000000u128, 0x4020100000000000u128, 0x4020100800000000u128, 0x4020100804000000u128, 0x4020100804020000u128, 0x4020100804020100u128, 0x4020100804020100u128, ], [0x2000000000000000u128, 0x2008000000000000u128, 0x2008020000000000u128, 0x2008020080000000u128, 0x2008020080200000u128, 0x2008020080200800u128, 0x2008020080200802u128, 0x2008020080200802u128, ], [0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, 0x1000000000000000u128, ], [0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, ], [0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, ], ], [[0x400000000000000000000u128, 0x100400000000000000000000u128, 0x100400000000000000000000u128, 0x100400000000000000000000u128, 0x100400000000000000000000u128, 0x100400000000000000000000u128, 0x100400000000000000000000u128, 0x100400000000000000000000u128, ], [0x800000000000000000000u128, 0x400800000000000000000000u128, 0x400800000000000000000000u128, 0x400800000000000000000000u128, 0x400800000000000000000000u128, 0x400800000000000000000000u128, 0x400800000000000000000000u128, 0x400800000000000000000000u128, ], [0x2000000000000000000u128, 0x6000000000000000000u128, 0xe000000000000000000u128, 0x1e000000000000000000u128, 0x3e000000000000000000u128, 0x7e000000000000000000u128, 0xfe000000000000000000u128, 0xfe000000000000000000u128, ], [0x8000000000000000u128, 0x8040000000000000u128, 0x8040200000000000u128, 0x8040201000000000u128, 0x8040201008000000u128, 0x8040201008040000u128, 0x8040201008040200u128, 0x8040201008040200u128, ], [0x4000000000000000u128, 0x4010000000000000u128, 0x4010040000000000u128, 0x4010040100000000u128, 0x4010040100400000u128, 0x4010040100401000u128, 0x4010040100401004u128, 0x4010040100401004u128, ], [0x2000000000000000u128, 0x2004000000000000u128, 0x2004000000000000u128, 0x2004000000000000u128, 0x2004000000000000u128, 0x2004000000000000u128, 0x2004000000000000u128, 0x2004000000000000u128, ], [0x800000000000000000u128, 0xc00000000000000000u128, 0xc00000000000000000u128, 0xc00000000000000000u128, 0xc00000000000000000u128, 0xc00000000000000000u128, 0xc00000000000000000u128, 0xc00000000000000000u128, ], [0x200000000000000000000u128, 0x40200000000000000000000u128, 0x40200000000000000000000u128, 0x40200000000000000000000u128, 0x40200000000000000000000u128, 0x40200000000000000000000u128, 0x40200000000000000000000u128, 0x40200000000000000000000u128, ], ], [[0x800000000000000000000u128, 0x200800000000000000000000u128, 0x200800000000000000000000u128, 0x200800000000000000000000u128, 0x200800000000000000000000u128, 0x200800000000000000000000u128, 0x200800000000000000000000u128, 0x200800000000000000000000u128, ], [0x1000000000000000000000u128, 0x801000000000000000000000u128, 0x801000000000000000000000u128, 0x801000000000000000000000u128, 0x801000000000000000000000u128, 0x801000000000000000000000u128, 0x801000000000000000000000u128, 0x801000000000000000000000u128, ], [0x4000000000000000000u128, 0xc000000000000000000u128, 0x1c000000000000000000u128, 0x3c000000000000000000u128, 0x7c000000000000000000u128, 0xfc000000000000000000u128, 0xfc000000000000000000u128, 0xfc000000000000000000u128, ], [0x10000000000000000u128, 0x10080000000000000u128, 0x10080400000000000u128, 0x10080402000000000u128, 0x10080402010000000u128, 0x10080402010080000u128, 0x10080402010080000u128, 0x10080402010080000u128, ], [0x8000000000000000u128, 0x8020000000000000u128, 0x8020080000000000u128, 0x8020080200000000u128, 0x8020080200800000u128, 0x8020080200802000u128, 0x8020080200802008u128, 0x8020080200802008u128, ], [0x4000000000000000u128, 0x4008000000000000u128, 0x4008010000000000u128, 0x4008010000000000u128, 0x4008010000000000u128, 0x4008010000000000u128, 0x4008010000000000u128, 0x4008010000000000u128, ], [0x1000000000000000000u128, 0x1800000000000000000u128, 0x1c00000000000000000u128, 0x1c00000000000000000u128, 0x1c00000000000000000u128, 0x1c00000000000000000u128, 0x1c00000000000000000u128, 0x1c00000000000000000u128, ], [0x400000000000000000000u128, 0x80400000000000000000000u128, 0x80400000000000000000000u128, 0x80400000000000000000000u128, 0x80400000000000000000000u128, 0x80400000000000000000000u128, 0x80400000000000000000000u128, 0x80400000000000000000000u128, ], ], [[0x1000000000000000000000u128, 0x401000000000000000000000u128, 0x401000000000000000000000u128, 0x401000000000000000000000u128, 0x401000000000000000000000u128, 0x401000000000000000000000u128, 0x401000000000000000000000u128, 0x401000000000000000000000u128, ], [0x2000000000000000000000u128, 0x1002000000000000000000000u128, 0x1002000000000000000000000u128, 0x1002000000000000000000000u128, 0x1002000000000000000000000u128, 0x1002000000000000000000000u128, 0x1002000000000000000000000u128, 0x1002000000000000000000000u128, ], [0x8000000000000000000u128, 0x18000000000000000000u128, 0x38000000000000000000u128, 0x78000000000000000000u128, 0xf8000000000000000000u128, 0xf8000000000000000000u128, 0xf8000000000000000000u128, 0xf8000000000000000000u128, ], [0x20000000000000000u128, 0x20100000000000000u128, 0x20100800000000000u128, 0x20100804000000000u128, 0x20100804020000000u128, 0x20100804020000000u128, 0x20100804020000000u128, 0x20100804020000000u128, ], [0x10000000000000000u128, 0x10040000000000000u128, 0x10040100000000000u128, 0x10040100400000000u128, 0x10040100401000000u128, 0x10040100401004000u128, 0x10040100401004010u128, 0x10040100401004010u128, ], [0x8000000000000000u128, 0x8010000000000000u128, 0x8010020000000000u128, 0x8010020040000000u128, 0x8010020040000000u128, 0x8010020040000000u128, 0x8010020040000000u128, 0x8010020040000000u128, ], [0x2000000000000000000u128, 0x3000000000000000000u128, 0x3800000000000000000u128, 0x3c00000000000000000u128, 0x3c00000000000000000u128, 0x3c00000000000000000u128, 0x3c00000000000000000u128, 0x3c00000000000000000u128, ], [0x800000000000000000000u128, 0x100800000000000000000000u128, 0x100800000000000000000000u128, 0x100800000000000000000000u128, 0x100800000000000000000000u128, 0x100800000000000000000000u128, 0x100800000000000000000000u128, 0x100800000000000000000000u128, ], ], [[0x2000000000000000000000u128, 0x802000000000000000000000u128, 0x802000000000000000000000u128, 0x802000000000000000000000u128, 0x802000000000000000000000u128, 0x802000000000000000000000u128, 0x802000000000000000000000u128, 0x802000000000000000000000u128, ], [0x4000000000000000000000u128, 0x2004000000000000000000000u128, 0x2004000000000000000000000u128, 0x2004000000000000000000000u128, 0x2004000000000000000000000u128, 0x2004000000000000000000000u128, 0x2004000000000000000000000u128, 0x2004000000000000000000000u128, ], [0x10000000000000000000u128, 0x30000000000000000000u128, 0x70000000000000000000u128, 0xf0000000000000000000u128, 0xf0000000000000000000u128, 0xf0000000000000000000u128, 0xf0000000000000000000u128, 0xf0000000000000000000u128, ], [0x40000000000000000u128, 0x40200000000000000u128, 0x40201000000000000u128, 0x40201008000000000u128, 0x40201008000000000u128, 0x40201008000000000u128, 0x40201008000000000u128, 0x40201008000000000u128, ], [0x20000000000000000u128, 0x20080000000000000u128, 0x20080200000000000u128, 0x20080200800000000u128, 0x20080200802000000u128, 0x20080200802008000u128, 0x20080200802008020u128, 0x20080200802008020u128, ], [0x10000000000000000u128, 0x10020000000000000u128, 0x10020040000000000u128, 0x10020040080000000u128, 0x10020040080100000u128, 0x10020040080100000u128, 0x10020040080100000u128, 0x10020040080100000u128, ], [0x4000000000000000000u128, 0x6000000000000000000u128, 0x7000000000000000000u128, 0x7800000000000000000u128, 0x7c00000000000000000u128, 0x7c00000000000000000u128, 0x7c00000000000000000u128, 0x7c00000000000000000u128, ], [0x1000000000000000000000u128, 0x201000000000000000000000u128, 0x201000000000000000000000u128, 0x201000000000000000000000u128, 0x201000000000000000000000u128, 0x201000000000000000000000u128, 0x201000000000000000000000u128, 0x201000000000000000000000u128, ], ], [[0x4000000000000000000000u128, 0x1004000000000000000000000u128, 0x1004000000000000000000000u128, 0x1004000000000000000000000u128, 0x1004000000000000000000000u128, 0x1004000000000000000000000u128, 0x1004000000000000000000000u128, 0x1004000000000000000000000u128, ], [0x8000000000000000000000u128, 0x4008000000000000000000000u128, 0x4008000000000000000000000u128, 0x4008000000000000000000000u128, 0x4008000000000000000000000u128, 0x4008000000000000000000000u128, 0x4008000000000000000000000u128, 0x4008000000000000000000000u128, ], [0x20000000000000000000u128, 0x60000000000000000000u128, 0xe0000000000000000000u128, 0xe0000000000000000000u128, 0xe0000000000000000000u128, 0xe0000000000000000000u128, 0xe0000000000000000000u128, 0xe0000000000000000000u128, ], [0x80000000000000000u128, 0x80400000000000000u128, 0x80402000000000000u128, 0x80402000000000000u128, 0x80402000000000000u128, 0x80402000000000000u128, 0x80402000000000000u128, 0x80402000000000000u128, ], [0x40000000000000000u128, 0x40100000000000000u128, 0x40100400000000000u128, 0x40100401000000000u128, 0x40100401004000000u128, 0x40100401004010000u128, 0x40100401004010040u128, 0x40100401004010040u128, ], [0x20000000000000000u128, 0x20040000000000000u128, 0x20040080000000000u128, 0x20040080100000000u128, 0x20040080100200000u128, 0x20040080100200400u128, 0x20040080100200400u128, 0x20040080100200400u128, ], [0x8000000000000000000u128, 0xc000000000000000000u128, 0xe000000000000000000u128, 0xf000000000000000000u128, 0xf800000000000000000u128, 0xfc00000000000000000u128, 0xfc00000000000000000u128, 0xfc00000000000000000u128, ], [0x2000000000000000000000u128, 0x402000000000000000000000u128, 0x402000000000000000000000u128, 0x402000000000000000000000u128, 0x402000000000000000000000u128, 0x402000000000000000000000u128, 0x402000000000000000000000u128, 0x402000000000000000000000u128, ], ], [[0x8000000000000000000000u128, 0x2008000000000000000000000u128, 0x2008000000000000000000000u128, 0x2008000000000000000000000u128, 0x2008000000000000000000000u128, 0x2008000000000000000000000u128, 0x2008000000000000000000000u128, 0x2008000000000000000000000u128, ], [0x10000000000000000000000u128, 0x8010000000000000000000000u128, 0x8010000000000000000000000u128, 0x8010000000000000000000000u128, 0x8010000000000000000000000u128, 0x8010000000000000000000000u128, 0x8010000000000000000000000u128, 0x8010000000000000000000000u128, ], [0x40000000000000000000u128, 0xc0000000000000000000u128, 0xc0000000000000000000u128, 0xc0000000000000000000u128, 0xc0000000000000000000u128, 0xc0000000000000000000u128, 0xc0000000000000000000u128, 0xc0000000000000000000u128, ], [0x100000000000000000u128, 0x100800000000000000u128, 0x100800000000000000u128, 0x100800000000000000u128, 0x100800000000000000u128, 0x100800000000000000u128, 0x100800000000000000u128, 0x100800000000000000u128, ], [0x80000000000000000u128, 0x80200000000000000u128, 0x80200800000000000u128, 0x80200802000000000u128, 0x80200802008000000u128, 0x80200802008020000u128, 0x80200802008020080u128, 0x80200802008020080u128, ], [0x40000000000000000u128, 0x40080000000000000u128, 0x40080100000000000u128, 0x40080100200000000u128, 0x40080100200400000u128, 0x40080100200400800u128, 0x40080100200400801u128, 0x40080100200400801u128, ], [0x10000000000000000000u128, 0x18000000000000000000u128, 0x1c000000000000000000u128, 0x1e000000000000000000u128, 0x1f000000000000000000u128, 0x1f800000000000000000u128, 0x1fc00000000000000000u128, 0x1fc00000000000000000u128, ], [0x4000000000000000000000u128, 0x804000000000000000000000u128, 0x804000000000000000000000u128, 0x804000000000000000000000u128, 0x804000000000000000000000u128, 0x804000000000000000000000u128, 0x804000000000000000000000u128, 0x804000000000000000000000u128, ], ], [[0x10000000000000000000000u128, 0x4010000000000000000000000u128, 0x4010000000000000000000000u128, 0x4010000000000000000000000u128, 0x4010000000000000000000000u128, 0x4010000000000000000000000u128, 0x4010000000000000000000000u128, 0x4010000000000000000000000u128, ], [0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, ], [0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, ], [0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, 0x200000000000000000u128, ], [0x100000000000000000u128, 0x100400000000000000u128, 0x100401000000000000u128, 0x100401004000000000u128, 0x100401004010000000u128, 0x100401004010040000u128, 0x100401004010040100u128, 0x100401004010040100u128, ], [0x80000000000000000u128, 0x80100000000000000u128, 0x80100200000000000u128, 0x80100200400000000u128, 0x80100200400800000u128, 0x80100200400801000u128, 0x80100200400801002u128, 0x80100200400801002u128, ], [0x20000000000000000000u128, 0x30000000000000000000u128, 0x38000000000000000000u128, 0x3c000000000000000000u128, 0x3e000000000000000000u128, 0x3f000000000000000000u128, 0x3f800000000000000000u128, 0x3fc00000000000000000u128, ], [0x8000000000000000000000u128, 0x1008000000000000000000000u128, 0x1008000000000000000000000u128, 0x1008000000000000000000000u128, 0x1008000000000000000000000u128, 0x1008000000000000000000000u128, 0x1008000000000000000000000u128, 0x1008000000000000000000000u128, ], ], [[0x20000000000000000000000u128, 0x8020000000000000000000000u128, 0x8020000000000000000000000u128, 0x8020000000000000000000000u128, 0x8020000000000000000000000u128, 0x8020000000000000000000000u128, 0x8020000000000000000000000u128, 0x8020000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x200000000000000000u128, 0x200800000000000000u128, 0x200802000000000000u128, 0x200802008000000000u128, 0x200802008020000000u128, 0x200802008020080000u128, 0x200802008020080200u128, 0x200802008020080200u128, ], [0x100000000000000000u128, 0x100200000000000000u128, 0x100200400000000000u128, 0x100200400800000000u128, 0x100200400801000000u128, 0x100200400801002000u128, 0x100200400801002004u128, 0x100200400801002004u128, ], [0x40000000000000000000u128, 0x60000000000000000000u128, 0x70000000000000000000u128, 0x78000000000000000000u128, 0x7c000000000000000000u128, 0x7e000000000000000000u128, 0x7f000000000000000000u128, 0x7f800000000000000000u128, ], [0x10000000000000000000000u128, 0x2010000000000000000000000u128, 0x2010000000000000000000000u128, 0x2010000000000000000000000u128, 0x2010000000000000000000000u128, 0x2010000000000000000000000u128, 0x2010000000000000000000000u128, 0x2010000000000000000000000u128, ], ], [[0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, ], [0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, ], [0x200000000000000000000u128, 0x600000000000000000000u128, 0xe00000000000000000000u128, 0x1e00000000000000000000u128, 0x3e00000000000000000000u128, 0x7e00000000000000000000u128, 0xfe00000000000000000000u128, 0x1fe00000000000000000000u128, ], [0x800000000000000000u128, 0x804000000000000000u128, 0x804020000000000000u128, 0x804020100000000000u128, 0x804020100800000000u128, 0x804020100804000000u128, 0x804020100804020000u128, 0x804020100804020100u128, ], [0x400000000000000000u128, 0x401000000000000000u128, 0x401004000000000000u128, 0x401004010000000000u128, 0x401004010040000000u128, 0x401004010040100000u128, 0x401004010040100400u128, 0x401004010040100401u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, ], [0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, ], [0x400000000000000000000u128, 0xc00000000000000000000u128, 0x1c00000000000000000000u128, 0x3c00000000000000000000u128, 0x7c00000000000000000000u128, 0xfc00000000000000000000u128, 0x1fc00000000000000000000u128, 0x3fc00000000000000000000u128, ], [0x1000000000000000000u128, 0x1008000000000000000u128, 0x1008040000000000000u128, 0x1008040200000000000u128, 0x1008040201000000000u128, 0x1008040201008000000u128, 0x1008040201008040000u128, 0x1008040201008040200u128, ], [0x800000000000000000u128, 0x802000000000000000u128, 0x802008000000000000u128, 0x802008020000000000u128, 0x802008020080000000u128, 0x802008020080200000u128, 0x802008020080200800u128, 0x802008020080200802u128, ], [0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, 0x400000000000000000u128, ], [0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, ], [0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, ], ], [[0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, ], [0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, ], [0x800000000000000000000u128, 0x1800000000000000000000u128, 0x3800000000000000000000u128, 0x7800000000000000000000u128, 0xf800000000000000000000u128, 0x1f800000000000000000000u128, 0x3f800000000000000000000u128, 0x3f800000000000000000000u128, ], [0x2000000000000000000u128, 0x2010000000000000000u128, 0x2010080000000000000u128, 0x2010080400000000000u128, 0x2010080402000000000u128, 0x2010080402010000000u128, 0x2010080402010080000u128, 0x2010080402010080000u128, ], [0x1000000000000000000u128, 0x1004000000000000000u128, 0x1004010000000000000u128, 0x1004010040000000000u128, 0x1004010040100000000u128, 0x1004010040100400000u128, 0x1004010040100401000u128, 0x1004010040100401004u128, ], [0x800000000000000000u128, 0x801000000000000000u128, 0x801000000000000000u128, 0x801000000000000000u128, 0x801000000000000000u128, 0x801000000000000000u128, 0x801000000000000000u128, 0x801000000000000000u128, ], [0x200000000000000000000u128, 0x300000000000000000000u128, 0x300000000000000000000u128, 0x300000000000000000000u128, 0x300000000000000000000u128, 0x300000000000000000000u128, 0x300000000000000000000u128, 0x300000000000000000000u128, ], [0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, 0x80000000000000000000000u128, ], ], [[0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, ], [0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, ], [0x1000000000000000000000u128, 0x3000000000000000000000u128, 0x7000000000000000000000u128, 0xf000000000000000000000u128, 0x1f000000000000000000000u128, 0x3f000000000000000000000u128, 0x3f000000000000000000000u128, 0x3f000000000000000000000u128, ], [0x4000000000000000000u128, 0x4020000000000000000u128, 0x4020100000000000000u128, 0x4020100800000000000u128, 0x4020100804000000000u128, 0x4020100804020000000u128, 0x4020100804020000000u128, 0x4020100804020000000u128, ], [0x2000000000000000000u128, 0x2008000000000000000u128, 0x2008020000000000000u128, 0x2008020080000000000u128, 0x2008020080200000000u128, 0x2008020080200800000u128, 0x2008020080200802000u128, 0x2008020080200802008u128, ], [0x1000000000000000000u128, 0x1002000000000000000u128, 0x1002004000000000000u128, 0x1002004000000000000u128, 0x1002004000000000000u128, 0x1002004000000000000u128, 0x1002004000000000000u128, 0x1002004000000000000u128, ], [0x400000000000000000000u128, 0x600000000000000000000u128, 0x700000000000000000000u128, 0x700000000000000000000u128, 0x700000000000000000000u128, 0x700000000000000000000u128, 0x700000000000000000000u128, 0x700000000000000000000u128, ], [0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, 0x100000000000000000000000u128, ], ], [[0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, ], [0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, ], [0x2000000000000000000000u128, 0x6000000000000000000000u128, 0xe000000000000000000000u128, 0x1e000000000000000000000u128, 0x3e000000000000000000000u128, 0x3e000000000000000000000u128, 0x3e000000000000000000000u128, 0x3e000000000000000000000u128, ], [0x8000000000000000000u128, 0x8040000000000000000u128, 0x8040200000000000000u128, 0x8040201000000000000u128, 0x8040201008000000000u128, 0x8040201008000000000u128, 0x8040201008000000000u128, 0x8040201008000000000u128, ], [0x4000000000000000000u128, 0x4010000000000000000u128, 0x4010040000000000000u128, 0x4010040100000000000u128, 0x4010040100400000000u128, 0x4010040100401000000u128, 0x4010040100401004000u128, 0x4010040100401004010u128, ], [0x2000000000000000000u128, 0x2004000000000000000u128, 0x2004008000000000000u128, 0x2004008010000000000u128, 0x2004008010000000000u128, 0x2004008010000000000u128, 0x2004008010000000000u128, 0x2004008010000000000u128, ], [0x800000000000000000000u128, 0xc00000000000000000000u128, 0xe00000000000000000000u128, 0xf00000000000000000000u128, 0xf00000000000000000000u128, 0xf00000000000000000000u128, 0xf00000000000000000000u128, 0xf00000000000000000000u128, ], [0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, 0x200000000000000000000000u128, ], ], [[0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, ], [0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, ], [0x4000000000000000000000u128, 0xc000000000000000000000u128, 0x1c000000000000000000000u128, 0x3c000000000000000000000u128, 0x3c000000000000000000000u128, 0x3c000000000000000000000u128, 0x3c000000000000000000000u128, 0x3c000000000000000000000u128, ], [0x10000000000000000000u128, 0x10080000000000000000u128, 0x10080400000000000000u128, 0x10080402000000000000u128, 0x10080402000000000000u128, 0x10080402000000000000u128, 0x10080402000000000000u128, 0x10080402000000000000u128, ], [0x8000000000000000000u128, 0x8020000000000000000u128, 0x8020080000000000000u128, 0x8020080200000000000u128, 0x8020080200800000000u128, 0x8020080200802000000u128, 0x8020080200802008000u128, 0x8020080200802008020u128, ], [0x4000000000000000000u128, 0x4008000000000000000u128, 0x4008010000000000000u128, 0x4008010020000000000u128, 0x4008010020040000000u128, 0x4008010020040000000u128, 0x4008010020040000000u128, 0x4008010020040000000u128, ], [0x1000000000000000000000u128, 0x1800000000000000000000u128, 0x1c00000000000000000000u128, 0x1e00000000000000000000u128, 0x1f00000000000000000000u128, 0x1f00000000000000000000u128, 0x1f00000000000000000000u128, 0x1f00000000000000000000u128, ], [0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, 0x400000000000000000000000u128, ], ], [[0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, ], [0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, ], [0x8000000000000000000000u128, 0x18000000000000000000000u128, 0x38000000000000000000000u128, 0x38000000000000000000000u128, 0x38000000000000000000000u128, 0x38000000000000000000000u128, 0x38000000000000000000000u128, 0x38000000000000000000000u128, ], [0x20000000000000000000u128, 0x20100000000000000000u128, 0x20100800000000000000u128, 0x20100800000000000000u128, 0x20100800000000000000u128, 0x20100800000000000000u128, 0x20100800000000000000u128, 0x20100800000000000000u128, ], [0x10000000000000000000u128, 0x10040000000000000000u128, 0x10040100000000000000u128, 0x10040100400000000000u128, 0x10040100401000000000u128, 0x10040100401004000000u128, 0x10040100401004010000u128, 0x10040100401004010040u128, ], [0x8000000000000000000u128, 0x8010000000000000000u128, 0x8010020000000000000u128, 0x8010020040000000000u128, 0x8010020040080000000u128, 0x8010020040080100000u128, 0x8010020040080100000u128, 0x8010020040080100000u128, ], [0x2000000000000000000000u128, 0x3000000000000000000000u128, 0x3800000000000000000000u128, 0x3c00000000000000000000u128, 0x3e00000000000000000000u128, 0x3f00000000000000000000u128, 0x3f00000000000000000000u128, 0x3f00000000000000000000u128, ], [0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, 0x800000000000000000000000u128, ], ], [[0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, ], [0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, ], [0x10000000000000000000000u128, 0x30000000000000000000000u128, 0x30000000000000000000000u128, 0x30000000000000000000000u128, 0x30000000000000000000000u128, 0x30000000000000000000000u128, 0x30000000000000000000000u128, 0x30000000000000000000000u128, ], [0x40000000000000000000u128, 0x40200000000000000000u128, 0x40200000000000000000u128, 0x40200000000000000000u128, 0x40200000000000000000u128, 0x40200000000000000000u128, 0x40200000000000000000u128, 0x40200000000000000000u128, ], [0x20000000000000000000u128, 0x20080000000000000000u128, 0x20080200000000000000u128, 0x20080200800000000000u128, 0x20080200802000000000u128, 0x20080200802008000000u128, 0x20080200802008020000u128, 0x20080200802008020080u128, ], [0x10000000000000000000u128, 0x10020000000000000000u128, 0x10020040000000000000u128, 0x10020040080000000000u128, 0x10020040080100000000u128, 0x10020040080100200000u128, 0x10020040080100200400u128, 0x10020040080100200400u128, ], [0x4000000000000000000000u128, 0x6000000000000000000000u128, 0x7000000000000000000000u128, 0x7800000000000000000000u128, 0x7c00000000000000000000u128, 0x7e00000000000000000000u128, 0x7f00000000000000000000u128, 0x7f00000000000000000000u128, ], [0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, 0x1000000000000000000000000u128, ], ], [[0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, ], [0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, ], [0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, ], [0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, 0x80000000000000000000u128, ], [0x40000000000000000000u128, 0x40100000000000000000u128, 0x40100400000000000000u128, 0x40100401000000000000u128, 0x40100401004000000000u128, 0x40100401004010000000u128, 0x40100401004010040000u128, 0x40100401004010040100u128, ], [0x20000000000000000000u128, 0x20040000000000000000u128, 0x20040080000000000000u128, 0x20040080100000000000u128, 0x20040080100200000000u128, 0x20040080100200400000u128, 0x20040080100200400800u128, 0x20040080100200400801u128, ], [0x8000000000000000000000u128, 0xc000000000000000000000u128, 0xe000000000000000000000u128, 0xf000000000000000000000u128, 0xf800000000000000000000u128, 0xfc00000000000000000000u128, 0xfe00000000000000000000u128, 0xff00000000000000000000u128, ], [0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, 0x2000000000000000000000000u128, ], ], [[0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x80000000000000000000u128, 0x80200000000000000000u128, 0x80200800000000000000u128, 0x80200802000000000000u128, 0x80200802008000000000u128, 0x80200802008020000000u128, 0x80200802008020080000u128, 0x80200802008020080200u128, ], [0x40000000000000000000u128, 0x40080000000000000000u128, 0x40080100000000000000u128, 0x40080100200000000000u128, 0x40080100200400000000u128, 0x40080100200400800000u128, 0x40080100200400801000u128, 0x40080100200400801002u128, ], [0x10000000000000000000000u128, 0x18000000000000000000000u128, 0x1c000000000000000000000u128, 0x1e000000000000000000000u128, 0x1f000000000000000000000u128, 0x1f800000000000000000000u128, 0x1fc00000000000000000000u128, 0x1fe00000000000000000000u128, ], [0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, 0x4000000000000000000000000u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x80000000000000000000000u128, 0x180000000000000000000000u128, 0x380000000000000000000000u128, 0x780000000000000000000000u128, 0xf80000000000000000000000u128, 0x1f80000000000000000000000u128, 0x3f80000000000000000000000u128, 0x7f80000000000000000000000u128, ], [0x200000000000000000000u128, 0x201000000000000000000u128, 0x201008000000000000000u128, 0x201008040000000000000u128, 0x201008040200000000000u128, 0x201008040201000000000u128, 0x201008040201008000000u128, 0x201008040201008040000u128, ], [0x100000000000000000000u128, 0x100400000000000000000u128, 0x100401000000000000000u128, 0x100401004000000000000u128, 0x100401004010000000000u128, 0x100401004010040000000u128, 0x100401004010040100000u128, 0x100401004010040100400u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x100000000000000000000000u128, 0x300000000000000000000000u128, 0x700000000000000000000000u128, 0xf00000000000000000000000u128, 0x1f00000000000000000000000u128, 0x3f00000000000000000000000u128, 0x7f00000000000000000000000u128, 0xff00000000000000000000000u128, ], [0x400000000000000000000u128, 0x402000000000000000000u128, 0x402010000000000000000u128, 0x402010080000000000000u128, 0x402010080400000000000u128, 0x402010080402000000000u128, 0x402010080402010000000u128, 0x402010080402010080000u128, ], [0x200000000000000000000u128, 0x200800000000000000000u128, 0x200802000000000000000u128, 0x200802008000000000000u128, 0x200802008020000000000u128, 0x200802008020080000000u128, 0x200802008020080200000u128, 0x200802008020080200800u128, ], [0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, 0x100000000000000000000u128, ], [0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, 0x40000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x200000000000000000000000u128, 0x600000000000000000000000u128, 0xe00000000000000000000000u128, 0x1e00000000000000000000000u128, 0x3e00000000000000000000000u128, 0x7e00000000000000000000000u128, 0xfe00000000000000000000000u128, 0xfe00000000000000000000000u128, ], [0x800000000000000000000u128, 0x804000000000000000000u128, 0x804020000000000000000u128, 0x804020100000000000000u128, 0x804020100800000000000u128, 0x804020100804000000000u128, 0x804020100804020000000u128, 0x804020100804020000000u128, ], [0x400000000000000000000u128, 0x401000000000000000000u128, 0x401004000000000000000u128, 0x401004010000000000000u128, 0x401004010040000000000u128, 0x401004010040100000000u128, 0x401004010040100400000u128, 0x401004010040100401000u128, ], [0x200000000000000000000u128, 0x200400000000000000000u128, 0x200400000000000000000u128, 0x200400000000000000000u128, 0x200400000000000000000u128, 0x200400000000000000000u128, 0x200400000000000000000u128, 0x200400000000000000000u128, ], [0x80000000000000000000000u128, 0xc0000000000000000000000u128, 0xc0000000000000000000000u128, 0xc0000000000000000000000u128, 0xc0000000000000000000000u128, 0xc0000000000000000000000u128, 0xc0000000000000000000000u128, 0xc0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x400000000000000000000000u128, 0xc00000000000000000000000u128, 0x1c00000000000000000000000u128, 0x3c00000000000000000000000u128, 0x7c00000000000000000000000u128, 0xfc00000000000000000000000u128, 0xfc00000000000000000000000u128, 0xfc00000000000000000000000u128, ], [0x1000000000000000000000u128, 0x1008000000000000000000u128, 0x1008040000000000000000u128, 0x1008040200000000000000u128, 0x1008040201000000000000u128, 0x1008040201008000000000u128, 0x1008040201008000000000u128, 0x1008040201008000000000u128, ], [0x800000000000000000000u128, 0x802000000000000000000u128, 0x802008000000000000000u128, 0x802008020000000000000u128, 0x802008020080000000000u128, 0x802008020080200000000u128, 0x802008020080200800000u128, 0x802008020080200802000u128, ], [0x400000000000000000000u128, 0x400800000000000000000u128, 0x400801000000000000000u128, 0x400801000000000000000u128, 0x400801000000000000000u128, 0x400801000000000000000u128, 0x400801000000000000000u128, 0x400801000000000000000u128, ], [0x100000000000000000000000u128, 0x180000000000000000000000u128, 0x1c0000000000000000000000u128, 0x1c0000000000000000000000u128, 0x1c0000000000000000000000u128, 0x1c0000000000000000000000u128, 0x1c0000000000000000000000u128, 0x1c0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x800000000000000000000000u128, 0x1800000000000000000000000u128, 0x3800000000000000000000000u128, 0x7800000000000000000000000u128, 0xf800000000000000000000000u128, 0xf800000000000000000000000u128, 0xf800000000000000000000000u128, 0xf800000000000000000000000u128, ], [0x2000000000000000000000u128, 0x2010000000000000000000u128, 0x2010080000000000000000u128, 0x2010080400000000000000u128, 0x2010080402000000000000u128, 0x2010080402000000000000u128, 0x2010080402000000000000u128, 0x2010080402000000000000u128, ], [0x1000000000000000000000u128, 0x1004000000000000000000u128, 0x1004010000000000000000u128, 0x1004010040000000000000u128, 0x1004010040100000000000u128, 0x1004010040100400000000u128, 0x1004010040100401000000u128, 0x1004010040100401004000u128, ], [0x800000000000000000000u128, 0x801000000000000000000u128, 0x801002000000000000000u128, 0x801002004000000000000u128, 0x801002004000000000000u128, 0x801002004000000000000u128, 0x801002004000000000000u128, 0x801002004000000000000u128, ], [0x200000000000000000000000u128, 0x300000000000000000000000u128, 0x380000000000000000000000u128, 0x3c0000000000000000000000u128, 0x3c0000000000000000000000u128, 0x3c0000000000000000000000u128, 0x3c0000000000000000000000u128, 0x3c0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x1000000000000000000000000u128, 0x3000000000000000000000000u128, 0x7000000000000000000000000u128, 0xf000000000000000000000000u128, 0xf000000000000000000000000u128, 0xf000000000000000000000000u128, 0xf000000000000000000000000u128, 0xf000000000000000000000000u128, ], [0x4000000000000000000000u128, 0x4020000000000000000000u128, 0x4020100000000000000000u128, 0x4020100800000000000000u128, 0x4020100800000000000000u128, 0x4020100800000000000000u128, 0x4020100800000000000000u128, 0x4020100800000000000000u128, ], [0x2000000000000000000000u128, 0x2008000000000000000000u128, 0x2008020000000000000000u128, 0x2008020080000000000000u128, 0x2008020080200000000000u128, 0x2008020080200800000000u128, 0x2008020080200802000000u128, 0x2008020080200802008000u128, ], [0x1000000000000000000000u128, 0x1002000000000000000000u128, 0x1002004000000000000000u128, 0x1002004008000000000000u128, 0x1002004008010000000000u128, 0x1002004008010000000000u128, 0x1002004008010000000000u128, 0x1002004008010000000000u128, ], [0x400000000000000000000000u128, 0x600000000000000000000000u128, 0x700000000000000000000000u128, 0x780000000000000000000000u128, 0x7c0000000000000000000000u128, 0x7c0000000000000000000000u128, 0x7c0000000000000000000000u128, 0x7c0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x2000000000000000000000000u128, 0x6000000000000000000000000u128, 0xe000000000000000000000000u128, 0xe000000000000000000000000u128, 0xe000000000000000000000000u128, 0xe000000000000000000000000u128, 0xe000000000000000000000000u128, 0xe000000000000000000000000u128, ], [0x8000000000000000000000u128, 0x8040000000000000000000u128, 0x8040200000000000000000u128, 0x8040200000000000000000u128, 0x8040200000000000000000u128, 0x8040200000000000000000u128, 0x8040200000000000000000u128, 0x8040200000000000000000u128, ], [0x4000000000000000000000u128, 0x4010000000000000000000u128, 0x4010040000000000000000u128, 0x4010040100000000000000u128, 0x4010040100400000000000u128, 0x4010040100401000000000u128, 0x4010040100401004000000u128, 0x4010040100401004010000u128, ], [0x2000000000000000000000u128, 0x2004000000000000000000u128, 0x2004008000000000000000u128, 0x2004008010000000000000u128, 0x2004008010020000000000u128, 0x2004008010020040000000u128, 0x2004008010020040000000u128, 0x2004008010020040000000u128, ], [0x800000000000000000000000u128, 0xc00000000000000000000000u128, 0xe00000000000000000000000u128, 0xf00000000000000000000000u128, 0xf80000000000000000000000u128, 0xfc0000000000000000000000u128, 0xfc0000000000000000000000u128, 0xfc0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x4000000000000000000000000u128, 0xc000000000000000000000000u128, 0xc000000000000000000000000u128, 0xc000000000000000000000000u128, 0xc000000000000000000000000u128, 0xc000000000000000000000000u128, 0xc000000000000000000000000u128, 0xc000000000000000000000000u128, ], [0x10000000000000000000000u128, 0x10080000000000000000000u128, 0x10080000000000000000000u128, 0x10080000000000000000000u128, 0x10080000000000000000000u128, 0x10080000000000000000000u128, 0x10080000000000000000000u128, 0x10080000000000000000000u128, ], [0x8000000000000000000000u128, 0x8020000000000000000000u128, 0x8020080000000000000000u128, 0x8020080200000000000000u128, 0x8020080200800000000000u128, 0x8020080200802000000000u128, 0x8020080200802008000000u128, 0x8020080200802008020000u128, ], [0x4000000000000000000000u128, 0x4008000000000000000000u128, 0x4008010000000000000000u128, 0x4008010020000000000000u128, 0x4008010020040000000000u128, 0x4008010020040080000000u128, 0x4008010020040080100000u128, 0x4008010020040080100000u128, ], [0x1000000000000000000000000u128, 0x1800000000000000000000000u128, 0x1c00000000000000000000000u128, 0x1e00000000000000000000000u128, 0x1f00000000000000000000000u128, 0x1f80000000000000000000000u128, 0x1fc0000000000000000000000u128, 0x1fc0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, 0x8000000000000000000000000u128, ], [0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, 0x20000000000000000000000u128, ], [0x10000000000000000000000u128, 0x10040000000000000000000u128, 0x10040100000000000000000u128, 0x10040100400000000000000u128, 0x10040100401000000000000u128, 0x10040100401004000000000u128, 0x10040100401004010000000u128, 0x10040100401004010040000u128, ], [0x8000000000000000000000u128, 0x8010000000000000000000u128, 0x8010020000000000000000u128, 0x8010020040000000000000u128, 0x8010020040080000000000u128, 0x8010020040080100000000u128, 0x8010020040080100200000u128, 0x8010020040080100200400u128, ], [0x2000000000000000000000000u128, 0x3000000000000000000000000u128, 0x3800000000000000000000000u128, 0x3c00000000000000000000000u128, 0x3e00000000000000000000000u128, 0x3f00000000000000000000000u128, 0x3f80000000000000000000000u128, 0x3fc0000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], [[0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], [0x20000000000000000000000u128, 0x20080000000000000000000u128, 0x20080200000000000000000u128, 0x20080200800000000000000u128, 0x20080200802000000000000u128, 0x20080200802008000000000u128, 0x20080200802008020000000u128, 0x20080200802008020080000u128, ], [0x10000000000000000000000u128, 0x10020000000000000000000u128, 0x10020040000000000000000u128, 0x10020040080000000000000u128, 0x10020040080100000000000u128, 0x10020040080100200000000u128, 0x10020040080100200400000u128, 0x10020040080100200400800u128, ], [0x4000000000000000000000000u128, 0x6000000000000000000000000u128, 0x7000000000000000000000000u128, 0x7800000000000000000000000u128, 0x7c00000000000000000000000u128, 0x7e00000000000000000000000u128, 0x7f00000000000000000000000u128, 0x7f80000000000000000000000u128, ], [0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, 0x0u128, ], ], ];
+pub const DISTANCE_TO_MID: [f64; 100] = [0.9999999999998316, 0.8958064164774657, 0.8089010988088102, 0.7453559924998043, 0.71145824860353, 0.71145824860353, 0.7453559924998043, 0.8089010988088102, 0.8958064164774657, 0.9999999999998316, 0.8958064164774657, 0.7777777777776468, 0.675862503366355, 0.5983516452370664, 0.555555555555462, 0.555555555555462, 0.5983516452370664, 0.675862503366355, 0.7777777777776468, 0.8958064164774657, 0.8089010988088102, 0.675862503366355, 0.555555555555462, 0.458122847290774, 0.4006168083848202, 0.4006168083848202, 0.458122847290774, 0.555555555555462, 0.675862503366355, 0.8089010988088102, 0.7453559924998043, 0.5983516452370664, 0.458122847290774, 0.33333333333327714,0.2484519974999348, 0.2484519974999348, 0.33333333333327714, 0.458122847290774, 0.5983516452370664, 0.7453559924998043, 0.71145824860353, 0.555555555555462, 0.4006168083848202,0.2484519974999348, 0.1111111111110924, 0.1111111111110924, 0.2484519974999348, 0.4006168083848202, 0.555555555555462, 0.71145824860353, 0.71145824860353, 0.555555555555462,0.4006168083848202, 0.2484519974999348, 0.1111111111110924, 0.1111111111110924, 0.2484519974999348, 0.4006168083848202, 0.555555555555462, 0.71145824860353, 0.7453559924998043,0.5983516452370664, 0.458122847290774, 0.33333333333327714, 0.2484519974999348, 0.2484519974999348, 0.33333333333327714, 0.458122847290774, 0.5983516452370664, 0.7453559924998043, 0.8089010988088102, 0.675862503366355, 0.555555555555462, 0.458122847290774, 0.4006168083848202,0.4006168083848202, 0.458122847290774, 0.555555555555462, 0.675862503366355, 0.8089010988088102, 0.8958064164774657, 0.7777777777776468, 0.675862503366355, 0.5983516452370664,0.555555555555462, 0.555555555555462, 0.5983516452370664, 0.675862503366355, 0.7777777777776468, 0.8958064164774657, 0.9999999999998316, 0.8958064164774657, 0.8089010988088102,0.7453559924998043, 0.71145824860353, 0.71145824860353, 0.7453559924998043, 0.8089010988088102, 0.8958064164774657, 0.9999999999998316, ];
\ No newline at end of file
@@ -0,0 +1,322 @@
+use super::game_state::{GameMove, GameState, GameStatus, GameColor};
+use super::game_logic::{get_possible_moves, make_move};
+use super::constants;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+//The solver takes over once at most this many plies are left ...
+pub const SOLVER_PLIES: u8 = 6;
+//... or both colors together have at most this many fish
+pub const SOLVER_FISH: u32 = 8;
+//Node budget of one solve if the search has no node limit
+pub const SOLVER_NODES: usize = 20_000_000;
+pub const SOLVER_TABLE_SIZE: usize = 1048576;
+
+//Values are from the point of view of the side to move: a game won at ply p is worth WIN - p.
+//Because the ply the game ends at is stored instead of the distance, values are the same at every node of a line
+pub const WIN: i32 = 1000;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Outcome {
+    //Plies until the game ends
+    Win(u8),
+    Draw,
+    Loss(u8),
+}
+
+impl Outcome {
+    pub fn from_value(value: i32, plies_played: u8) -> Outcome {
+        if value > 0 {
+            Outcome::Win((WIN - value) as u8 - plies_played)
+        } else if value < 0 {
+            Outcome::Loss((WIN + value) as u8 - plies_played)
+        } else {
+            Outcome::Draw
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Win(plies) => write!(formatter, "win in {} plies", plies),
+            Outcome::Draw => write!(formatter, "draw"),
+            Outcome::Loss(plies) => write!(formatter, "loss in {} plies", plies),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Copy, Clone)]
+struct SolverEntry {
+    hash: u64,
+    //The hash does not contain the ply, but the value depends on it
+    plies_played: u8,
+    value: i32,
+    bound: Bound,
+    gm: Option<GameMove>,
+}
+
+pub struct Solution {
+    pub outcome: Outcome,
+    pub value: i32,
+    //Best play for both sides until the end of the game, as far as the table still knows it
+    pub line: Vec<GameMove>,
+    pub hashes: Vec<u64>,
+}
+
+impl Solution {
+    pub fn score(&self) -> f64 {
+        value_to_score(self.value)
+    }
+}
+
+//A solver value on the scale of alpha_beta's game over scores
+pub fn value_to_score(value: i32) -> f64 {
+    if value > 0 {
+        30000.0 - (WIN - value) as f64
+    } else if value < 0 {
+        -30000.0 + (WIN + value) as f64
+    } else {
+        0.0
+    }
+}
+
+//Exact search to the end of the game with its own table. It only knows win, draw and loss, so every
+//result it returns is proven and never depends on the evaluation
+pub struct Solver {
+    table: Vec<Option<SolverEntry>>,
+    pub nodes: usize,
+    max_nodes: usize,
+    deadline: Option<Instant>,
+    aborted: bool,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Solver::new()
+    }
+}
+
+impl Solver {
+    pub fn new() -> Solver {
+        Solver {
+            table: vec![None; SOLVER_TABLE_SIZE],
+            nodes: 0,
+            max_nodes: 0,
+            deadline: None,
+            aborted: false,
+        }
+    }
+
+    //Whether the position is small enough to be handed to the solver
+    pub fn applies(game_state: &GameState) -> bool {
+        game_state.plies_left() <= SOLVER_PLIES || (game_state.rote_fische | game_state.blaue_fische).count_ones() <= SOLVER_FISH
+    }
+
+    //Proves the result of the position. Returns None if the node budget, the deadline or the stop signal
+    //ended the search before that
+    pub fn solve(&mut self, game_state: &mut GameState, max_nodes: usize, deadline: Option<Instant>, stop_signal: Option<&AtomicBool>) -> Option<Solution> {
+        if game_state.schwaerme.is_none() {
+            game_state.refresh_schwaerme();
+        }
+        self.nodes = 0;
+        self.max_nodes = max_nodes;
+        self.deadline = deadline;
+        self.aborted = false;
+        let value = self.search(game_state, -WIN - 1, WIN + 1, stop_signal);
+        if self.aborted {
+            return None;
+        }
+        let (line, hashes) = self.line(game_state, stop_signal);
+        Some(Solution { outcome: Outcome::from_value(value, game_state.plies_played), value, line, hashes })
+    }
+
+    //Proven value of the position, if the table has one
+    pub fn probe(&self, game_state: &GameState) -> Option<i32> {
+        match self.table[(game_state.hash as usize) % SOLVER_TABLE_SIZE] {
+            Some(entry) if entry.hash == game_state.hash && entry.plies_played == game_state.plies_played && entry.bound == Bound::Exact => Some(entry.value),
+            _ => None,
+        }
+    }
+
+    fn search(&mut self, game_state: &mut GameState, mut alpha: i32, mut beta: i32, stop_signal: Option<&AtomicBool>) -> i32 {
+        self.nodes += 1;
+        if self.nodes.is_multiple_of(1024) {
+            let time_over = self.deadline.is_some_and(|deadline| Instant::now() >= deadline);
+            let stopped = stop_signal.is_some_and(|signal| signal.load(Ordering::Relaxed));
+            self.aborted |= time_over || stopped;
+        }
+        if self.nodes >= self.max_nodes {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return 0;
+        }
+        let mut move_list = get_possible_moves(game_state, &game_state.move_color, false);
+        game_state.analyze(&move_list);
+        if game_state.game_over() {
+            return terminal_value(game_state);
+        }
+        let original_alpha = alpha;
+        let index = (game_state.hash as usize) % SOLVER_TABLE_SIZE;
+        let mut table_move = None;
+        if let Some(entry) = self.table[index] {
+            if entry.hash == game_state.hash && entry.plies_played == game_state.plies_played {
+                match entry.bound {
+                    Bound::Exact => return entry.value,
+                    Bound::Lower => alpha = alpha.max(entry.value),
+                    Bound::Upper => beta = beta.min(entry.value),
+                }
+                if alpha >= beta {
+                    return entry.value;
+                }
+                table_move = entry.gm;
+            }
+        }
+        //Table move first, then moves towards the middle, which tend to connect the swarm
+        move_list.sort_by(|a, b| {
+            let rating = |mv: &GameMove| constants::DISTANCE_TO_MID[mv.from as usize] - constants::DISTANCE_TO_MID[mv.to as usize];
+            rating(b).partial_cmp(&rating(a)).unwrap()
+        });
+        if let Some(position) = table_move.and_then(|gm| move_list.iter().position(|mv| *mv == gm)) {
+            let gm = move_list.remove(position);
+            move_list.insert(0, gm);
+        }
+        let mut best = -WIN - 1;
+        let mut best_move = None;
+        for mv in move_list {
+            let mut next_state = make_move(game_state, &mv);
+            let value = -self.search(&mut next_state, -beta, -alpha, stop_signal);
+            if self.aborted {
+                return 0;
+            }
+            if value > best {
+                best = value;
+                best_move = Some(mv);
+            }
+            if best > alpha {
+                alpha = best;
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+        let bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.table[index] = Some(SolverEntry { hash: game_state.hash, plies_played: game_state.plies_played, value: best, bound, gm: best_move });
+        best
+    }
+
+    //Follows the best moves from the position on. Nodes that were only bounded, because a cutoff was enough
+    //for the move before, are searched again with the full window
+    fn line(&mut self, game_state: &GameState, stop_signal: Option<&AtomicBool>) -> (Vec<GameMove>, Vec<u64>) {
+        let mut line = Vec::new();
+        let mut hashes = Vec::new();
+        let mut current = GameState::from_fen(&game_state.to_fen());
+        current.refresh_schwaerme();
+        loop {
+            if self.probe(&current).is_none() {
+                self.search(&mut current, -WIN - 1, WIN + 1, stop_signal);
+            }
+            let move_list = get_possible_moves(&current, &current.move_color, false);
+            current.analyze(&move_list);
+            //The root is proven, a line cut short by the limits is still good to know
+            if self.aborted || current.game_over() {
+                break;
+            }
+            let gm = match self.table[(current.hash as usize) % SOLVER_TABLE_SIZE] {
+                Some(entry) if entry.hash == current.hash && entry.plies_played == current.plies_played && entry.bound == Bound::Exact => entry.gm,
+                _ => None,
+            };
+            match gm {
+                Some(gm) => {
+                    line.push(gm);
+                    hashes.push(current.hash);
+                    current = make_move(&current, &gm);
+                }
+                None => break,
+            }
+        }
+        (line, hashes)
+    }
+}
+
+fn terminal_value(game_state: &GameState) -> i32 {
+    let winner = match game_state.game_status {
+        Some(GameStatus::RedWin) => GameColor::Red,
+        Some(GameStatus::BlueWin) => GameColor::Blue,
+        Some(GameStatus::Draw) => return 0,
+        _ => panic!("Invalid game over situation"),
+    };
+    let value = WIN - game_state.plies_played as i32;
+    if winner == game_state.move_color { value } else { -value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{self, random_positions};
+
+    //Value of the position as the solver defines it, without table or pruning
+    fn minimax_value(game_state: &mut GameState) -> i32 {
+        let move_list = game_logic::get_possible_moves(game_state, &game_state.move_color, false);
+        game_state.analyze(&move_list);
+        if game_state.game_over() {
+            let value = WIN - game_state.plies_played as i32;
+            return match game_state.game_status {
+                Some(GameStatus::Draw) => 0,
+                Some(GameStatus::RedWin) if game_state.move_color == GameColor::Red => value,
+                Some(GameStatus::BlueWin) if game_state.move_color == GameColor::Blue => value,
+                _ => -value,
+            };
+        }
+        move_list.iter().map(|mv| -minimax_value(&mut game_logic::make_move(game_state, mv))).max().unwrap()
+    }
+
+    #[test]
+    fn endgame_solver_matches_minimax() -> Result<(), String> {
+        let mut solver = Solver::new();
+        let mut checked = 0;
+        let mut positions: Vec<GameState> = random_positions(30).into_iter().filter(|game_state| !game_state.game_over() && game_state.plies_played >= 57).collect();
+        for game_state in positions.iter_mut().take(12) {
+            let expected = minimax_value(&mut GameState::from_fen(&game_state.to_fen()));
+            let solution = solver.solve(game_state, usize::MAX, None, None).ok_or("Solver gave up without limits")?;
+            if solution.value != expected {
+                return Err(format!("Solver says {} ({}), minimax {} in {}", solution.value, solution.outcome, expected, game_state.to_fen()));
+            }
+            //Playing the solver's line has to end the game with exactly that result
+            let mut end = GameState::from_fen(&game_state.to_fen());
+            for mv in &solution.line {
+                end = game_logic::make_move(&end, mv);
+            }
+            if minimax_value(&mut end) != if solution.line.len() % 2 == 0 { expected } else { -expected } || !end.game_over() {
+                return Err(format!("Line of {} moves does not reach the solved end in {}", solution.line.len(), game_state.to_fen()));
+            }
+            let plies = match solution.outcome {
+                Outcome::Win(plies) | Outcome::Loss(plies) => plies as usize,
+                Outcome::Draw => solution.line.len(),
+            };
+            if plies != solution.line.len() {
+                return Err(format!("{} but the line has {} moves", solution.outcome, solution.line.len()));
+            }
+            checked += 1;
+        }
+        if checked == 0 {
+            return Err(String::from("No random playout reached ply 57"));
+        }
+        Ok(())
+    }
+}
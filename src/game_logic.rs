@@ -1,6 +1,12 @@
 use super::game_state::{GameState, GameColor, DIRECTIONS, GameMove};
 use super::constants;
+use super::bitboard::{Bitboard, Square};
 use super::zobrist;
+use super::nnue;
+use super::random;
+use rand::Rng;
+use std::convert::TryFrom;
+use std::time::Instant;
 
 #[inline(always)]
 pub fn get_schwarm(gs: &GameState, gc: &GameColor) -> u8 {
@@ -16,9 +22,9 @@ pub fn get_schwarm(gs: &GameState, gc: &GameColor) -> u8 {
     let mut neighboring_fields_and_meine_fische: u128 = meine_fische;
     loop {
         result += 1;
-        let fisch_bit: usize = neighboring_fields_and_meine_fische.trailing_zeros() as usize;
-        meine_fische &= !(1u128 << fisch_bit);
-        neighboring_fields |= constants::NACHBARN[fisch_bit];
+        let fisch = Square::from_index(neighboring_fields_and_meine_fische.trailing_zeros() as u8);
+        meine_fische &= !fisch.bitboard().0;
+        neighboring_fields |= fisch.neighbours().0;
         neighboring_fields_and_meine_fische = neighboring_fields & meine_fische;
         if neighboring_fields_and_meine_fische == 0u128 {
             break;
@@ -36,10 +42,10 @@ pub fn get_schwarm_board(mut meine_fische: u128) -> u128 {
     let mut neighboring_fields: u128 = 0u128;
     let mut neighboring_fields_and_meine_fische: u128 = meine_fische;
     loop {
-        let fisch_bit: usize = neighboring_fields_and_meine_fische.trailing_zeros() as usize;
-        meine_fische ^= 1u128 << fisch_bit;
-        result |= 1u128 << fisch_bit;
-        neighboring_fields |= constants::NACHBARN[fisch_bit];
+        let fisch = Square::from_index(neighboring_fields_and_meine_fische.trailing_zeros() as u8);
+        meine_fische ^= fisch.bitboard().0;
+        result |= fisch.bitboard().0;
+        neighboring_fields |= fisch.neighbours().0;
         neighboring_fields_and_meine_fische = neighboring_fields & meine_fische;
         if neighboring_fields_and_meine_fische == 0u128 {
             break;
@@ -48,6 +54,131 @@ pub fn get_schwarm_board(mut meine_fische: u128) -> u128 {
     result
 }
 
+//Moves every fish of board one square into all eight directions, keeping the original fish
+#[inline(always)]
+pub fn dilate(board: u128) -> u128 {
+    let left = board & constants::NOT_X0;
+    let right = board & constants::NOT_X9;
+    (board | board << 10 | board >> 10 | right << 1 | left >> 1 | right << 11 | left << 9 | right >> 9 | left >> 11) & constants::BOARD
+}
+
+//Same result as get_schwarm_board, but grows the swarm in all directions at once instead of fish by fish
+#[inline(always)]
+pub fn get_schwarm_board_flood(meine_fische: u128) -> u128 {
+    if meine_fische == 0u128 {
+        return 0u128;
+    }
+    let mut result: u128 = meine_fische & meine_fische.wrapping_neg();
+    loop {
+        let next = dilate(result) & meine_fische;
+        if next == result {
+            return result;
+        }
+        result = next;
+    }
+}
+
+pub fn is_one_fish_missing(fische: u128) -> bool {
+    let fisch_anz = fische.count_ones() as usize;
+    let board_one = get_schwarm_board(fische);
+    let board_fische = board_one.count_ones() as usize;
+    board_fische >= fisch_anz - 1 || (board_fische == 1 && get_schwarm_board(fische & !board_one).count_ones() as usize == fisch_anz - 1)
+}
+
+pub const MAX_SCHWAERME: usize = 16;
+
+//Decomposition of one color's fish into swarms, ordered by their lowest square like repeated get_schwarm_board calls
+#[derive(Copy, Clone)]
+pub struct Schwaerme {
+    pub boards: [u128; MAX_SCHWAERME],
+    pub count: usize,
+}
+
+impl Schwaerme {
+    pub fn new(mut fische: u128) -> Schwaerme {
+        let mut res = Schwaerme { boards: [0u128; MAX_SCHWAERME], count: 0 };
+        while fische != 0u128 {
+            let board = get_schwarm_board(fische);
+            res.boards[res.count] = board;
+            res.count += 1;
+            fische ^= board;
+        }
+        res
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, u128> {
+        self.boards[..self.count].iter()
+    }
+
+    pub fn fische(&self) -> u128 {
+        self.iter().fold(0u128, |acc, board| acc | board)
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.count <= 1
+    }
+
+    pub fn biggest(&self) -> usize {
+        self.iter().map(|board| board.count_ones() as usize).max().unwrap_or(0)
+    }
+
+    pub fn is_one_fish_missing(&self) -> bool {
+        self.biggest() + 1 >= self.fische().count_ones() as usize
+    }
+
+    //Only the swarms touching the from square or neighboring the to square can change
+    #[inline(always)]
+    pub fn apply_move(&mut self, from: Square, to: Square) {
+        let from_square = from.bitboard().0;
+        let touched = to.neighbours().0 | from_square;
+        let mut affected = to.bitboard().0;
+        let mut i = 0;
+        while i < self.count {
+            if self.boards[i] & touched != 0u128 {
+                affected |= self.boards[i];
+                self.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        self.insert_fische(affected & !from_square);
+    }
+
+    #[inline(always)]
+    pub fn remove_fish(&mut self, square: Square) {
+        let fisch = square.bitboard().0;
+        if let Some(i) = self.iter().position(|board| board & fisch != 0u128) {
+            let rest = self.boards[i] ^ fisch;
+            self.remove(i);
+            self.insert_fische(rest);
+        }
+    }
+
+    fn insert_fische(&mut self, mut fische: u128) {
+        while fische != 0u128 {
+            let board = get_schwarm_board(fische);
+            self.insert(board);
+            fische ^= board;
+        }
+    }
+
+    fn insert(&mut self, board: u128) {
+        let lowest = board.trailing_zeros();
+        let mut i = self.count;
+        while i > 0 && self.boards[i - 1].trailing_zeros() > lowest {
+            self.boards[i] = self.boards[i - 1];
+            i -= 1;
+        }
+        self.boards[i] = board;
+        self.count += 1;
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.boards.copy_within(index + 1..self.count, index);
+        self.count -= 1;
+    }
+}
+
 #[inline(always)]
 pub fn get_possible_moves(gs: &GameState, gc: &GameColor, early_return: bool) -> Vec<GameMove> {
     let mut res: Vec<GameMove> = Vec::with_capacity(90);
@@ -55,18 +186,16 @@ pub fn get_possible_moves(gs: &GameState, gc: &GameColor, early_return: bool) ->
         GameColor::Red => (gs.rote_fische, gs.blaue_fische),
         GameColor::Blue => (gs.blaue_fische, gs.rote_fische),
     };
-    let mut fisch_iterator = meine_fische;
-    while fisch_iterator != 0u128 {
-        let fisch_pos = fisch_iterator.trailing_zeros() as usize;
+    for fisch in Bitboard(meine_fische) {
+        let fisch_pos = fisch.index();
         for i in 0..4 {
             let squares: usize = (constants::ATTACK_TWO_SIDED[fisch_pos][i] & (meine_fische | gegner_fische)).count_ones() as usize;
             for j in 0..2 {
                 let destination: isize = fisch_pos as isize + DIRECTIONS[i] as isize * if j == 0 { 1isize } else { -1isize } * squares as isize;
-                if destination <= 99 && destination >= 0 {
-                    let destination_square: u128 = 1u128 << destination;
-                    if (destination_square & (meine_fische | gs.kraken)) == 0 && (constants::ATTACK_TWO_SIDED[fisch_pos][i] & destination_square) != 0 {
+                if let Some(destination) = u8::try_from(destination).ok().and_then(Square::new) {
+                    if !Bitboard(meine_fische | gs.kraken).contains(destination) && Bitboard(constants::ATTACK_TWO_SIDED[fisch_pos][i]).contains(destination) {
                         if squares < 2 || (constants::ATTACK_ONE_SIDED_SKIPPED_SQUARES[fisch_pos][i + if j == 0 { 0usize } else { 4usize }][squares - 2] & gegner_fische) == 0u128 {
-                            res.push(GameMove::new(fisch_pos as u8, destination as u8));
+                            res.push(GameMove::from_squares(fisch, destination));
                             if early_return {
                                 return res;
                             }
@@ -75,45 +204,181 @@ pub fn get_possible_moves(gs: &GameState, gc: &GameColor, early_return: bool) ->
                 }
             }
         }
-        fisch_iterator &= !(1u128 << fisch_pos);
     }
     res
 }
 
 #[inline(always)]
 pub fn make_move(gs: &GameState, gm: &GameMove) -> GameState {
+    let (from, to) = (gm.from_square(), gm.to_square());
     match gs.move_color {
         GameColor::Red => {
-            let mut new_red: u128 = gs.rote_fische & !(1u128 << gm.from);
-            new_red |= 1u128 << gm.to;
-            let new_blau: u128 = gs.blaue_fische & !(1u128 << gm.to);
+            let new_red: u128 = (gs.rote_fische & !from.bitboard().0) | to.bitboard().0;
+            let new_blau: u128 = gs.blaue_fische & !to.bitboard().0;
             //Update hash
             let mut hash = gs.hash;
-            hash ^= zobrist::ZOBRIST_KEYS[(gm.from / 10) as usize][(gm.from % 10) as usize][0];
-            hash ^= zobrist::ZOBRIST_KEYS[(gm.to / 10) as usize][(gm.to % 10) as usize][0];
+            hash ^= zobrist::ZOBRIST_KEYS[from.index()][0];
+            hash ^= zobrist::ZOBRIST_KEYS[to.index()][0];
             if new_blau != gs.blaue_fische {
-                hash ^= zobrist::ZOBRIST_KEYS[(gm.to / 10) as usize][(gm.to % 10) as usize][1];
+                hash ^= zobrist::ZOBRIST_KEYS[to.index()][1];
             }
             hash ^= zobrist::SIDE_TO_MOVE_IS_BLUE;
-            GameState::new(new_red, new_blau, gs.kraken, gs.plies_played + 1, gs.rounds_played, GameColor::Blue, hash)
+            let mut res = GameState::new(new_red, new_blau, gs.kraken, gs.plies_played + 1, gs.rounds_played, GameColor::Blue, hash);
+            res.accumulator = updated_accumulator(gs, gm, new_blau != gs.blaue_fische);
+            res.schwaerme = updated_schwaerme(gs, gm, new_blau != gs.blaue_fische);
+            res
         }
         GameColor::Blue => {
-            let mut new_blau: u128 = gs.blaue_fische & !(1u128 << gm.from);
-            new_blau |= 1u128 << gm.to;
-            let new_red: u128 = gs.rote_fische & !(1u128 << gm.to);
+            let new_blau: u128 = (gs.blaue_fische & !from.bitboard().0) | to.bitboard().0;
+            let new_red: u128 = gs.rote_fische & !to.bitboard().0;
             let mut hash = gs.hash;
-            hash ^= zobrist::ZOBRIST_KEYS[(gm.from / 10) as usize][(gm.from % 10) as usize][1];
-            hash ^= zobrist::ZOBRIST_KEYS[(gm.to / 10) as usize][(gm.to % 10) as usize][1];
+            hash ^= zobrist::ZOBRIST_KEYS[from.index()][1];
+            hash ^= zobrist::ZOBRIST_KEYS[to.index()][1];
             if new_red != gs.rote_fische {
-                hash ^= zobrist::ZOBRIST_KEYS[(gm.to / 10) as usize][(gm.to % 10) as usize][0];
+                hash ^= zobrist::ZOBRIST_KEYS[to.index()][0];
             }
             hash ^= zobrist::SIDE_TO_MOVE_IS_BLUE;
-            GameState::new(new_red, new_blau, gs.kraken, gs.plies_played + 1, gs.rounds_played + 1, GameColor::Red, hash)
+            let mut res = GameState::new(new_red, new_blau, gs.kraken, gs.plies_played + 1, gs.rounds_played + 1, GameColor::Red, hash);
+            res.accumulator = updated_accumulator(gs, gm, new_red != gs.rote_fische);
+            res.schwaerme = updated_schwaerme(gs, gm, new_red != gs.rote_fische);
+            res
         }
     }
 }
 
+#[inline(always)]
+fn updated_accumulator(gs: &GameState, gm: &GameMove, captured: bool) -> Option<nnue::Accumulator> {
+    match (&gs.accumulator, nnue::network()) {
+        (Some(acc), Some(net)) => {
+            let mut acc = *acc;
+            acc.apply_move(net, gm, &gs.move_color, captured);
+            Some(acc)
+        }
+        _ => None,
+    }
+}
+
+#[inline(always)]
+fn updated_schwaerme(gs: &GameState, gm: &GameMove, captured: bool) -> Option<[Schwaerme; 2]> {
+    gs.schwaerme.map(|mut schwaerme| {
+        let (mine, theirs) = match gs.move_color {
+            GameColor::Red => (0, 1),
+            GameColor::Blue => (1, 0),
+        };
+        schwaerme[mine].apply_move(gm.from_square(), gm.to_square());
+        if captured {
+            schwaerme[theirs].remove_fish(gm.to_square());
+        }
+        schwaerme
+    })
+}
+
 #[inline(always)]
 pub fn make_null_move(gs: &GameState) -> GameState {
-    GameState::new(gs.rote_fische, gs.blaue_fische, gs.kraken, gs.plies_played + 1, gs.rounds_played + if let GameColor::Blue = gs.move_color { 1 } else { 0 }, if let GameColor::Blue = gs.move_color { GameColor::Red } else { GameColor::Blue }, gs.hash ^ zobrist::SIDE_TO_MOVE_IS_BLUE)
-}
\ No newline at end of file
+    let mut res = GameState::new(gs.rote_fische, gs.blaue_fische, gs.kraken, gs.plies_played + 1, gs.rounds_played + if let GameColor::Blue = gs.move_color { 1 } else { 0 }, if let GameColor::Blue = gs.move_color { GameColor::Red } else { GameColor::Blue }, gs.hash ^ zobrist::SIDE_TO_MOVE_IS_BLUE);
+    res.accumulator = gs.accumulator;
+    res.schwaerme = gs.schwaerme;
+    res
+}
+
+//A swarm finder with its name, as compared by benchmark_schwarm_board
+type SchwarmBoardFn = (&'static str, fn(u128) -> u128);
+
+//Compares get_schwarm_board with get_schwarm_board_flood by decomposing the given boards into swarms
+pub fn benchmark_schwarm_board(boards: &[u128]) {
+    let implementations: [SchwarmBoardFn; 2] = [("get_schwarm_board", get_schwarm_board), ("get_schwarm_board_flood", get_schwarm_board_flood)];
+    for (name, schwarm_board) in implementations.iter() {
+        let mut checksum = 0u32;
+        let time = Instant::now();
+        for board in boards {
+            let mut fische = *board;
+            while fische != 0u128 {
+                let schwarm = schwarm_board(fische);
+                checksum = checksum.wrapping_add(schwarm.count_ones());
+                fische ^= schwarm;
+            }
+        }
+        let nanos = time.elapsed().as_nanos() as f64;
+        println!("{:<24} {:>10.1} ns/board (checksum {})", name, nanos / boards.len() as f64, checksum);
+    }
+}
+
+//Every square is occupied with probability density
+pub fn random_board(density: f64) -> u128 {
+    random::with_rng(|rng| {
+        let mut res = 0u128;
+        for square in 0..100 {
+            if rng.gen::<f64>() < density {
+                res |= 1u128 << square;
+            }
+        }
+        res
+    })
+}
+
+//All positions of random playouts from the standard start, each already analyzed
+#[cfg(test)]
+pub fn random_positions(games: usize) -> Vec<GameState> {
+    let mut res = Vec::new();
+    for _ in 0..games {
+        let mut curr_state = GameState::standard();
+        loop {
+            let moves = get_possible_moves(&curr_state, &curr_state.move_color, false);
+            curr_state.analyze(&moves);
+            if curr_state.game_over() {
+                res.push(curr_state);
+                break;
+            }
+            let next_state = make_move(&curr_state, &moves[random::gen_range(0, moves.len())]);
+            res.push(curr_state);
+            curr_state = next_state;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flood_fill_matches_schwarm_board() -> Result<(), String> {
+        for i in 0..100000 {
+            let mut fische = random_board((i % 10) as f64 / 10.0);
+            while fische != 0u128 {
+                let expected = get_schwarm_board(fische);
+                let actual = get_schwarm_board_flood(fische);
+                if expected != actual {
+                    return Err(format!("fische 0x{:x}: expected 0x{:x}, got 0x{:x}", fische, expected, actual));
+                }
+                fische ^= expected;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn schwaerme_are_consistent() -> Result<(), String> {
+        for _ in 0..50 {
+            let mut game_state = GameState::standard();
+            game_state.refresh_schwaerme();
+            loop {
+                let moves = get_possible_moves(&game_state, &game_state.move_color, false);
+                game_state.analyze(&moves);
+                if game_state.game_over() {
+                    break;
+                }
+                let mv = moves[random::gen_range(0, moves.len())];
+                game_state = make_move(&game_state, &mv);
+                let schwaerme = game_state.schwaerme.ok_or("make_move dropped the swarms")?;
+                for (incremental, fische) in schwaerme.iter().zip([game_state.rote_fische, game_state.blaue_fische].iter()) {
+                    let fresh = Schwaerme::new(*fische);
+                    if incremental.boards[..incremental.count] != fresh.boards[..fresh.count] {
+                        return Err(format!("Swarms differ from Schwaerme::new after {} in {}", mv, game_state.to_fen()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
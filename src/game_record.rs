@@ -0,0 +1,284 @@
+use super::game_state::{GameState, GameMove, GameStatus};
+use super::game_logic::{get_possible_moves, make_move};
+use super::online::messages::{ServerMove, Direction};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+//Text format of a game, records in one file are separated by a blank line:
+//  kraken 0x400000000200000
+//  red Client 1
+//  blue Client 2
+//  move 12 34 score=1.25 depth=7 time=1698
+//  move 3 1 UP
+//  result RedWin
+//  termination regular
+//Moves are given either as from/to squares of GameMove or as server x, y and direction.
+//Scores are from the point of view of the player who made the move
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MoveInfo {
+    pub score: Option<f64>,
+    pub depth: Option<usize>,
+    pub time_ms: Option<u64>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct RecordedMove {
+    pub mv: GameMove,
+    pub info: MoveInfo,
+}
+
+pub struct GameRecord {
+    pub kraken: u128,
+    pub red: String,
+    pub blue: String,
+    pub moves: Vec<RecordedMove>,
+    //None while the game is running
+    pub result: Option<GameStatus>,
+    //How the game ended, e.g. "regular" or "SOFT_TIMEOUT"
+    pub termination: Option<String>,
+}
+
+impl GameRecord {
+    pub fn new(kraken: u128, red: &str, blue: &str) -> GameRecord {
+        GameRecord {
+            kraken,
+            red: String::from(red),
+            blue: String::from(blue),
+            moves: Vec::new(),
+            result: None,
+            termination: None,
+        }
+    }
+
+    pub fn push(&mut self, mv: GameMove, info: MoveInfo) {
+        self.moves.push(RecordedMove { mv, info });
+    }
+
+    pub fn finish(&mut self, result: GameStatus, termination: &str) {
+        self.result = Some(result);
+        self.termination = Some(String::from(termination));
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut res = format!("kraken 0x{:x}\nred {}\nblue {}\n", self.kraken, self.red, self.blue);
+        for recorded in &self.moves {
+            res.push_str(&format!("move {} {}", recorded.mv.from, recorded.mv.to));
+            if let Some(score) = recorded.info.score {
+                res.push_str(&format!(" score={}", score));
+            }
+            if let Some(depth) = recorded.info.depth {
+                res.push_str(&format!(" depth={}", depth));
+            }
+            if let Some(time_ms) = recorded.info.time_ms {
+                res.push_str(&format!(" time={}", time_ms));
+            }
+            res.push('\n');
+        }
+        if let Some(result) = self.result {
+            res.push_str(&format!("result {}\n", result));
+        }
+        if let Some(termination) = &self.termination {
+            res.push_str(&format!("termination {}\n", termination));
+        }
+        res
+    }
+
+    //Appends the record to a file of records
+    pub fn append_to(&self, path: &str) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", self.to_text())
+    }
+
+    //Parses a single record. Every move is checked against the legal moves while reading
+    pub fn parse(text: &str) -> Result<GameRecord, String> {
+        let mut record = GameRecord::new(0u128, "", "");
+        let mut game_state: Option<GameState> = None;
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = match line.find(' ') {
+                Some(space) => (&line[..space], line[space + 1..].trim()),
+                None => (line, ""),
+            };
+            let error = |msg: String| format!("Line {}: {}", number + 1, msg);
+            match key {
+                "kraken" => {
+                    record.kraken = u128::from_str_radix(value.trim_start_matches("0x"), 16).map_err(|_| error(format!("Invalid kraken {}", value)))?;
+                }
+                "red" => record.red = String::from(value),
+                "blue" => record.blue = String::from(value),
+                "move" => {
+                    let state = game_state.get_or_insert_with(|| GameState::standard_with_kraken(record.kraken));
+                    let (mv, info) = parse_move(value, state).map_err(error)?;
+                    *state = make_move(state, &mv);
+                    record.push(mv, info);
+                }
+                "result" => record.result = Some(parse_status(value).map_err(error)?),
+                "termination" => record.termination = Some(String::from(value)),
+                _ => return Err(error(format!("Unknown key {}", key))),
+            }
+        }
+        if record.kraken == 0u128 {
+            return Err(String::from("Record without kraken"));
+        }
+        Ok(record)
+    }
+
+    //Records are separated by blank lines, lines() also accepts files with \r\n line endings
+    pub fn parse_all(text: &str) -> Result<Vec<GameRecord>, String> {
+        let mut blocks: Vec<Vec<&str>> = vec![Vec::new()];
+        for line in text.lines() {
+            if !line.trim().is_empty() {
+                blocks.last_mut().unwrap().push(line);
+            } else if !blocks.last().unwrap().is_empty() {
+                blocks.push(Vec::new());
+            }
+        }
+        let mut res = Vec::new();
+        for (index, block) in blocks.iter().filter(|block| !block.is_empty()).enumerate() {
+            res.push(GameRecord::parse(&block.join("\n")).map_err(|e| format!("Record {}: {}", index + 1, e))?);
+        }
+        Ok(res)
+    }
+
+    //Plays the record through make_move and returns all positions, the start position included.
+    //Fails on illegal moves, moves after the end of the game and a regular result that contradicts analyze
+    pub fn replay(&self) -> Result<Vec<GameState>, String> {
+        let mut game_state = GameState::standard_with_kraken(self.kraken);
+        let mut positions = Vec::with_capacity(self.moves.len() + 1);
+        for (ply, recorded) in self.moves.iter().enumerate() {
+            let move_list = get_possible_moves(&game_state, &game_state.move_color, false);
+            game_state.analyze(&move_list);
+            if game_state.game_over() {
+                return Err(format!("Ply {}: the game is already over ({})", ply, game_state.game_status.unwrap()));
+            }
+            if !move_list.contains(&recorded.mv) {
+                return Err(format!("Ply {}: {} is not legal in {}", ply, recorded.mv, game_state.to_fen()));
+            }
+            let next = make_move(&game_state, &recorded.mv);
+            positions.push(game_state);
+            game_state = next;
+        }
+        let move_list = get_possible_moves(&game_state, &game_state.move_color, false);
+        game_state.analyze(&move_list);
+        if let (Some(result), Some("regular")) = (self.result, self.termination.as_deref()) {
+            if game_state.game_status != Some(result) {
+                return Err(format!("Recorded result {} but the final position is {}", result, game_state.game_status.unwrap()));
+            }
+        }
+        positions.push(game_state);
+        Ok(positions)
+    }
+}
+
+//"12 34 score=..." in squares or "3 1 UP score=..." in server coordinates
+fn parse_move(value: &str, game_state: &GameState) -> Result<(GameMove, MoveInfo), String> {
+    let tokens: Vec<&str> = value.split_whitespace().collect();
+    if tokens.len() < 2 {
+        return Err(format!("Invalid move {}", value));
+    }
+    let number = |token: &str| token.parse::<u8>().map_err(|_| format!("Invalid number {}", token));
+    let (mv, rest) = match tokens.get(2).map(|token| Direction::parse(token)) {
+        Some(Ok(direction)) => {
+            let server_move = ServerMove { x: number(tokens[0])?, y: number(tokens[1])?, direction };
+            (server_move.to_game_move(game_state).ok_or_else(|| format!("{:?} is not legal in {}", server_move, game_state.to_fen()))?, &tokens[3..])
+        }
+        _ => {
+            let mv = GameMove { from: number(tokens[0])?, to: number(tokens[1])? };
+            let move_list = get_possible_moves(game_state, &game_state.move_color, false);
+            if !move_list.contains(&mv) {
+                return Err(format!("{} is not legal in {}", mv, game_state.to_fen()));
+            }
+            (mv, &tokens[2..])
+        }
+    };
+    let mut info = MoveInfo::default();
+    for token in rest {
+        let (key, value) = token.split_at(token.find('=').ok_or_else(|| format!("Invalid move annotation {}", token))?);
+        let value = &value[1..];
+        match key {
+            "score" => info.score = Some(value.parse::<f64>().map_err(|_| format!("Invalid score {}", value))?),
+            "depth" => info.depth = Some(value.parse::<usize>().map_err(|_| format!("Invalid depth {}", value))?),
+            "time" => info.time_ms = Some(value.parse::<u64>().map_err(|_| format!("Invalid time {}", value))?),
+            _ => return Err(format!("Unknown move annotation {}", key)),
+        }
+    }
+    Ok((mv, info))
+}
+
+pub fn parse_status(status: &str) -> Result<GameStatus, String> {
+    match status {
+        "Ingame" => Ok(GameStatus::Ingame),
+        "Draw" => Ok(GameStatus::Draw),
+        "RedWin" => Ok(GameStatus::RedWin),
+        "BlueWin" => Ok(GameStatus::BlueWin),
+        _ => Err(format!("Unknown result {}", status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic;
+    use crate::random;
+
+    #[test]
+    fn game_records_replay() -> Result<(), String> {
+        let mut file = String::new();
+        for _ in 0..20 {
+            let mut game_state = GameState::standard();
+            let mut record = GameRecord::new(game_state.kraken, "Red", "Blue");
+            let mut moves = game_logic::get_possible_moves(&game_state, &game_state.move_color, false);
+            game_state.analyze(&moves);
+            while !game_state.game_over() {
+                let mv = moves[random::gen_range(0, moves.len())];
+                game_state = game_logic::make_move(&game_state, &mv);
+                record.push(mv, MoveInfo { score: Some(0.25), depth: Some(3), time_ms: None });
+                moves = game_logic::get_possible_moves(&game_state, &game_state.move_color, false);
+                game_state.analyze(&moves);
+            }
+            record.finish(game_state.game_status.unwrap(), "regular");
+            let text = record.to_text();
+            file.push_str(&format!("{}\n", text));
+            let parsed = GameRecord::parse(&text)?;
+            if parsed.to_text() != text {
+                return Err(format!("Record changed after parsing:\n{}\n{}", text, parsed.to_text()));
+            }
+            let positions = parsed.replay()?;
+            if positions.last().unwrap().hash != game_state.hash {
+                return Err(format!("Replay of\n{}ends in a different position", text));
+            }
+            //The same moves in server notation
+            let mut server_text = format!("kraken 0x{:x}\n", record.kraken);
+            for (position, recorded) in positions.iter().zip(record.moves.iter()) {
+                let mv = ServerMove::from_game_move(&recorded.mv);
+                if mv.to_game_move(position) != Some(recorded.mv) {
+                    return Err(format!("{} does not survive the conversion to server notation", recorded.mv));
+                }
+                server_text.push_str(&format!("move {} {} {}\n", mv.x, mv.y, mv.direction.as_str()));
+            }
+            if GameRecord::parse(&server_text)?.moves.iter().map(|m| m.mv).ne(record.moves.iter().map(|m| m.mv)) {
+                return Err(format!("Server notation gives other moves:\n{}", server_text));
+            }
+            //A wrong result has to be rejected
+            let mut wrong = GameRecord::parse(&text)?;
+            wrong.result = Some(if wrong.result == Some(GameStatus::Draw) { GameStatus::RedWin } else { GameStatus::Draw });
+            if wrong.replay().is_ok() {
+                return Err(format!("Wrong result accepted for\n{}", text));
+            }
+        }
+        //Files as written by append_to, also with Windows line endings
+        for file in [file.clone(), file.replace('\n', "\r\n")].iter() {
+            if GameRecord::parse_all(file)?.len() != 20 {
+                return Err(format!("Expected 20 records in {:?}", file));
+            }
+        }
+        if GameRecord::parse("kraken 0x800800000000\nmove 0 99\n").is_ok() {
+            return Err(String::from("Illegal move accepted"));
+        }
+        Ok(())
+    }
+}
@@ -1,23 +1,31 @@
 use std::fmt::{self, Formatter, Display};
 use super::game_logic;
+use super::constants;
 use super::zobrist;
+use super::nnue;
+use super::random;
+use super::bitboard::{Bitboard, Square};
 
 extern crate rand;
 
 use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use colored::*;
 
 pub const DIRECTIONS: [i8; 8] = [10, 11, 1, -9, -10, -11, -1, 9];
+//After 30 rounds the biggest swarms decide the game
+pub const MAX_PLIES: u8 = 60;
 
 #[allow(dead_code)]
-#[derive(PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum GameColor {
     Red,
     Blue,
 }
 
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum GameStatus {
     Ingame,
     Draw,
@@ -50,9 +58,11 @@ pub struct GameMove {
     pub to: u8,
 }
 
+//Column and row of a printed board, as the logs have always shown moves
 impl Display for GameMove {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "({xone},{yone}) --> ({xtwo},{ytwo})", xone = (9 - self.from % 10), yone = (9 - self.from / 10), xtwo = (9 - self.to % 10), ytwo = (9 - self.to / 10))
+        let (from, to) = (self.from_square(), self.to_square());
+        write!(f, "({},{}) --> ({},{})", 9 - from.x(), 9 - from.y(), 9 - to.x(), 9 - to.y())
     }
 }
 
@@ -60,6 +70,20 @@ impl GameMove {
     pub fn new(from: u8, to: u8) -> GameMove {
         GameMove { from, to }
     }
+
+    pub fn from_squares(from: Square, to: Square) -> GameMove {
+        GameMove::new(from.index() as u8, to.index() as u8)
+    }
+
+    #[inline(always)]
+    pub fn from_square(&self) -> Square {
+        Square::from_index(self.from)
+    }
+
+    #[inline(always)]
+    pub fn to_square(&self) -> Square {
+        Square::from_index(self.to)
+    }
 }
 
 pub struct GameState {
@@ -72,17 +96,21 @@ pub struct GameState {
 
     pub game_status: Option<GameStatus>,
     pub move_color: GameColor,
-    pub hash: i64,
+    pub hash: u64,
+    //Only maintained when a network is active, see nnue::network
+    pub accumulator: Option<nnue::Accumulator>,
+    //Swarms of red and blue, only maintained once refresh_schwaerme was called
+    pub schwaerme: Option<[game_logic::Schwaerme; 2]>,
 }
 
 impl GameState {
     pub fn analyze(&mut self, possible_moves: &Vec<GameMove>) {
         if self.plies_played % 2 == 0 {
             let rote_fische: u8 = self.rote_fische.count_ones() as u8;
-            let roter_schwarm = game_logic::get_schwarm(&self, &GameColor::Red);
             let blaue_fische: u8 = self.blaue_fische.count_ones() as u8;
-            let blauer_schwarm = game_logic::get_schwarm(&self, &GameColor::Blue);
-            if roter_schwarm == rote_fische && blauer_schwarm == blaue_fische {
+            let roter_schwarm = self.is_connected(&GameColor::Red);
+            let blauer_schwarm = self.is_connected(&GameColor::Blue);
+            if roter_schwarm && blauer_schwarm {
                 if rote_fische > blaue_fische {
                     self.game_status = Some(GameStatus::RedWin);
                     return;
@@ -93,32 +121,18 @@ impl GameState {
                     self.game_status = Some(GameStatus::Draw);
                     return;
                 }
-            } else if roter_schwarm == rote_fische {
+            } else if roter_schwarm {
                 self.game_status = Some(GameStatus::RedWin);
                 return;
-            } else if blauer_schwarm == blaue_fische {
+            } else if blauer_schwarm {
                 self.game_status = Some(GameStatus::BlueWin);
                 return;
             }
         }
         if self.rounds_played == 30 {
             //Ermittle größten Schwarm
-            let mut rot_biggest_schwarm = 0;
-            let mut blau_biggest_schwarm = 0;
-            let mut rote_fische = self.rote_fische;
-            while rote_fische != 0u128 {
-                let schwarm = game_logic::get_schwarm_board(rote_fische);
-                let schwarm_count = schwarm.count_ones() as usize;
-                rot_biggest_schwarm = rot_biggest_schwarm.max(schwarm_count);
-                rote_fische &= !schwarm;
-            }
-            let mut blaue_fische: u128 = self.blaue_fische;
-            while blaue_fische != 0u128 {
-                let schwarm = game_logic::get_schwarm_board(blaue_fische);
-                let schwarm_count = schwarm.count_ones() as usize;
-                blau_biggest_schwarm = blau_biggest_schwarm.max(schwarm_count);
-                blaue_fische &= !schwarm;
-            }
+            let rot_biggest_schwarm = self.biggest_schwarm(&GameColor::Red);
+            let blau_biggest_schwarm = self.biggest_schwarm(&GameColor::Blue);
             if rot_biggest_schwarm > blau_biggest_schwarm {
                 self.game_status = Some(GameStatus::RedWin);
             } else if blau_biggest_schwarm > rot_biggest_schwarm {
@@ -143,6 +157,48 @@ impl GameState {
         self.game_status = Some(GameStatus::Ingame);
     }
 
+    pub fn refresh_schwaerme(&mut self) {
+        self.schwaerme = Some([game_logic::Schwaerme::new(self.rote_fische), game_logic::Schwaerme::new(self.blaue_fische)]);
+    }
+
+    pub fn fische(&self, gc: &GameColor) -> u128 {
+        match gc {
+            GameColor::Red => self.rote_fische,
+            GameColor::Blue => self.blaue_fische,
+        }
+    }
+
+    //Whether all fish of gc form a single swarm
+    pub fn is_connected(&self, gc: &GameColor) -> bool {
+        match &self.schwaerme {
+            Some(schwaerme) => schwaerme[color_index(gc)].is_connected(),
+            None => game_logic::get_schwarm(self, gc) as u32 == self.fische(gc).count_ones(),
+        }
+    }
+
+    pub fn biggest_schwarm(&self, gc: &GameColor) -> usize {
+        match &self.schwaerme {
+            Some(schwaerme) => schwaerme[color_index(gc)].biggest(),
+            None => {
+                let mut biggest = 0;
+                let mut fische = self.fische(gc);
+                while fische != 0u128 {
+                    let schwarm = game_logic::get_schwarm_board(fische);
+                    biggest = biggest.max(schwarm.count_ones() as usize);
+                    fische &= !schwarm;
+                }
+                biggest
+            }
+        }
+    }
+
+    pub fn is_one_fish_missing(&self, gc: &GameColor) -> bool {
+        match &self.schwaerme {
+            Some(schwaerme) => schwaerme[color_index(gc)].is_one_fish_missing(),
+            None => game_logic::is_one_fish_missing(self.fische(gc)),
+        }
+    }
+
     pub fn game_over(&self) -> bool {
         match &self.game_status {
             Some(x) => {
@@ -159,18 +215,15 @@ impl GameState {
         }
     }
 
-    pub fn calculate_hash(rote_fische: u128, blaue_fische: u128, kraken: u128, move_color: &GameColor) -> i64 {
-        let mut hash = 0;
-        for y in 0..10 {
-            for x in 0..10 {
-                let shift = 10 * y + x;
-                if ((rote_fische >> shift) & 1) != 0u128 {
-                    hash ^= zobrist::ZOBRIST_KEYS[y][x][0];
-                } else if ((blaue_fische >> shift) & 1) != 0u128 {
-                    hash ^= zobrist::ZOBRIST_KEYS[y][x][1];
-                } else if ((kraken >> shift) & 1) != 0u128 {
-                    hash ^= zobrist::ZOBRIST_KEYS[y][x][2];
-                }
+    pub fn plies_left(&self) -> u8 {
+        MAX_PLIES.saturating_sub(self.plies_played)
+    }
+
+    pub fn calculate_hash(rote_fische: u128, blaue_fische: u128, kraken: u128, move_color: &GameColor) -> u64 {
+        let mut hash = 0u64;
+        for (plane, board) in [rote_fische, blaue_fische, kraken].iter().enumerate() {
+            for square in Bitboard(*board) {
+                hash ^= zobrist::ZOBRIST_KEYS[square.index()][plane];
             }
         }
         if let GameColor::Blue = move_color {
@@ -218,6 +271,44 @@ impl GameState {
         GameState::new(rote_fische, blaue_fische, kraken, plies_played, rounds_played, move_color, hash)
     }
 
+    //from_fen for input from outside the engine: malformed fields and impossible boards are errors
+    pub fn parse_fen(fen: &str) -> Result<GameState, String> {
+        let arr: Vec<&str> = fen.split_whitespace().collect();
+        if arr.len() != 9 {
+            return Err(format!("Expected 9 fields in the fen, got {}", arr.len()));
+        }
+        let board = |i: usize| -> Result<u128, String> {
+            let half = |field: &str| field.parse::<i64>().map(GameState::my_u64).map_err(|_| format!("Invalid fen field {}", field));
+            Ok(((half(arr[i])? as u128) << 64) | (half(arr[i + 1])? as u128))
+        };
+        let (rote_fische, blaue_fische, kraken) = (board(0)?, board(2)?, board(4)?);
+        GameState::check_board(rote_fische, blaue_fische, kraken)?;
+        let move_color = match arr[6] {
+            "r" | "R" => GameColor::Red,
+            "b" | "B" => GameColor::Blue,
+            other => return Err(format!("Invalid color {}", other)),
+        };
+        let plies_played = arr[7].parse::<u8>().ok().filter(|plies| *plies <= MAX_PLIES).ok_or_else(|| format!("Invalid plies {}", arr[7]))?;
+        let rounds_played = arr[8].parse::<u8>().map_err(|_| format!("Invalid rounds {}", arr[8]))?;
+        let hash = GameState::calculate_hash(rote_fische, blaue_fische, kraken, &move_color);
+        Ok(GameState::new(rote_fische, blaue_fische, kraken, plies_played, rounds_played, move_color, hash))
+    }
+
+    //Boards that cannot occur in a game: squares off the board or occupied twice, a color without fish.
+    //A color cannot lose its last fish, a single fish is a complete swarm and ends the game
+    pub fn check_board(rote_fische: u128, blaue_fische: u128, kraken: u128) -> Result<(), String> {
+        if (rote_fische | blaue_fische | kraken) & !constants::BOARD != 0u128 {
+            return Err(String::from("A square is off the board"));
+        }
+        if rote_fische & blaue_fische != 0u128 || (rote_fische | blaue_fische) & kraken != 0u128 {
+            return Err(String::from("A square is occupied twice"));
+        }
+        if rote_fische == 0u128 || blaue_fische == 0u128 {
+            return Err(String::from("Both colors need at least one fish"));
+        }
+        Ok(())
+    }
+
     pub fn to_fen(&self) -> String {
         let mut res_str = String::new();
         let rlinks = GameState::my_i64((self.rote_fische >> 64) as u64);
@@ -231,7 +322,7 @@ impl GameState {
         res_str
     }
 
-    pub fn new(rote_fische: u128, blaue_fische: u128, kraken: u128, plies_played: u8, rounds_played: u8, move_color: GameColor, hash: i64) -> GameState {
+    pub fn new(rote_fische: u128, blaue_fische: u128, kraken: u128, plies_played: u8, rounds_played: u8, move_color: GameColor, hash: u64) -> GameState {
         GameState {
             rote_fische,
             blaue_fische,
@@ -241,9 +332,15 @@ impl GameState {
             game_status: None,
             move_color,
             hash,
+            accumulator: None,
+            schwaerme: None,
         }
     }
 
+    pub fn refresh_accumulator(&mut self) {
+        self.accumulator = nnue::network().map(|net| nnue::Accumulator::refresh(net, self.rote_fische, self.blaue_fische, self.kraken));
+    }
+
     pub fn standard() -> GameState {
         GameState::standard_with_kraken(GameState::generate_random_kraken())
     }
@@ -258,25 +355,49 @@ impl GameState {
             move_color: GameColor::Red,
             game_status: Some(GameStatus::Ingame),
             hash,
+            accumulator: None,
+            schwaerme: None,
         }
     }
 
     pub fn generate_random_kraken() -> u128 {
-        let mut pos1: i8 = GameState::get_random_kraken_position();
-        while pos1 % 10 == 9 || pos1 % 10 == 8 || pos1 % 10 == 1 || pos1 % 10 == 0 {
-            pos1 = GameState::get_random_kraken_position();
+        random::with_rng(GameState::generate_random_kraken_with)
+    }
+
+    pub fn generate_random_kraken_with<R: Rng>(rng: &mut R) -> u128 {
+        let mut pos1: i8 = rng.gen_range(22, 78);
+        while !GameState::is_kraken_square(pos1) {
+            pos1 = rng.gen_range(22, 78);
         }
-        let mut pos2: i8 = GameState::get_random_kraken_position();
-        let mut diff: i8 = pos1 - pos2;
-        while pos2 % 10 == 9 || pos2 % 10 == 8 || pos2 % 10 == 1 || pos2 % 10 == 0 || diff % 10 == 0 || pos1 / 10 == pos2 / 10 || diff % 11 == 0 || diff % 9 == 0 {
-            pos2 = GameState::get_random_kraken_position();
-            diff = pos1 - pos2;
+        let mut pos2: i8 = rng.gen_range(22, 78);
+        while !GameState::is_kraken_pair(pos1, pos2) {
+            pos2 = rng.gen_range(22, 78);
         }
-        return 1u128 << pos1 | 1u128 << pos2;
+        1u128 << pos1 | 1u128 << pos2
     }
 
-    pub fn get_random_kraken_position() -> i8 {
-        rand::thread_rng().gen_range(22, 78)
+    //A kraken may not stand in the two outer columns on each side
+    pub fn is_kraken_square(pos: i8) -> bool {
+        pos % 10 != 9 && pos % 10 != 8 && pos % 10 != 1 && pos % 10 != 0
+    }
+
+    //The second kraken may not share a column, row or diagonal with the first one
+    pub fn is_kraken_pair(pos1: i8, pos2: i8) -> bool {
+        let diff: i8 = pos1 - pos2;
+        GameState::is_kraken_square(pos2) && diff % 10 != 0 && pos1 / 10 != pos2 / 10 && diff % 11 != 0 && diff % 9 != 0
+    }
+
+    //Every kraken layout generate_random_kraken can return
+    pub fn all_kraken_placements() -> Vec<u128> {
+        let mut res = Vec::new();
+        for pos1 in 22..78i8 {
+            for pos2 in pos1 + 1..78i8 {
+                if GameState::is_kraken_square(pos1) && GameState::is_kraken_pair(pos1, pos2) {
+                    res.push(1u128 << pos1 | 1u128 << pos2);
+                }
+            }
+        }
+        res
     }
 
     #[allow(dead_code)]
@@ -285,9 +406,9 @@ impl GameState {
         for y in 0..10 {
             res_str.push_str("|");
             for x in 0..10 {
-                let shift: u128 = 99 - (y * 10 + x);
-                res_str.push_str(&format!("\t{}", shift));
-                if ((num >> shift) & 1u128) != 0u128 {
+                let square = Square::from_grid(y, x);
+                res_str.push_str(&format!("\t{}", square.index()));
+                if Bitboard(num).contains(square) {
                     res_str.push_str("X");
                 } else {
                     res_str.push_str(" ");
@@ -300,6 +421,46 @@ impl GameState {
     }
 }
 
+//Start positions for book building and matches: either every kraken placement once, in order,
+//or an endless reproducible sequence of random placements
+pub enum StartPositions {
+    Enumerated { placements: Vec<u128>, index: usize },
+    Seeded { seed: u64, rng: Box<StdRng> },
+}
+
+impl StartPositions {
+    pub fn enumerated() -> StartPositions {
+        StartPositions::Enumerated { placements: GameState::all_kraken_placements(), index: 0 }
+    }
+
+    pub fn seeded(seed: u64) -> StartPositions {
+        StartPositions::Seeded { seed, rng: Box::new(StdRng::seed_from_u64(seed)) }
+    }
+}
+
+impl Iterator for StartPositions {
+    type Item = GameState;
+
+    fn next(&mut self) -> Option<GameState> {
+        let kraken = match self {
+            StartPositions::Enumerated { placements, index } => {
+                let kraken = *placements.get(*index)?;
+                *index += 1;
+                kraken
+            }
+            StartPositions::Seeded { rng, .. } => GameState::generate_random_kraken_with(&mut **rng),
+        };
+        Some(GameState::standard_with_kraken(kraken))
+    }
+}
+
+pub fn color_index(gc: &GameColor) -> usize {
+    match gc {
+        GameColor::Red => 0,
+        GameColor::Blue => 1,
+    }
+}
+
 impl Display for GameState {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         let mut res_str: String = String::new();
@@ -307,12 +468,12 @@ impl Display for GameState {
             res_str.push_str("|");
             for x in 0..10 {
                 res_str.push_str("\t");
-                let shift: u128 = 99 - (y * 10 + x);
-                if ((&self.rote_fische >> shift) & 1u128) != 0u128 {
+                let square = Square::from_grid(y, x);
+                if Bitboard(self.rote_fische).contains(square) {
                     res_str.push_str(&format!("{}", "🐟".red()));
-                } else if ((&self.blaue_fische >> shift) & 1u128) != 0u128 {
+                } else if Bitboard(self.blaue_fische).contains(square) {
                     res_str.push_str(&format!("{}", "🐟".blue()));
-                } else if ((&self.kraken >> shift) & 1u128) != 0u128 {
+                } else if Bitboard(self.kraken).contains(square) {
                     res_str.push_str(&format!("{}", "🐙".green()));
                 }
                 res_str.push_str("\t|");
@@ -324,4 +485,67 @@ impl Display for GameState {
         res_str.push_str(&format!("Hash: {}", self.hash));
         write!(formatter, "{}", res_str)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::random_positions;
+    use std::collections::HashSet;
+
+    #[test]
+    fn kraken_placements_enumerated() -> Result<(), String> {
+        let placements = GameState::all_kraken_placements();
+        let set: HashSet<u128> = placements.iter().cloned().collect();
+        if set.len() != placements.len() {
+            return Err(format!("{} placements, but only {} distinct", placements.len(), set.len()));
+        }
+        for kraken in &placements {
+            let squares: Vec<i8> = (0..100).filter(|square| kraken & (1u128 << square) != 0).collect();
+            if squares.len() != 2 || !GameState::is_kraken_square(squares[0]) || !GameState::is_kraken_pair(squares[0], squares[1]) {
+                return Err(format!("Placement 0x{:x} is not legal", kraken));
+            }
+        }
+        let mut seen = HashSet::new();
+        for _ in 0..20000 {
+            let kraken = GameState::generate_random_kraken();
+            if !set.contains(&kraken) {
+                return Err(format!("generate_random_kraken returned 0x{:x}, which is not enumerated", kraken));
+            }
+            seen.insert(kraken);
+        }
+        //With 20000 samples every placement should have come up
+        if seen.len() != set.len() {
+            return Err(format!("Only {} of {} placements were generated", seen.len(), set.len()));
+        }
+        let enumerated: Vec<u128> = StartPositions::enumerated().map(|game_state| game_state.kraken).collect();
+        if enumerated != placements {
+            return Err(String::from("Enumerated start positions differ from all_kraken_placements"));
+        }
+        let first: Vec<u128> = StartPositions::seeded(42).take(50).map(|game_state| game_state.kraken).collect();
+        let second: Vec<u128> = StartPositions::seeded(42).take(50).map(|game_state| game_state.kraken).collect();
+        if first != second || first.iter().any(|kraken| !set.contains(kraken)) {
+            return Err(String::from("Seeded start positions are not reproducible"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fen_round_trip() -> Result<(), String> {
+        for game_state in random_positions(20) {
+            let fen = game_state.to_fen();
+            if GameState::parse_fen(&fen)?.to_fen() != fen {
+                return Err(format!("{} changes when parsed", fen));
+            }
+        }
+        let start = GameState::standard().to_fen();
+        let fields: Vec<&str> = start.split(' ').collect();
+        let with = |index: usize, value: &str| fields.iter().enumerate().map(|(i, field)| if i == index { value } else { field }).collect::<Vec<&str>>().join(" ");
+        for invalid in &[String::new(), fields[..8].join(" "), with(0, "x"), with(4, "1099511627776"), with(2, fields[0]), with(4, "-1"), with(6, "g"), with(7, "61")] {
+            if GameState::parse_fen(invalid).is_ok() {
+                return Err(format!("{} is accepted", invalid));
+            }
+        }
+        Ok(())
+    }
+}
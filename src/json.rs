@@ -0,0 +1,525 @@
+use super::game_state::{GameState, GameMove, GameStatus, GameColor, MAX_PLIES};
+use super::bitboard::{Bitboard, Square};
+use super::search::PrincipialVariation;
+use super::board_rating::EvalTrace;
+use super::game_record::parse_status;
+use std::fmt::{self, Display, Formatter};
+
+//Minimal JSON support, written by hand like the XML of the server protocol.
+//Positions and moves use the server's coordinates, so the representation does not depend on the engine's bit layout:
+//  {"red":[[0,1],...],"blue":[[1,0],...],"kraken":[[4,3],[6,6]],"plies_played":0,"rounds_played":0,"move_color":"red"}
+//  {"from":[0,1],"to":[1,2]}
+//  {"score":0.25,"depth":7,"pv":[{"from":[0,1],"to":[1,2]},...]}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    //Keys keep their order, so output is stable
+    Object(Vec<(String, Json)>),
+}
+
+pub trait ToJson {
+    fn to_json(&self) -> Json;
+}
+
+pub trait FromJson: Sized {
+    fn from_json(json: &Json) -> Result<Self, String>;
+}
+
+impl Json {
+    pub fn object(members: Vec<(&str, Json)>) -> Json {
+        Json::Object(members.into_iter().map(|(key, value)| (String::from(key), value)).collect())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    //Like get, but a missing key is an error
+    pub fn field(&self, key: &str) -> Result<&Json, String> {
+        self.get(key).ok_or_else(|| format!("Missing key {}", key))
+    }
+
+    pub fn as_str(&self) -> Result<&str, String> {
+        match self {
+            Json::String(s) => Ok(s),
+            _ => Err(format!("Expected a string, got {}", self)),
+        }
+    }
+
+    pub fn as_f64(&self) -> Result<f64, String> {
+        match self {
+            Json::Number(n) => Ok(*n),
+            _ => Err(format!("Expected a number, got {}", self)),
+        }
+    }
+
+    pub fn as_u64(&self) -> Result<u64, String> {
+        match self {
+            Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 && *n <= u64::MAX as f64 => Ok(*n as u64),
+            _ => Err(format!("Expected a non-negative integer, got {}", self)),
+        }
+    }
+
+    pub fn as_bool(&self) -> Result<bool, String> {
+        match self {
+            Json::Bool(b) => Ok(*b),
+            _ => Err(format!("Expected true or false, got {}", self)),
+        }
+    }
+
+    pub fn as_array(&self) -> Result<&Vec<Json>, String> {
+        match self {
+            Json::Array(values) => Ok(values),
+            _ => Err(format!("Expected an array, got {}", self)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser { bytes: text.as_bytes(), position: 0, depth: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position != parser.bytes.len() {
+            return Err(format!("Unexpected data at byte {}", parser.position));
+        }
+        Ok(value)
+    }
+}
+
+//Compact, on one line, so it can be used for JSON lines
+impl Display for Json {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            //JSON has no NaN or infinity
+            Json::Number(n) if !n.is_finite() => write!(f, "null"),
+            Json::Number(n) => write!(f, "{}", n),
+            Json::String(s) => write_string(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+//Arrays and objects nest at most this deep, so a malicious line cannot overflow the stack of the recursive parser
+const MAX_DEPTH: usize = 64;
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.position < self.bytes.len() && (self.bytes[self.position] as char).is_ascii_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).cloned()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(format!("Expected '{}' at byte {}", byte as char, self.position))
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, String> {
+        if self.bytes[self.position..].starts_with(word.as_bytes()) {
+            self.position += word.len();
+            Ok(value)
+        } else {
+            Err(format!("Invalid literal at byte {}", self.position))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        match self.peek() {
+            Some(b'{') | Some(b'[') => self.nested(),
+            Some(b'"') => Ok(Json::String(self.string()?)),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            Some(other) => Err(format!("Unexpected '{}' at byte {}", other as char, self.position)),
+            None => Err(String::from("Unexpected end of input")),
+        }
+    }
+
+    fn nested(&mut self) -> Result<Json, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(String::from("Nesting too deep"));
+        }
+        self.depth += 1;
+        let value = if self.peek() == Some(b'{') { self.object() } else { self.array() };
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(format!("Expected a key at byte {}", self.position));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            members.push((key, self.value()?));
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(format!("Expected ',' or '}}' at byte {}", self.position)),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                }
+                _ => return Err(format!("Expected ',' or ']' at byte {}", self.position)),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.position;
+        while self.position < self.bytes.len() && matches!(self.bytes[self.position], b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') {
+            self.position += 1;
+        }
+        let text = std::str::from_utf8(&self.bytes[start..self.position]).unwrap();
+        text.parse::<f64>().map(Json::Number).map_err(|_| format!("Invalid number {} at byte {}", text, start))
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self.bytes.get(self.position..self.position + 4).ok_or("Unexpected end of input")?;
+        let value = u32::from_str_radix(std::str::from_utf8(digits).map_err(|e| e.to_string())?, 16).map_err(|_| format!("Invalid escape at byte {}", self.position))?;
+        self.position += 4;
+        Ok(value)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut res = String::new();
+        loop {
+            let start = self.position;
+            while self.position < self.bytes.len() && self.bytes[self.position] != b'"' && self.bytes[self.position] != b'\\' {
+                self.position += 1;
+            }
+            res.push_str(std::str::from_utf8(&self.bytes[start..self.position]).map_err(|e| e.to_string())?);
+            match self.bytes.get(self.position) {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(res);
+                }
+                Some(b'\\') => {
+                    let escape = *self.bytes.get(self.position + 1).ok_or("Unexpected end of input")?;
+                    self.position += 2;
+                    match escape {
+                        b'"' => res.push('"'),
+                        b'\\' => res.push('\\'),
+                        b'/' => res.push('/'),
+                        b'b' => res.push('\u{8}'),
+                        b'f' => res.push('\u{c}'),
+                        b'n' => res.push('\n'),
+                        b'r' => res.push('\r'),
+                        b't' => res.push('\t'),
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            //Characters outside the basic plane come as a surrogate pair
+                            if (0xd800..0xdc00).contains(&code) && self.bytes[self.position..].starts_with(b"\\u") {
+                                self.position += 2;
+                                let low = self.hex4()?;
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff);
+                            }
+                            res.push(std::char::from_u32(code).ok_or_else(|| format!("Invalid character {:x}", code))?);
+                        }
+                        _ => return Err(format!("Invalid escape at byte {}", self.position - 1)),
+                    }
+                }
+                _ => return Err(String::from("Unterminated string")),
+            }
+        }
+    }
+}
+
+impl ToJson for Square {
+    fn to_json(&self) -> Json {
+        let (x, y) = self.server_coordinates();
+        Json::Array(vec![Json::Number(x as f64), Json::Number(y as f64)])
+    }
+}
+
+impl FromJson for Square {
+    fn from_json(json: &Json) -> Result<Square, String> {
+        match &json.as_array()?[..] {
+            [x, y] => {
+                let (x, y) = (x.as_u64()?, y.as_u64()?);
+                Square::from_server(x.min(10) as u8, y.min(10) as u8).ok_or_else(|| format!("Square ({},{}) is not on the board", x, y))
+            }
+            _ => Err(format!("Expected [x, y], got {}", json)),
+        }
+    }
+}
+
+fn bitboard_to_json(board: u128) -> Json {
+    Json::Array(Bitboard(board).iter().map(|square| square.to_json()).collect())
+}
+
+fn bitboard_from_json(json: &Json) -> Result<u128, String> {
+    let mut res = Bitboard::EMPTY;
+    for square in json.as_array()? {
+        res |= Square::from_json(square)?.bitboard();
+    }
+    Ok(res.0)
+}
+
+impl ToJson for GameColor {
+    fn to_json(&self) -> Json {
+        Json::String(String::from(match self {
+            GameColor::Red => "red",
+            GameColor::Blue => "blue",
+        }))
+    }
+}
+
+impl FromJson for GameColor {
+    fn from_json(json: &Json) -> Result<GameColor, String> {
+        match json.as_str()? {
+            "red" => Ok(GameColor::Red),
+            "blue" => Ok(GameColor::Blue),
+            other => Err(format!("Unknown color {}", other)),
+        }
+    }
+}
+
+impl ToJson for GameStatus {
+    fn to_json(&self) -> Json {
+        Json::String(self.to_string())
+    }
+}
+
+impl FromJson for GameStatus {
+    fn from_json(json: &Json) -> Result<GameStatus, String> {
+        parse_status(json.as_str()?)
+    }
+}
+
+impl ToJson for GameMove {
+    fn to_json(&self) -> Json {
+        Json::object(vec![("from", self.from_square().to_json()), ("to", self.to_square().to_json())])
+    }
+}
+
+//Only the squares are checked, whether the move is legal depends on the position
+impl FromJson for GameMove {
+    fn from_json(json: &Json) -> Result<GameMove, String> {
+        Ok(GameMove::from_squares(Square::from_json(json.field("from")?)?, Square::from_json(json.field("to")?)?))
+    }
+}
+
+//The status is only written once analyze has set it and is ignored when reading
+impl ToJson for GameState {
+    fn to_json(&self) -> Json {
+        let mut members = vec![
+            ("red", bitboard_to_json(self.rote_fische)),
+            ("blue", bitboard_to_json(self.blaue_fische)),
+            ("kraken", bitboard_to_json(self.kraken)),
+            ("plies_played", Json::Number(self.plies_played as f64)),
+            ("rounds_played", Json::Number(self.rounds_played as f64)),
+            ("move_color", self.move_color.to_json()),
+        ];
+        if let Some(status) = self.game_status {
+            members.push(("status", status.to_json()));
+        }
+        Json::object(members)
+    }
+}
+
+impl FromJson for GameState {
+    fn from_json(json: &Json) -> Result<GameState, String> {
+        let rote_fische = bitboard_from_json(json.field("red")?)?;
+        let blaue_fische = bitboard_from_json(json.field("blue")?)?;
+        let kraken = bitboard_from_json(json.field("kraken")?)?;
+        GameState::check_board(rote_fische, blaue_fische, kraken)?;
+        let plies_played = json.field("plies_played")?.as_u64()?;
+        if plies_played > MAX_PLIES as u64 {
+            return Err(format!("More than {} plies played", MAX_PLIES));
+        }
+        let plies_played = plies_played as u8;
+        //Games always start with red, so rounds and side to move follow from the plies. If given they have to agree
+        let rounds_played = plies_played / 2;
+        if let Some(rounds) = json.get("rounds_played") {
+            if rounds.as_u64()? != rounds_played as u64 {
+                return Err(format!("{} plies played are {} rounds", plies_played, rounds_played));
+            }
+        }
+        let move_color = if plies_played.is_multiple_of(2) { GameColor::Red } else { GameColor::Blue };
+        if let Some(color) = json.get("move_color") {
+            if GameColor::from_json(color)? != move_color {
+                return Err(format!("{:?} is to move after {} plies", move_color, plies_played));
+            }
+        }
+        let hash = GameState::calculate_hash(rote_fische, blaue_fische, kraken, &move_color);
+        Ok(GameState::new(rote_fische, blaue_fische, kraken, plies_played, rounds_played, move_color, hash))
+    }
+}
+
+impl ToJson for PrincipialVariation {
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("score", Json::Number(self.score)),
+            ("depth", Json::Number(self.depth as f64)),
+            ("pv", Json::Array(self.stack.iter().map(|mv| mv.to_json()).collect())),
+        ])
+    }
+}
+
+impl FromJson for PrincipialVariation {
+    fn from_json(json: &Json) -> Result<PrincipialVariation, String> {
+        let mut pv = PrincipialVariation::new(json.field("depth")?.as_u64()? as usize);
+        pv.score = json.field("score")?.as_f64()?;
+        for mv in json.field("pv")?.as_array()? {
+            pv.stack.push(GameMove::from_json(mv)?);
+        }
+        Ok(pv)
+    }
+}
+
+impl ToJson for EvalTrace {
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("phase", Json::Number(self.phase)),
+            ("fische", Json::Number(self.fische)),
+            ("distance_to_mid", Json::Number(self.distance_to_mid)),
+            ("distance_to_biggest", Json::Number(self.distance_to_biggest)),
+            ("biggest_schwarm", Json::Number(self.biggest_schwarm)),
+            ("absolut_schwarm", Json::Number(self.absolut_schwarm)),
+            ("rand_fische", Json::Number(self.rand_fische)),
+            ("distance_to_enemy", Json::Number(self.distance_to_enemy)),
+            ("total", Json::Number(self.total())),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{self, random_positions};
+
+    #[test]
+    fn json_round_trip() -> Result<(), String> {
+        for game_state in random_positions(20) {
+            let text = game_state.to_json().to_string();
+            let parsed = GameState::from_json(&Json::parse(&text)?)?;
+            if parsed.rote_fische != game_state.rote_fische || parsed.blaue_fische != game_state.blaue_fische || parsed.kraken != game_state.kraken
+                || parsed.plies_played != game_state.plies_played || parsed.rounds_played != game_state.rounds_played
+                || parsed.move_color != game_state.move_color || parsed.hash != game_state.hash {
+                return Err(format!("{} parses to a different position", text));
+            }
+            if GameStatus::from_json(&game_state.game_status.unwrap().to_json())? != game_state.game_status.unwrap() {
+                return Err(format!("Status of {} changes", text));
+            }
+        }
+        let start = GameState::standard();
+        let moves = game_logic::get_possible_moves(&start, &start.move_color, false);
+        let mut pv = PrincipialVariation::new(moves.len());
+        pv.score = -0.375;
+        pv.stack = moves.clone();
+        let parsed = PrincipialVariation::from_json(&Json::parse(&pv.to_json().to_string())?)?;
+        if parsed.score != pv.score || parsed.depth != pv.depth || parsed.stack != moves {
+            return Err(String::from("Variation changes in a round trip"));
+        }
+        //Plies alone are enough, the rest has defaults
+        let minimal = GameState::from_json(&Json::parse(r#"{"red":[[0,1]],"blue":[[1,0]],"kraken":[],"plies_played":3}"#)?)?;
+        if minimal.move_color != GameColor::Blue || minimal.rounds_played != 1 || minimal.rote_fische != Square::from_server(0, 1).unwrap().bitboard().0 {
+            return Err(String::from("Minimal position is read wrongly"));
+        }
+        let text = Json::parse(r#" { "a" : [1, -2.5e1, true, null], "b": "\u00e4\ud83d\ude00\n\"" } "#)?;
+        if text.get("b").map(|b| b.as_str()) != Some(Ok("\u{e4}\u{1f600}\n\"")) || text.get("a") != Some(&Json::Array(vec![Json::Number(1.0), Json::Number(-25.0), Json::Bool(true), Json::Null])) {
+            return Err(format!("Escapes or numbers parse wrongly: {}", text));
+        }
+        if Json::parse(&text.to_string())? != text {
+            return Err(format!("{} changes when written and parsed again", text));
+        }
+        for invalid in &["", "[1,]", "{\"a\" 1}", "\"open", "tru", "[1] 2", r#"{"red":[[10,0]],"blue":[],"kraken":[],"plies_played":0}"#,
+                        r#"{"red":[[0,1]],"blue":[[1,0]],"kraken":[],"plies_played":61}"#, r#"{"red":[[0,1]],"blue":[[1,0]],"kraken":[],"plies_played":3,"rounds_played":2}"#,
+                        r#"{"red":[[0,1]],"blue":[[1,0]],"kraken":[],"plies_played":3,"move_color":"red"}"#,
+                        r#"{"red":[],"blue":[[1,0]],"kraken":[],"plies_played":3}"#, &format!("{}{}", "[".repeat(65), "]".repeat(65))] {
+            if Json::parse(invalid).and_then(|json| if json.get("red").is_some() { GameState::from_json(&json).map(|_| ()) } else { Ok(()) }).is_ok() {
+                return Err(format!("{} is accepted", invalid));
+            }
+        }
+        Json::parse(&format!("{}{}", "[".repeat(64), "]".repeat(64))).map_err(|e| format!("64 nested arrays are rejected: {}", e))?;
+        Ok(())
+    }
+}
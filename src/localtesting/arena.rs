@@ -0,0 +1,333 @@
+use crate::game_logic::{get_possible_moves, make_move};
+use crate::game_state::{GameState, GameStatus, StartPositions, color_index};
+use crate::game_record::{GameRecord, MoveInfo};
+use crate::logging::Logger;
+use crate::random;
+use crate::cli;
+use super::sprt::{SprtConfig, SprtDecision, Pentanomial};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+//Plays matches between two engine processes speaking the local protocol (newgame/requestmove/makemove/end)
+
+pub struct ArenaConfig {
+    //Command lines of both engines, e.g. "./RustSwCl local engine1 --movetime 200"
+    pub engines: Vec<String>,
+    pub games: usize,
+    //Time an engine has to answer a requestmove before it loses
+    pub move_timeout: Duration,
+    pub startup_timeout: Duration,
+    pub record_path: String,
+    //If set, games is only the maximum and the match stops once the test is decided
+    pub sprt: Option<SprtConfig>,
+    //Seed of the random kraken placements (the engine seed, see random.rs), or None to play every placement once
+    pub seed: Option<u64>,
+}
+
+impl ArenaConfig {
+    pub fn from_args(args: &[String]) -> Result<ArenaConfig, String> {
+        let mut config = ArenaConfig {
+            engines: Vec::new(),
+            games: 100,
+            move_timeout: Duration::from_millis(2000),
+            startup_timeout: Duration::from_millis(10000),
+            record_path: String::from("match_games.txt"),
+            sprt: None,
+            seed: Some(random::seed()),
+        };
+        let mut index: usize = 0;
+        while index < args.len() {
+            match &args[index][..] {
+                "-e" | "--engine" => {
+                    config.engines.push(cli::value(args, index)?);
+                    index += 2;
+                }
+                "--games" => {
+                    config.games = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--timeout" => {
+                    config.move_timeout = Duration::from_millis(cli::value(args, index)?);
+                    index += 2;
+                }
+                "--sprt" => {
+                    //--sprt elo0 elo1 [alpha beta]
+                    let number = |i: usize| args.get(index + i).and_then(|arg| arg.parse::<f64>().ok());
+                    let alpha_beta = number(3).zip(number(4));
+                    config.sprt = Some(SprtConfig {
+                        elo0: number(1).ok_or("--sprt needs elo0 and elo1")?,
+                        elo1: number(2).ok_or("--sprt needs elo0 and elo1")?,
+                        alpha: alpha_beta.map(|(alpha, _)| alpha).unwrap_or(0.05),
+                        beta: alpha_beta.map(|(_, beta)| beta).unwrap_or(0.05),
+                    });
+                    index += if alpha_beta.is_some() { 5 } else { 3 };
+                }
+                "--all-placements" => {
+                    config.seed = None;
+                    index += 1;
+                }
+                "--out" => {
+                    config.record_path = cli::value(args, index)?;
+                    index += 2;
+                }
+                _ => { index += 1; }
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug)]
+pub enum EngineFailure {
+    Timeout,
+    Crashed,
+}
+
+pub struct Engine {
+    pub command: String,
+    child: Child,
+    stdin: ChildStdin,
+    //Lines the engine printed, read by a separate thread so we can wait with a timeout
+    lines: Receiver<String>,
+}
+
+impl Engine {
+    pub fn spawn(command: &str, startup_timeout: Duration) -> io::Result<Engine> {
+        let mut parts = command.split_whitespace();
+        let program = parts.next().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Empty engine command"))?;
+        let mut child = Command::new(program).args(parts).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::null()).spawn()?;
+        let stdin = child.stdin.take().unwrap();
+        let stdout = child.stdout.take().unwrap();
+        let (sender, lines) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                match line {
+                    Ok(line) => {
+                        if sender.send(line).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+        let mut engine = Engine { command: String::from(command), child, stdin, lines };
+        let deadline = Instant::now() + startup_timeout;
+        loop {
+            match engine.read_line(deadline) {
+                Ok(line) if line.trim() == "ready" => return Ok(engine),
+                Ok(_) => {}
+                Err(e) => {
+                    engine.kill();
+                    return Err(io::Error::other(format!("{} did not get ready: {:?}", command, e)));
+                }
+            }
+        }
+    }
+
+    pub fn send(&mut self, msg: &str) -> Result<(), EngineFailure> {
+        writeln!(self.stdin, "{}", msg).and_then(|_| self.stdin.flush()).map_err(|_| EngineFailure::Crashed)
+    }
+
+    pub fn read_line(&self, deadline: Instant) -> Result<String, EngineFailure> {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        match self.lines.recv_timeout(timeout) {
+            Ok(line) => Ok(line),
+            Err(RecvTimeoutError::Timeout) => Err(EngineFailure::Timeout),
+            Err(RecvTimeoutError::Disconnected) => Err(EngineFailure::Crashed),
+        }
+    }
+
+    //Asks for a move and returns it as (from, to) squares
+    pub fn request_move(&mut self, timeout: Duration) -> Result<(u8, u8), EngineFailure> {
+        let deadline = Instant::now() + timeout;
+        self.send("requestmove")?;
+        loop {
+            let line = self.read_line(deadline)?;
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() >= 2 {
+                if let (Ok(from), Ok(to)) = (parts[0].parse::<u8>(), parts[1].parse::<u8>()) {
+                    return Ok((from, to));
+                }
+            }
+        }
+    }
+
+    pub fn quit(mut self) {
+        let _ = self.send("end");
+        std::thread::sleep(Duration::from_millis(50));
+        self.kill();
+    }
+
+    fn kill(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Termination {
+    //Decided by the rules, see GameState::analyze
+    Regular,
+    Timeout,
+    IllegalMove,
+    Crash,
+}
+
+impl Termination {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Termination::Regular => "regular",
+            Termination::Timeout => "timeout",
+            Termination::IllegalMove => "illegal move",
+            Termination::Crash => "crash",
+        }
+    }
+}
+
+pub struct PlayedGame {
+    //Index in ArenaConfig::engines of the engine playing red
+    pub red: usize,
+    //Always holds a result
+    pub record: GameRecord,
+    pub termination: Termination,
+}
+
+impl PlayedGame {
+    //1.0 for a win, 0.5 for a draw and 0.0 for a loss of the given engine
+    pub fn score(&self, engine: usize) -> f64 {
+        let red_score = match self.record.result.unwrap() {
+            GameStatus::RedWin => 1.0,
+            GameStatus::BlueWin => 0.0,
+            _ => 0.5,
+        };
+        if engine == self.red { red_score } else { 1.0 - red_score }
+    }
+}
+
+//Plays one game. On a failure the failing engine loses and has to be restarted by the caller
+pub fn play_game(engines: &mut [Engine], red: usize, kraken: u128, move_timeout: Duration, log: &Logger) -> (PlayedGame, Option<usize>) {
+    let mut game_state = GameState::standard_with_kraken(kraken);
+    let mut record = GameRecord::new(kraken, &engines[red].command, &engines[1 - red].command);
+    let newgame = format!("newgame {} {}", GameState::my_i64((kraken >> 64) as u64), GameState::my_i64(kraken as u64));
+    let mut failed: Option<(usize, Termination)> = None;
+    for (index, engine) in engines.iter_mut().enumerate() {
+        if engine.send(&newgame).is_err() && failed.is_none() {
+            failed = Some((index, Termination::Crash));
+        }
+    }
+    while failed.is_none() {
+        let move_list = get_possible_moves(&game_state, &game_state.move_color, false);
+        game_state.analyze(&move_list);
+        if game_state.game_over() {
+            break;
+        }
+        let mover = if color_index(&game_state.move_color) == 0 { red } else { 1 - red };
+        let before = Instant::now();
+        let (from, to) = match engines[mover].request_move(move_timeout) {
+            Ok(mv) => mv,
+            Err(EngineFailure::Timeout) => {
+                failed = Some((mover, Termination::Timeout));
+                break;
+            }
+            Err(EngineFailure::Crashed) => {
+                failed = Some((mover, Termination::Crash));
+                break;
+            }
+        };
+        let mv = match move_list.iter().find(|mv| mv.from == from && mv.to == to) {
+            Some(mv) => *mv,
+            None => {
+                log.log(&format!("{} sent the illegal move {} {} in {}\n", engines[mover].command, from, to, game_state.to_fen()), true);
+                failed = Some((mover, Termination::IllegalMove));
+                break;
+            }
+        };
+        for (index, engine) in engines.iter_mut().enumerate() {
+            if engine.send(&format!("makemove {} {}", mv.from, mv.to)).is_err() {
+                failed = Some((index, Termination::Crash));
+            }
+        }
+        game_state = make_move(&game_state, &mv);
+        record.push(mv, MoveInfo { time_ms: Some(before.elapsed().as_millis() as u64), ..MoveInfo::default() });
+    }
+    let (status, termination, failed_engine) = match failed {
+        Some((engine, termination)) => {
+            let status = if engine == red { GameStatus::BlueWin } else { GameStatus::RedWin };
+            (status, termination, Some(engine))
+        }
+        None => (game_state.game_status.unwrap(), Termination::Regular, None),
+    };
+    record.finish(status, termination.as_str());
+    (PlayedGame { red, record, termination }, failed_engine)
+}
+
+//Plays config.games games. Every kraken placement is played twice with swapped colors
+//and the pairs are fed into the SPRT, if one is configured
+pub fn run_match(config: &ArenaConfig, log: &Logger) -> io::Result<Vec<PlayedGame>> {
+    if config.engines.len() != 2 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Exactly two engines are needed (--engine <command> twice)"));
+    }
+    let mut engines = Vec::with_capacity(2);
+    for command in &config.engines {
+        engines.push(Engine::spawn(command, config.startup_timeout)?);
+    }
+    let mut records = BufWriter::new(File::create(&config.record_path)?);
+    let mut games = Vec::with_capacity(config.games);
+    let mut start_positions = match config.seed {
+        Some(seed) => {
+            log.log(&format!("Kraken placements from seed {}\n", seed), true);
+            StartPositions::seeded(seed)
+        }
+        None => StartPositions::enumerated(),
+    };
+    let mut kraken = 0u128;
+    let mut pentanomial = Pentanomial::default();
+    for index in 0..config.games {
+        if index % 2 == 0 {
+            kraken = match start_positions.next() {
+                Some(start) => start.kraken,
+                None => break,
+            };
+        }
+        let (game, failed_engine) = play_game(&mut engines, index % 2, kraken, config.move_timeout, log);
+        if let Some(engine) = failed_engine {
+            log.log(&format!("{} lost by {}, restarting it\n", config.engines[engine], game.termination.as_str()), true);
+            let restarted = Engine::spawn(&config.engines[engine], config.startup_timeout)?;
+            std::mem::replace(&mut engines[engine], restarted).quit();
+        }
+        writeln!(records, "{}", game.record.to_text())?;
+        records.flush()?;
+        games.push(game);
+        log.log(&format!("Game {}: {}\n", index + 1, summary(&games)), true);
+        if let Some(sprt) = &config.sprt {
+            if index % 2 == 1 {
+                pentanomial.add_pair(games[index - 1].score(0) + games[index].score(0));
+                log.log(&format!("{}\n", pentanomial.report(sprt)), true);
+                match sprt.decide(pentanomial.llr(sprt)) {
+                    SprtDecision::Continue => {}
+                    decision => {
+                        log.log(&format!("SPRT finished after {} games: {:?}\n", games.len(), decision), true);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    for engine in engines {
+        engine.quit();
+    }
+    Ok(games)
+}
+
+//Wins, losses and draws from the point of view of the first engine
+pub fn summary(games: &[PlayedGame]) -> String {
+    let wins = games.iter().filter(|g| g.score(0) == 1.0).count();
+    let losses = games.iter().filter(|g| g.score(0) == 0.0).count();
+    let draws = games.len() - wins - losses;
+    let points: f64 = games.iter().map(|g| g.score(0)).sum();
+    format!("{} - {} - {} ({:.1}/{})", wins, losses, draws, points, games.len())
+}
@@ -1 +1,2 @@
-pub mod protocol;
\ No newline at end of file
+pub mod protocol;pub mod arena;
+pub mod sprt;
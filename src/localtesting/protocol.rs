@@ -1,25 +1,43 @@
-use crate::search::{Search, TimeControl};
+use crate::search::{Search, TimeControl, PrincipialVariation};
 use crate::game_logic::{get_possible_moves, make_move};
-use crate::game_state::GameState;
-use std::env;
+use crate::game_state::{GameState, GameMove, GameColor};
+use crate::game_record::{GameRecord, MoveInfo};
+use crate::board_rating;
 use crate::logging::Logger;
+use crate::cli;
+use std::io::BufRead;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Instant;
 
 //This protocol can be used with the referee given in https://github.com/fabianvdW/SwClPiranha (Spielleiter.java)
-pub fn go() {
-    let mut search = Search::new(TimeControl::MoveTime(1700));
+//or with the built-in match arena (see arena.rs)
+//Commands:
+//  newgame <kraken high i64> <kraken low i64>, requestmove, makemove <from> <to>, fen <fen>, static, end
+//  isready, setoption name <name> value <value>, position fen <fen> [moves <from>-<to> ...],
+//  go [depth <d>] [nodes <n>] [movetime <ms>] [infinite], stop
+//Malformed commands are answered with "info string error ..." and ignored
+pub fn go(path_to_log: &str, move_time: u64) {
+    let mut move_time = move_time;
+    let mut search = Search::new(TimeControl::MoveTime(move_time));
+    let stop = Arc::new(AtomicBool::new(false));
+    let searching = Arc::new(AtomicBool::new(false));
+    search.stop_signal = Some(stop.clone());
     let mut my_state = GameState::standard();
-    let args: Vec<String> = env::args().collect();
-    let path_to_log = args[1].clone();
     let log = Logger::new(&format!("{}.txt", path_to_log), false);
     log.log("Started program!\n", false);
+    let record_path = format!("{}_games.txt", path_to_log);
+    //Only recorded while the game started with newgame
+    let mut record: Option<GameRecord> = None;
+    //Search information of the move we sent last, attached once it comes back as makemove
+    let mut sent: Option<(GameMove, MoveInfo)> = None;
+    let lines = read_commands(stop, searching.clone());
     println!("ready");
-    let stdin = std::io::stdin();
-    let mut line = String::new();
-    loop {
-        line.clear();
-        stdin.read_line(&mut line).ok().unwrap();
+    //Ends when stdin is closed
+    while let Ok(line) = lines.recv() {
         let arg: Vec<&str> = line.split_whitespace().collect();
-        if arg.len() == 0 {
+        if arg.is_empty() {
             continue;
         }
         if arg[0] == "newgame" {
@@ -27,16 +45,123 @@ pub fn go() {
             let l0 = GameState::my_u64(arg[1].parse::<i64>().unwrap());
             let l1 = GameState::my_u64(arg[2].parse::<i64>().unwrap());
             let kraken = ((l0 as u128) << 64) | (l1 as u128);
+            save_record(&mut record, &mut my_state, &record_path, &log);
             my_state = GameState::standard_with_kraken(kraken);
+            record = Some(GameRecord::new(kraken, "?", "?"));
         } else if arg[0] == "requestmove" {
+            let before = Instant::now();
             let pv = search.run(100, &mut my_state);
+            if let Some(record) = &mut record {
+                match my_state.move_color {
+                    GameColor::Red => record.red = String::from(path_to_log),
+                    GameColor::Blue => record.blue = String::from(path_to_log),
+                }
+            }
             let res = pv.stack[0];
             let mate_found = pv.score < -29000.0 || pv.score > 29000.0;
             println!("{} {} {}", res.from, res.to, mate_found);
             log.log(&format!("sent {} {}\n", res.from, res.to), false);
-            log.log(&format!("Searched to depth: {}\n", pv.depth), false);
-            log.log(&format!("Search result:  {}\n", pv.score), false);
-            log.log(&format!("Nodes examined:  {}\n", search.nodes_analyzed), false);
+            log_search(&log, &search, &pv);
+            sent = Some((res, MoveInfo { score: Some(pv.score), depth: Some(pv.depth), time_ms: Some(before.elapsed().as_millis() as u64) }));
+        } else if arg[0] == "go" {
+            let move_list = get_possible_moves(&my_state, &my_state.move_color, false);
+            my_state.analyze(&move_list);
+            if my_state.game_over() {
+                println!("info string error the game is over");
+                continue;
+            }
+            let depth = match go_limits(&arg, &mut search, move_time) {
+                Ok(depth) => depth,
+                Err(e) => {
+                    println!("info string error {}", e);
+                    continue;
+                }
+            };
+            let before = Instant::now();
+            search.print_info = true;
+            searching.store(true, Ordering::Relaxed);
+            let pv = search.run(depth, &mut my_state);
+            searching.store(false, Ordering::Relaxed);
+            search.print_info = false;
+            search.max_nodes = None;
+            search.tc = TimeControl::MoveTime(move_time);
+            let res = pv.stack[0];
+            println!("bestmove {} {}", res.from, res.to);
+            log.log(&format!("bestmove {} {}\n", res.from, res.to), false);
+            log_search(&log, &search, &pv);
+            sent = Some((res, MoveInfo { score: Some(pv.score), depth: Some(pv.depth), time_ms: Some(before.elapsed().as_millis() as u64) }));
+        } else if arg[0] == "isready" {
+            println!("readyok");
+        } else if arg[0] == "setoption" {
+            let name_index = arg.iter().position(|a| *a == "name");
+            let value_index = arg.iter().position(|a| *a == "value");
+            let (name, value) = match (name_index, value_index) {
+                (Some(n), Some(v)) if n + 1 < v && v + 1 < arg.len() => (arg[n + 1..v].join(" "), arg[v + 1]),
+                _ => {
+                    println!("info string usage: setoption name <name> value <value>");
+                    continue;
+                }
+            };
+            let invalid = || format!("invalid value {} for {}", value, name);
+            let set = match &name.to_lowercase()[..] {
+                "hash" => value.parse::<usize>().map(|megabytes| search.resize_cache(megabytes)).map_err(|_| invalid()),
+                "threads" => {
+                    if value != "1" {
+                        println!("info string only one search thread is supported");
+                    }
+                    Ok(())
+                }
+                "movetime" => value.parse::<u64>().map(|time| {
+                    move_time = time;
+                    search.tc = TimeControl::MoveTime(move_time);
+                }).map_err(|_| invalid()),
+                "solver" => value.parse::<bool>().map(|solver| search.use_solver = solver).map_err(|_| invalid()),
+                _ => match value.parse::<f64>() {
+                    Ok(weight) if board_rating::set_parameter(&name, weight) => {
+                        //Cached evaluations used the old weights
+                        search.clear();
+                        Ok(())
+                    }
+                    Ok(_) => {
+                        println!("info string unknown option {}", name);
+                        Ok(())
+                    }
+                    Err(_) => Err(invalid()),
+                },
+            };
+            if let Err(e) = set {
+                println!("info string error {}", e);
+                continue;
+            }
+            log.log(&format!("Set option {} to {}\n", name, value), false);
+        } else if arg[0] == "position" {
+            if arg.get(1) != Some(&"fen") {
+                println!("info string usage: position fen <fen> [moves <from>-<to> ...]");
+                continue;
+            }
+            let moves_index = arg.iter().position(|a| *a == "moves").unwrap_or(arg.len());
+            let position = match GameState::parse_fen(&arg[2..moves_index].join(" ")) {
+                Ok(position) => position,
+                Err(e) => {
+                    println!("info string error {}", e);
+                    continue;
+                }
+            };
+            save_record(&mut record, &mut my_state, &record_path, &log);
+            record = None;
+            my_state = position;
+            for mv in arg.iter().skip(moves_index + 1) {
+                let squares: Vec<u8> = mv.split('-').filter_map(|square| square.parse::<u8>().ok()).collect();
+                let move_list = get_possible_moves(&my_state, &my_state.move_color, false);
+                match move_list.iter().find(|m| squares.len() == 2 && m.from == squares[0] && m.to == squares[1]) {
+                    Some(m) => my_state = make_move(&my_state, m),
+                    None => {
+                        println!("info string illegal move {}", mv);
+                        break;
+                    }
+                }
+            }
+            log.log(&format!("FEN:\n{}\n", my_state.to_fen()), false);
         } else if arg[0] == "makemove" {
             let from = arg[1].parse::<u64>().unwrap();
             let to = arg[2].parse::<u64>().unwrap();
@@ -45,6 +170,13 @@ pub fn go() {
             for mv in move_list {
                 if mv.from == from as u8 && mv.to == to as u8 {
                     my_state = make_move(&my_state, &mv);
+                    let info = match sent.take() {
+                        Some((sent_move, info)) if sent_move == mv => info,
+                        _ => MoveInfo::default(),
+                    };
+                    if let Some(record) = &mut record {
+                        record.push(mv, info);
+                    }
                     found = true;
                     break;
                 }
@@ -54,17 +186,108 @@ pub fn go() {
                 break;
             }
             log.log(&format!("FEN:\n{}\n", my_state.to_fen()), false);
-        } else if arg[0] == "end" {
+        } else if arg[0] == "end" || arg[0] == "quit" {
+            save_record(&mut record, &mut my_state, &record_path, &log);
             break;
         } else if arg[0] == "fen" {
-            let mut fen = String::new();
-            for i in 1..arg.len() {
-                fen.push_str(&format!("{} ", arg[i]));
+            let position = match GameState::parse_fen(&arg[1..].join(" ")) {
+                Ok(position) => position,
+                Err(e) => {
+                    println!("info string error {}", e);
+                    continue;
+                }
+            };
+            save_record(&mut record, &mut my_state, &record_path, &log);
+            record = None;
+            my_state = position;
+            println!("{}", my_state);
+        } else if arg[0] == "static" {
+            println!("{}", board_rating::rating(&my_state, true));
+        }
+    }
+}
+
+//Sets the time control and node limit of search for a go command and returns its depth
+fn go_limits(arg: &[&str], search: &mut Search, move_time: u64) -> Result<u8, String> {
+    let mut depth = 100u8;
+    let mut max_nodes = None;
+    let mut tc = TimeControl::MoveTime(move_time);
+    let mut index = 1;
+    while index < arg.len() {
+        match arg[index] {
+            "depth" => depth = cli::value(arg, index)?,
+            "nodes" => max_nodes = Some(cli::value(arg, index)?),
+            "movetime" => tc = TimeControl::MoveTime(cli::value(arg, index)?),
+            "infinite" => {
+                tc = TimeControl::Infinite;
+                index += 1;
+                continue;
             }
-            my_state = GameState::from_fen(&fen);
-            println!("{}",my_state);
-        }else if arg[0]=="static"{
-            println!("{}",crate::board_rating::rating(&my_state,true));
+            _ => {}
         }
+        index += 2;
+    }
+    //Only depth or nodes given: no time limit
+    if !arg.contains(&"movetime") && (arg.contains(&"depth") || arg.contains(&"nodes")) {
+        tc = TimeControl::Infinite;
+    }
+    search.tc = tc;
+    search.max_nodes = max_nodes;
+    //The search needs at least one iteration for a move
+    Ok(depth.max(1))
+}
+
+//Reads stdin on its own thread. "stop" and, during a search, "isready" are answered right away,
+//everything else is queued for the main loop
+fn read_commands(stop: Arc<AtomicBool>, searching: Arc<AtomicBool>) -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let stdin = std::io::stdin();
+        for line in stdin.lock().lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+            match line.split_whitespace().next() {
+                Some("stop") => {
+                    stop.store(true, Ordering::Relaxed);
+                    continue;
+                }
+                Some("isready") if searching.load(Ordering::Relaxed) => {
+                    println!("readyok");
+                    continue;
+                }
+                //A stop sent before this search must not end it
+                Some("go") | Some("requestmove") => stop.store(false, Ordering::Relaxed),
+                _ => {}
+            }
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+fn log_search(log: &Logger, search: &Search, pv: &PrincipialVariation) {
+    log.log(&format!("Searched to depth: {}\n", pv.depth), false);
+    log.log(&format!("Search result:  {}\n", pv.score), false);
+    log.log(&format!("Nodes examined:  {}\n", search.nodes_analyzed), false);
+}
+
+//Appends the record of the last game, if it has moves, to the record file
+fn save_record(record: &mut Option<GameRecord>, game_state: &mut GameState, path: &str, log: &Logger) {
+    let record = match record {
+        Some(record) if !record.moves.is_empty() => record,
+        _ => return,
+    };
+    let move_list = get_possible_moves(game_state, &game_state.move_color, false);
+    game_state.analyze(&move_list);
+    if game_state.game_over() {
+        record.finish(game_state.game_status.unwrap(), "regular");
+    }
+    if let Err(e) = record.append_to(path) {
+        log.log(&format!("Could not write game record: {}\n", e), false);
     }
-}
\ No newline at end of file
+    record.moves.clear();
+}
@@ -0,0 +1,142 @@
+//Sequential probability ratio test on game pairs, as used by fishtest.
+//Both games of a pair are played on the same kraken layout with swapped colors, so the pair score
+//(0, 0.5, 1, 1.5 or 2 points for the first engine) removes most of the noise of the start position.
+
+pub struct SprtConfig {
+    //Elo difference under the null hypothesis and under the alternative
+    pub elo0: f64,
+    pub elo1: f64,
+    //Probability of accepting H1 although H0 holds, and the other way round
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SprtDecision {
+    Continue,
+    AcceptH0,
+    AcceptH1,
+}
+
+impl SprtConfig {
+    //Lower and upper LLR bound
+    pub fn bounds(&self) -> (f64, f64) {
+        ((self.beta / (1.0 - self.alpha)).ln(), ((1.0 - self.beta) / self.alpha).ln())
+    }
+
+    pub fn decide(&self, llr: f64) -> SprtDecision {
+        let (lower, upper) = self.bounds();
+        if llr >= upper {
+            SprtDecision::AcceptH1
+        } else if llr <= lower {
+            SprtDecision::AcceptH0
+        } else {
+            SprtDecision::Continue
+        }
+    }
+}
+
+//Number of pairs with 0, 0.5, 1, 1.5 and 2 points for the first engine
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Pentanomial {
+    pub counts: [usize; 5],
+}
+
+impl Pentanomial {
+    pub fn add_pair(&mut self, points: f64) {
+        self.counts[(points * 2.0).round() as usize] += 1;
+    }
+
+    pub fn pairs(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    //Mean and variance of the score per game (0 to 1) of a pair. Empty classes get a tiny weight,
+    //otherwise a run of identical results would have no variance at all
+    fn mean_and_variance(&self) -> (f64, f64) {
+        let counts: Vec<f64> = self.counts.iter().map(|&c| if c == 0 { 1e-3 } else { c as f64 }).collect();
+        let total: f64 = counts.iter().sum();
+        let mean: f64 = counts.iter().enumerate().map(|(i, c)| c * i as f64 / 4.0).sum::<f64>() / total;
+        let variance: f64 = counts.iter().enumerate().map(|(i, c)| c * (i as f64 / 4.0 - mean).powi(2)).sum::<f64>() / total;
+        (mean, variance)
+    }
+
+    //Generalized SPRT log likelihood ratio of elo1 against elo0
+    pub fn llr(&self, config: &SprtConfig) -> f64 {
+        if self.pairs() == 0 {
+            return 0.0;
+        }
+        let (mean, variance) = self.mean_and_variance();
+        let s0 = elo_to_score(config.elo0);
+        let s1 = elo_to_score(config.elo1);
+        self.pairs() as f64 * (s1 - s0) * (2.0 * mean - s0 - s1) / (2.0 * variance)
+    }
+
+    //Elo estimate and the half width of its 95% confidence interval
+    pub fn elo(&self) -> (f64, f64) {
+        if self.pairs() == 0 {
+            return (0.0, 0.0);
+        }
+        let (mean, variance) = self.mean_and_variance();
+        let error = 1.96 * (variance / self.pairs() as f64).sqrt();
+        let elo = score_to_elo(mean);
+        let upper = score_to_elo((mean + error).min(1.0 - 1e-6));
+        let lower = score_to_elo((mean - error).max(1e-6));
+        (elo, (upper - lower) / 2.0)
+    }
+
+    pub fn report(&self, config: &SprtConfig) -> String {
+        let (lower, upper) = config.bounds();
+        let (elo, error) = self.elo();
+        format!("LLR {:.2} ({:.2}, {:.2}) [{}, {}] | Elo {:.1} +- {:.1} | Pentanomial {:?}", self.llr(config), lower, upper, config.elo0, config.elo1, elo, error, self.counts)
+    }
+}
+
+pub fn elo_to_score(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+pub fn score_to_elo(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sprt_statistics() -> Result<(), String> {
+        let config = SprtConfig { elo0: 0.0, elo1: 10.0, alpha: 0.05, beta: 0.05 };
+        let (lower, upper) = config.bounds();
+        if (lower + 2.944).abs() > 1e-3 || (upper - 2.944).abs() > 1e-3 {
+            return Err(format!("Bounds ({}, {}) for alpha = beta = 0.05", lower, upper));
+        }
+        for elo in [-300.0, -25.0, 0.0, 10.0, 400.0].iter() {
+            if (score_to_elo(elo_to_score(*elo)) - elo).abs() > 1e-9 {
+                return Err(format!("Elo {} does not survive the conversion to a score and back", elo));
+            }
+        }
+        let even = Pentanomial { counts: [10, 20, 40, 20, 10] };
+        let (elo, error) = even.elo();
+        if elo.abs() > 1e-9 || error <= 0.0 || even.llr(&config) >= 0.0 {
+            return Err(format!("Balanced pairs: {}", even.report(&config)));
+        }
+        let mut strong = Pentanomial::default();
+        for _ in 0..200 {
+            strong.add_pair(1.5);
+            strong.add_pair(1.0);
+        }
+        if strong.elo().0 < 80.0 || config.decide(strong.llr(&config)) != SprtDecision::AcceptH1 {
+            return Err(format!("Winning pairs: {}", strong.report(&config)));
+        }
+        let mut weak = Pentanomial::default();
+        for _ in 0..200 {
+            weak.add_pair(0.5);
+            weak.add_pair(1.0);
+        }
+        if config.decide(weak.llr(&config)) != SprtDecision::AcceptH0 {
+            return Err(format!("Losing pairs: {}", weak.report(&config)));
+        }
+        Ok(())
+    }
+}
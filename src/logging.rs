@@ -5,6 +5,8 @@ use std::io::Write;
 
 pub struct Logger {
     pub file: Mutex<File>,
+    //If false, messages are never echoed to stdout
+    pub echo: bool,
 }
 
 impl Logger {
@@ -22,16 +24,23 @@ impl Logger {
             .unwrap();
         Logger {
             file: Mutex::new(file),
+            echo: true,
         }
     }
 
+    pub fn quiet(path: &str) -> Self {
+        let mut logger = Logger::new(path, false);
+        logger.echo = false;
+        logger
+    }
+
     pub fn log(&self, msg: &str, also_stdout: bool) {
         self.file
             .lock()
             .unwrap()
             .write(msg.as_bytes())
             .expect("Something went wrong when trying to write to this file");
-        if also_stdout {
+        if also_stdout && self.echo {
             print!("{}", msg);
         }
     }
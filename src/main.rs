@@ -1,5 +1,4 @@
 pub mod game_state;
-pub mod generate_u128_nums;
 pub mod string_to_game_state_converter;
 pub mod game_logic;
 pub mod constants;
@@ -9,17 +8,126 @@ pub mod search;
 pub mod localtesting;
 pub mod online;
 pub mod logging;
+pub mod nnue;
+pub mod symmetry;
+pub mod game_record;
+pub mod book;
+pub mod endgame;
+pub mod random;
+pub mod bitboard;
+pub mod json;
+pub mod analysis;
+pub mod cli;
 
 use self::game_state::{GameState, GameMove};
 
 extern crate rand;
 extern crate colored;
 
-use rand::Rng;
+use std::env;
 
 fn main() {
-    //localtesting::protocol::go();
-    online::protocol::go();
+    let args: Vec<String> = env::args().collect();
+    if let Some(seed) = cli::option::<u64>(&args, "--seed").unwrap_or_else(|e| cli::exit_with_usage(&e, "--seed <n>")) {
+        random::set_seed(seed);
+    }
+    //stderr, stdout belongs to the protocols
+    eprintln!("Seed {}", random::seed());
+    if let Some(path) = cli::option::<String>(&args, "--nnue").unwrap_or_else(|e| cli::exit_with_usage(&e, "--nnue <network file>")) {
+        let net = nnue::Network::load(&path).unwrap_or_else(|e| cli::exit_with_error(&format!("Could not load network {}: {}", path, e)));
+        nnue::set_network(net);
+    }
+    if let Some(path) = cli::option::<String>(&args, "--book").unwrap_or_else(|e| cli::exit_with_usage(&e, "--book <book file>")) {
+        let book = book::Book::load(&path).unwrap_or_else(|e| cli::exit_with_error(&format!("Could not load book {}: {}", path, e)));
+        book::set_book(book);
+    }
+    if args.len() > 1 && args[1] == "buildbook" {
+        //buildbook <file> [--plies n] [--depth d] [--margin m] [--moves k] [--placements n]
+        let usage = "buildbook <file> [--plies n] [--depth d] [--margin m] [--moves k] [--placements n]";
+        let path = args.get(2).unwrap_or_else(|| cli::exit_with_usage("Missing book file", usage));
+        let config = book::BookConfig::from_args(&args).unwrap_or_else(|e| cli::exit_with_usage(&e, usage));
+        let book = book::build(&config);
+        book.save(path).expect("Could not save book");
+        println!("Wrote {} entries to {}", book.entries.len(), path);
+        return;
+    }
+    if args.len() > 1 && args[1] == "benchnnue" {
+        //benchnnue [network file] [games]
+        let net = match args.get(2) {
+            Some(path) if path != "random" => nnue::Network::load(path).unwrap_or_else(|e| cli::exit_with_error(&format!("Could not load network {}: {}", path, e))),
+            _ => nnue::Network::random(),
+        };
+        let games = match args.get(3) {
+            Some(games) => games.parse::<usize>().unwrap_or_else(|_| cli::exit_with_usage(&format!("Invalid number of games {}", games), "benchnnue [network file|random] [games]")),
+            None => 1000,
+        };
+        nnue::benchmark(&net, games);
+        return;
+    }
+    if args.len() > 1 && args[1] == "benchschwarm" {
+        //Boards with 16 fish, as in the starting position
+        let boards: Vec<u128> = (0..100000).map(|_| game_logic::random_board(0.16)).collect();
+        game_logic::benchmark_schwarm_board(&boards);
+        return;
+    }
+    if args.len() > 1 && args[1] == "server" {
+        //server [-p port] [--games n] [--timeout ms] [--reservations red blue]
+        let log = logging::Logger::new("server_log.txt", false);
+        let config = online::server::ServerConfig::from_args(&args).unwrap_or_else(|e| cli::exit_with_usage(&e, "server [-p port] [--games n] [--timeout ms] [--reservations red blue]"));
+        if let Err(e) = online::server::serve(&config, &log) {
+            log.log(&format!("Server failed: {}\n", e), true);
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.len() > 1 && args[1] == "local" {
+        //local [log file] [--movetime ms], speaks the protocol of localtesting::protocol on stdin/stdout
+        let path_to_log = args.get(2).filter(|arg| !arg.starts_with("--")).map(|arg| &arg[..]).unwrap_or("local");
+        let move_time = cli::option::<u64>(&args, "--movetime").unwrap_or_else(|e| cli::exit_with_usage(&e, "local [log file] [--movetime ms]")).unwrap_or(1700);
+        localtesting::protocol::go(path_to_log, move_time);
+        return;
+    }
+    if args.len() > 1 && args[1] == "analysis" {
+        //analysis [--port p] [--movetime ms], JSON lines on stdin/stdout or on 127.0.0.1:p, see analysis.rs
+        let config = analysis::AnalysisConfig::from_args(&args).unwrap_or_else(|e| cli::exit_with_usage(&e, "analysis [--port p] [--movetime ms]"));
+        if let Err(e) = analysis::go(&config) {
+            eprintln!("Analysis server failed: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.len() > 1 && args[1] == "match" {
+        //match --engine <command> --engine <command> [--games n] [--timeout ms] [--out file] [--sprt elo0 elo1 [alpha beta]] [--all-placements]
+        let log = logging::Logger::new("match_log.txt", false);
+        let config = localtesting::arena::ArenaConfig::from_args(&args)
+            .unwrap_or_else(|e| cli::exit_with_usage(&e, "match --engine <command> --engine <command> [--games n] [--timeout ms] [--out file] [--sprt elo0 elo1 [alpha beta]] [--all-placements]"));
+        match localtesting::arena::run_match(&config, &log) {
+            Ok(games) => log.log(&format!("Final score: {}\n", localtesting::arena::summary(&games)), true),
+            Err(e) => {
+                log.log(&format!("Match failed: {}\n", e), true);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    if args.len() > 1 && args[1] == "replay" {
+        //replay <record file>, validates every game of the file
+        let path = cli::value::<String>(&args, 1).unwrap_or_else(|_| cli::exit_with_usage("Missing record file", "replay <record file>"));
+        let text = std::fs::read_to_string(&path).unwrap_or_else(|e| cli::exit_with_error(&format!("Could not read record file {}: {}", path, e)));
+        let records = game_record::GameRecord::parse_all(&text).unwrap_or_else(|e| cli::exit_with_error(&format!("Invalid record file: {}", e)));
+        let mut valid = true;
+        for (index, record) in records.iter().enumerate() {
+            match record.replay() {
+                Ok(positions) => println!("Game {}: {} vs {}, {} moves, result {}", index + 1, record.red, record.blue, record.moves.len(), positions.last().unwrap().game_status.unwrap()),
+                Err(e) => {
+                    println!("Game {}: {}", index + 1, e);
+                    valid = false;
+                }
+            }
+        }
+        std::process::exit(if valid { 0 } else { 1 });
+    }
+    std::process::exit(online::protocol::go());
 }
 
 fn play_rand_games() {
@@ -30,7 +138,7 @@ fn play_rand_games() {
         moves = game_logic::get_possible_moves(&curr_state, &curr_state.move_color, false);
         curr_state.analyze(&moves);
         while !curr_state.game_over() {
-            curr_state = game_logic::make_move(&curr_state, &moves[rand::thread_rng().gen_range(0, moves.len())]);
+            curr_state = game_logic::make_move(&curr_state, &moves[random::gen_range(0, moves.len())]);
             let moves = game_logic::get_possible_moves(&curr_state, &curr_state.move_color, false);
             curr_state.analyze(&moves);
         }
@@ -0,0 +1,295 @@
+use super::game_state::{GameState, GameMove, GameColor};
+use super::game_logic;
+use super::board_rating;
+use super::random;
+use super::bitboard::Bitboard;
+use std::fs::File;
+use std::io::{self, Read, Write, BufReader, BufWriter};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+extern crate rand;
+
+use rand::Rng;
+
+//Input planes: red fish, blue fish and kraken, 100 squares each
+pub const INPUTS: usize = 300;
+pub const HIDDEN: usize = 32;
+//Clipped ReLU ceiling of the hidden layer
+pub const ACTIVATION_MAX: i32 = 127;
+//Divisor converting the integer output into the units of board_rating::rating
+pub const OUTPUT_SCALE: f64 = 127.0 * 64.0;
+
+pub const MAGIC: &[u8; 4] = b"SWNN";
+pub const VERSION: u32 = 1;
+
+static NETWORK: OnceLock<Network> = OnceLock::new();
+
+//Binary layout (little endian):
+//magic "SWNN", u32 version, u32 inputs, u32 hidden,
+//i16 feature_weights[INPUTS][HIDDEN], i16 feature_bias[HIDDEN], i16 output_weights[HIDDEN], i32 output_bias
+pub struct Network {
+    pub feature_weights: Vec<[i16; HIDDEN]>,
+    pub feature_bias: [i16; HIDDEN],
+    pub output_weights: [i16; HIDDEN],
+    pub output_bias: i32,
+}
+
+#[derive(Copy, Clone)]
+pub struct Accumulator {
+    pub values: [i32; HIDDEN],
+}
+
+impl Accumulator {
+    pub fn refresh(net: &Network, rote_fische: u128, blaue_fische: u128, kraken: u128) -> Accumulator {
+        let mut values = [0i32; HIDDEN];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = net.feature_bias[i] as i32;
+        }
+        let mut acc = Accumulator { values };
+        for (plane, board) in [rote_fische, blaue_fische, kraken].iter().cloned().enumerate() {
+            for square in Bitboard(board) {
+                acc.add(net, feature_index(plane, square.index()));
+            }
+        }
+        acc
+    }
+
+    #[inline(always)]
+    pub fn add(&mut self, net: &Network, feature: usize) {
+        for (v, w) in self.values.iter_mut().zip(net.feature_weights[feature].iter()) {
+            *v += *w as i32;
+        }
+    }
+
+    #[inline(always)]
+    pub fn sub(&mut self, net: &Network, feature: usize) {
+        for (v, w) in self.values.iter_mut().zip(net.feature_weights[feature].iter()) {
+            *v -= *w as i32;
+        }
+    }
+
+    //Applies the fish move gm of color gc. captured tells whether an enemy fish stood on gm.to
+    #[inline(always)]
+    pub fn apply_move(&mut self, net: &Network, gm: &GameMove, gc: &GameColor, captured: bool) {
+        let (mine, theirs) = match gc {
+            GameColor::Red => (0, 1),
+            GameColor::Blue => (1, 0),
+        };
+        self.sub(net, feature_index(mine, gm.from as usize));
+        self.add(net, feature_index(mine, gm.to as usize));
+        if captured {
+            self.sub(net, feature_index(theirs, gm.to as usize));
+        }
+    }
+}
+
+#[inline(always)]
+pub fn feature_index(plane: usize, square: usize) -> usize {
+    plane * 100 + square
+}
+
+impl Network {
+    pub fn zeroed() -> Network {
+        Network {
+            feature_weights: vec![[0i16; HIDDEN]; INPUTS],
+            feature_bias: [0i16; HIDDEN],
+            output_weights: [0i16; HIDDEN],
+            output_bias: 0,
+        }
+    }
+
+    pub fn random() -> Network {
+        random::with_rng(|rng| {
+            let mut net = Network::zeroed();
+            for weights in net.feature_weights.iter_mut() {
+                for w in weights.iter_mut() {
+                    *w = rng.gen_range(-16, 17);
+                }
+            }
+            for i in 0..HIDDEN {
+                net.feature_bias[i] = rng.gen_range(0, 32);
+                net.output_weights[i] = rng.gen_range(-64, 65);
+            }
+            net
+        })
+    }
+
+    pub fn load(path: &str) -> io::Result<Network> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Not a network file"));
+        }
+        let version = read_u32(&mut reader)?;
+        let inputs = read_u32(&mut reader)? as usize;
+        let hidden = read_u32(&mut reader)? as usize;
+        if version != VERSION || inputs != INPUTS || hidden != HIDDEN {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unsupported network: version {}, {}x{}", version, inputs, hidden)));
+        }
+        let mut net = Network::zeroed();
+        for weights in net.feature_weights.iter_mut() {
+            for w in weights.iter_mut() {
+                *w = read_i16(&mut reader)?;
+            }
+        }
+        for i in 0..HIDDEN {
+            net.feature_bias[i] = read_i16(&mut reader)?;
+        }
+        for i in 0..HIDDEN {
+            net.output_weights[i] = read_i16(&mut reader)?;
+        }
+        net.output_bias = read_u32(&mut reader)? as i32;
+        Ok(net)
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(INPUTS as u32).to_le_bytes())?;
+        writer.write_all(&(HIDDEN as u32).to_le_bytes())?;
+        for weights in &self.feature_weights {
+            for w in weights.iter() {
+                writer.write_all(&w.to_le_bytes())?;
+            }
+        }
+        for b in self.feature_bias.iter() {
+            writer.write_all(&b.to_le_bytes())?;
+        }
+        for w in self.output_weights.iter() {
+            writer.write_all(&w.to_le_bytes())?;
+        }
+        writer.write_all(&self.output_bias.to_le_bytes())?;
+        writer.flush()
+    }
+
+    //Score from red's point of view, in the units of board_rating::rating
+    #[inline(always)]
+    pub fn evaluate_accumulator(&self, acc: &Accumulator) -> f64 {
+        let mut output = self.output_bias;
+        for (v, w) in acc.values.iter().zip(self.output_weights.iter()) {
+            output += v.clamp(&0, &ACTIVATION_MAX) * *w as i32;
+        }
+        output as f64 / OUTPUT_SCALE
+    }
+
+    pub fn evaluate(&self, game_state: &GameState) -> f64 {
+        match &game_state.accumulator {
+            Some(acc) => self.evaluate_accumulator(acc),
+            None => self.evaluate_accumulator(&Accumulator::refresh(self, game_state.rote_fische, game_state.blaue_fische, game_state.kraken)),
+        }
+    }
+}
+
+fn read_u32(reader: &mut dyn Read) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_i16(reader: &mut dyn Read) -> io::Result<i16> {
+    let mut buf = [0u8; 2];
+    reader.read_exact(&mut buf)?;
+    Ok(i16::from_le_bytes(buf))
+}
+
+//Activates the network for the whole process. Returns false if a network was already set
+pub fn set_network(net: Network) -> bool {
+    NETWORK.set(net).is_ok()
+}
+
+#[inline(always)]
+pub fn network() -> Option<&'static Network> {
+    NETWORK.get()
+}
+
+//Compares the speed of the handcrafted rating with full and incremental network evaluation on random playouts
+pub fn benchmark(net: &Network, games: usize) {
+    //Every game is stored as its sequence of positions together with the move played from them
+    let mut records: Vec<Vec<(GameState, GameMove)>> = Vec::with_capacity(games);
+    for _ in 0..games {
+        let mut record = Vec::new();
+        let mut curr_state = GameState::standard();
+        let mut move_list = game_logic::get_possible_moves(&curr_state, &curr_state.move_color, false);
+        curr_state.analyze(&move_list);
+        while !curr_state.game_over() {
+            let mv = move_list[random::gen_range(0, move_list.len())];
+            let next_state = game_logic::make_move(&curr_state, &mv);
+            record.push((curr_state, mv));
+            curr_state = next_state;
+            move_list = game_logic::get_possible_moves(&curr_state, &curr_state.move_color, false);
+            curr_state.analyze(&move_list);
+        }
+        records.push(record);
+    }
+    let positions: usize = records.iter().map(|r| r.len()).sum();
+    println!("Benchmarking on {} positions", positions);
+
+    let mut checksum = 0.0;
+    let time = Instant::now();
+    for (state, _) in records.iter().flatten() {
+        checksum += board_rating::rating(state, false);
+    }
+    report("rating", time, positions, checksum);
+
+    let mut checksum = 0.0;
+    let time = Instant::now();
+    for (state, _) in records.iter().flatten() {
+        checksum += net.evaluate_accumulator(&Accumulator::refresh(net, state.rote_fische, state.blaue_fische, state.kraken));
+    }
+    report("nnue (refresh)", time, positions, checksum);
+
+    let mut checksum = 0.0;
+    let time = Instant::now();
+    for record in &records {
+        let first = &record[0].0;
+        let mut acc = Accumulator::refresh(net, first.rote_fische, first.blaue_fische, first.kraken);
+        for (state, mv) in record {
+            let gegner_fische = match state.move_color {
+                GameColor::Red => state.blaue_fische,
+                GameColor::Blue => state.rote_fische,
+            };
+            acc.apply_move(net, mv, &state.move_color, gegner_fische & (1u128 << mv.to) != 0u128);
+            checksum += net.evaluate_accumulator(&acc);
+        }
+    }
+    report("nnue (incremental)", time, positions, checksum);
+}
+
+fn report(name: &str, time: Instant, positions: usize, checksum: f64) {
+    let nanos = time.elapsed().as_nanos() as f64;
+    println!("{:<20} {:>10.1} ns/eval (checksum {:.3})", name, nanos / positions as f64, checksum);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Uses its own random network, so the network of the engine (--nnue) stays untouched
+    #[test]
+    fn accumulators_are_consistent() -> Result<(), String> {
+        let net = Network::random();
+        for _ in 0..20 {
+            let mut game_state = GameState::standard();
+            let mut acc = Accumulator::refresh(&net, game_state.rote_fische, game_state.blaue_fische, game_state.kraken);
+            loop {
+                let moves = game_logic::get_possible_moves(&game_state, &game_state.move_color, false);
+                game_state.analyze(&moves);
+                if game_state.game_over() {
+                    break;
+                }
+                let mv = moves[random::gen_range(0, moves.len())];
+                let enemy = if let GameColor::Red = game_state.move_color { game_state.blaue_fische } else { game_state.rote_fische };
+                acc.apply_move(&net, &mv, &game_state.move_color, Bitboard(enemy).contains(mv.to_square()));
+                game_state = game_logic::make_move(&game_state, &mv);
+                let refreshed = Accumulator::refresh(&net, game_state.rote_fische, game_state.blaue_fische, game_state.kraken);
+                if acc.values[..] != refreshed.values[..] {
+                    return Err(format!("Accumulator differs from a refresh after {} in {}", mv, game_state.to_fen()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
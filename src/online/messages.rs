@@ -0,0 +1,669 @@
+use super::xml::{Element, XmlReader};
+use crate::game_state::{GameState, GameColor, GameMove};
+use crate::game_logic::get_possible_moves;
+use crate::bitboard::Square;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FieldState {
+    Empty,
+    Obstructed,
+    Red,
+    Blue,
+}
+
+impl FieldState {
+    pub fn parse(desc: &str) -> Result<FieldState, String> {
+        match desc {
+            "EMPTY" => Ok(FieldState::Empty),
+            "OBSTRUCTED" => Ok(FieldState::Obstructed),
+            "RED" => Ok(FieldState::Red),
+            "BLUE" => Ok(FieldState::Blue),
+            _ => Err(format!("Invalid field state {}", desc)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FieldState::Empty => "EMPTY",
+            FieldState::Obstructed => "OBSTRUCTED",
+            FieldState::Red => "RED",
+            FieldState::Blue => "BLUE",
+        }
+    }
+}
+
+//Directions in server coordinates, where UP increases y and RIGHT increases x
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+pub const ALL_DIRECTIONS: [Direction; 8] = [Direction::Up, Direction::UpRight, Direction::Right, Direction::DownRight, Direction::Down, Direction::DownLeft, Direction::Left, Direction::UpLeft];
+
+impl Direction {
+    pub fn parse(direction: &str) -> Result<Direction, String> {
+        ALL_DIRECTIONS.iter().find(|d| d.as_str() == direction).cloned().ok_or_else(|| format!("Invalid direction {}", direction))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::Up => "UP",
+            Direction::UpRight => "UP_RIGHT",
+            Direction::Right => "RIGHT",
+            Direction::DownRight => "DOWN_RIGHT",
+            Direction::Down => "DOWN",
+            Direction::DownLeft => "DOWN_LEFT",
+            Direction::Left => "LEFT",
+            Direction::UpLeft => "UP_LEFT",
+        }
+    }
+
+    //Step in server coordinates (x, y)
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::Up => (0, 1),
+            Direction::UpRight => (1, 1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, -1),
+            Direction::Down => (0, -1),
+            Direction::DownLeft => (-1, -1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, 1),
+        }
+    }
+
+    pub fn of_move(gm: &GameMove) -> Option<Direction> {
+        let (x, y) = gm.from_square().server_coordinates();
+        let (new_x, new_y) = gm.to_square().server_coordinates();
+        let delta = ((new_x as i8 - x as i8).signum(), (new_y as i8 - y as i8).signum());
+        ALL_DIRECTIONS.iter().find(|d| d.delta() == delta).cloned()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerMove {
+    pub x: u8,
+    pub y: u8,
+    pub direction: Direction,
+}
+
+impl ServerMove {
+    pub fn from_element(element: &Element) -> Result<ServerMove, String> {
+        let (x, y) = required_square(element)?.server_coordinates();
+        Ok(ServerMove { x, y, direction: Direction::parse(required(element, "direction")?)? })
+    }
+
+    pub fn from_game_move(gm: &GameMove) -> ServerMove {
+        let (x, y) = gm.from_square().server_coordinates();
+        ServerMove { x, y, direction: Direction::of_move(gm).expect("Move without direction") }
+    }
+
+    //The distance of a move depends on the position, so the move is looked up among the legal moves of game_state
+    pub fn to_game_move(&self, game_state: &GameState) -> Option<GameMove> {
+        let from = Square::from_server(self.x, self.y)?;
+        get_possible_moves(game_state, &game_state.move_color, false).into_iter().find(|mv| mv.from_square() == from && Direction::of_move(mv) == Some(self.direction))
+    }
+
+    pub fn add_to(&self, element: Element) -> Element {
+        element
+            .with_attribute("x", &self.x.to_string())
+            .with_attribute("y", &self.y.to_string())
+            .with_attribute("direction", self.direction.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateMessage {
+    pub turn: u8,
+    pub start_player: GameColor,
+    pub current_player: GameColor,
+    pub red_name: Option<String>,
+    pub blue_name: Option<String>,
+    pub rote_fische: u128,
+    pub blaue_fische: u128,
+    pub kraken: u128,
+    pub last_move: Option<ServerMove>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ScoreCause {
+    Regular,
+    Left,
+    RuleViolation,
+    SoftTimeout,
+    HardTimeout,
+    Unknown,
+}
+
+impl ScoreCause {
+    pub fn parse(cause: &str) -> ScoreCause {
+        match cause {
+            "REGULAR" => ScoreCause::Regular,
+            "LEFT" => ScoreCause::Left,
+            "RULE_VIOLATION" => ScoreCause::RuleViolation,
+            "SOFT_TIMEOUT" => ScoreCause::SoftTimeout,
+            "HARD_TIMEOUT" => ScoreCause::HardTimeout,
+            _ => ScoreCause::Unknown,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScoreCause::Regular => "REGULAR",
+            ScoreCause::Left => "LEFT",
+            ScoreCause::RuleViolation => "RULE_VIOLATION",
+            ScoreCause::SoftTimeout => "SOFT_TIMEOUT",
+            ScoreCause::HardTimeout => "HARD_TIMEOUT",
+            ScoreCause::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Score {
+    pub cause: ScoreCause,
+    pub reason: String,
+    pub parts: Vec<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Winner {
+    pub display_name: String,
+    pub color: GameColor,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameResult {
+    //Names of the score parts, e.g. "Siegpunkte"
+    pub fragments: Vec<String>,
+    //First the score of red, then the one of blue
+    pub scores: Vec<Score>,
+    //None on a draw
+    pub winner: Option<Winner>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GameOutcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl GameOutcome {
+    //Exit codes of the client, so tournament scripts can tally results
+    pub fn exit_code(self) -> i32 {
+        match self {
+            GameOutcome::Win => 0,
+            GameOutcome::Loss => 1,
+            GameOutcome::Draw => 2,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RoomData {
+    Welcome { color: GameColor },
+    Memento(StateMessage),
+    MoveRequest,
+    Result(GameResult),
+    Error { message: String },
+    Other { class: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ServerMessage {
+    Joined { room_id: String },
+    Left { room_id: String },
+    Room { room_id: String, data: RoomData },
+    Error { message: String },
+    Other { name: String },
+}
+
+//Everything the client sends. The opening <protocol> tag stays open until CloseProtocol
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClientMessage {
+    Protocol,
+    JoinPrepared { reservation: String },
+    Join { game_type: String },
+    Move { room_id: String, mv: ServerMove },
+    CloseProtocol,
+}
+
+pub fn parse_color(color: &str) -> Result<GameColor, String> {
+    match &color.to_uppercase()[..] {
+        "RED" => Ok(GameColor::Red),
+        "BLUE" => Ok(GameColor::Blue),
+        _ => Err(format!("Invalid color {}", color)),
+    }
+}
+
+fn required<'a>(element: &'a Element, attribute: &str) -> Result<&'a str, String> {
+    element.attribute(attribute).ok_or_else(|| format!("<{}> is missing attribute {}", element.name, attribute))
+}
+
+fn required_number(element: &Element, attribute: &str) -> Result<u8, String> {
+    let value = required(element, attribute)?;
+    value.parse::<u8>().map_err(|_| format!("<{}>: {}=\"{}\" is not a number", element.name, attribute, value))
+}
+
+//An x or y in server coordinates, on the board
+fn required_square(element: &Element) -> Result<Square, String> {
+    let (x, y) = (required_number(element, "x")?, required_number(element, "y")?);
+    Square::from_server(x, y).ok_or_else(|| format!("<{}>: x=\"{}\" y=\"{}\" is not on the board", element.name, x, y))
+}
+
+pub fn color_name(color: GameColor) -> &'static str {
+    match color {
+        GameColor::Red => "RED",
+        GameColor::Blue => "BLUE",
+    }
+}
+
+fn collect_fields(element: &Element, rote_fische: &mut u128, blaue_fische: &mut u128, kraken: &mut u128) -> Result<(), String> {
+    for child in &element.children {
+        if child.name == "field" {
+            let square = required_square(child)?.bitboard().0;
+            match FieldState::parse(required(child, "state")?)? {
+                FieldState::Red => *rote_fische |= square,
+                FieldState::Blue => *blaue_fische |= square,
+                FieldState::Obstructed => *kraken |= square,
+                FieldState::Empty => {}
+            }
+        } else {
+            collect_fields(child, rote_fische, blaue_fische, kraken)?;
+        }
+    }
+    Ok(())
+}
+
+impl StateMessage {
+    pub fn from_element(state: &Element) -> Result<StateMessage, String> {
+        let board = state.child("board").ok_or("<state> without <board>")?;
+        let mut rote_fische = 0u128;
+        let mut blaue_fische = 0u128;
+        let mut kraken = 0u128;
+        collect_fields(board, &mut rote_fische, &mut blaue_fische, &mut kraken)?;
+        let last_move = match state.child("lastMove") {
+            Some(mv) => Some(ServerMove::from_element(mv)?),
+            None => None,
+        };
+        Ok(StateMessage {
+            turn: required_number(state, "turn")?,
+            start_player: parse_color(state.attribute("startPlayerColor").unwrap_or("RED"))?,
+            current_player: parse_color(required(state, "currentPlayerColor")?)?,
+            red_name: state.child("red").and_then(|p| p.attribute("displayName")).map(String::from),
+            blue_name: state.child("blue").and_then(|p| p.attribute("displayName")).map(String::from),
+            rote_fische,
+            blaue_fische,
+            kraken,
+            last_move,
+        })
+    }
+
+    pub fn from_game_state(game_state: &GameState, last_move: Option<ServerMove>, red_name: &str, blue_name: &str) -> StateMessage {
+        StateMessage {
+            turn: game_state.plies_played,
+            start_player: GameColor::Red,
+            current_player: game_state.move_color,
+            red_name: Some(String::from(red_name)),
+            blue_name: Some(String::from(blue_name)),
+            rote_fische: game_state.rote_fische,
+            blaue_fische: game_state.blaue_fische,
+            kraken: game_state.kraken,
+            last_move,
+        }
+    }
+
+    pub fn to_game_state(&self) -> GameState {
+        let hash = GameState::calculate_hash(self.rote_fische, self.blaue_fische, self.kraken, &self.current_player);
+        GameState::new(self.rote_fische, self.blaue_fische, self.kraken, self.turn, self.rounds_played(), self.current_player, hash)
+    }
+
+    //make_move counts a round as played once blue has moved, so this depends on who started
+    pub fn rounds_played(&self) -> u8 {
+        match self.start_player {
+            GameColor::Red => self.turn / 2,
+            GameColor::Blue => self.turn.div_ceil(2),
+        }
+    }
+
+    pub fn to_element(&self) -> Element {
+        let mut state = Element::new("state")
+            .with_attribute("class", "sc.plugin2019.GameState")
+            .with_attribute("startPlayerColor", color_name(self.start_player))
+            .with_attribute("currentPlayerColor", color_name(self.current_player))
+            .with_attribute("turn", &self.turn.to_string());
+        if let Some(name) = &self.red_name {
+            state = state.with_child(Element::new("red").with_attribute("displayName", name).with_attribute("color", "RED"));
+        }
+        if let Some(name) = &self.blue_name {
+            state = state.with_child(Element::new("blue").with_attribute("displayName", name).with_attribute("color", "BLUE"));
+        }
+        let mut board = Element::new("board");
+        for x in 0..10 {
+            let mut fields = Element::new("fields");
+            for y in 0..10 {
+                let square = Square::from_coordinates(9 - x, y).bitboard().0;
+                let field_state = if self.rote_fische & square != 0u128 {
+                    FieldState::Red
+                } else if self.blaue_fische & square != 0u128 {
+                    FieldState::Blue
+                } else if self.kraken & square != 0u128 {
+                    FieldState::Obstructed
+                } else {
+                    FieldState::Empty
+                };
+                fields = fields.with_child(Element::new("field")
+                    .with_attribute("x", &x.to_string())
+                    .with_attribute("y", &y.to_string())
+                    .with_attribute("state", field_state.as_str()));
+            }
+            board = board.with_child(fields);
+        }
+        state = state.with_child(board);
+        if let Some(mv) = &self.last_move {
+            state = state.with_child(mv.add_to(Element::new("lastMove")));
+        }
+        state
+    }
+}
+
+impl GameResult {
+    pub fn from_element(data: &Element) -> Result<GameResult, String> {
+        let fragments = match data.child("definition") {
+            Some(definition) => definition.children_named("fragment").map(|f| String::from(f.attribute("name").unwrap_or(""))).collect(),
+            None => Vec::new(),
+        };
+        let mut scores = Vec::new();
+        for score in data.children_named("score") {
+            let mut parts = Vec::new();
+            for part in score.children_named("part") {
+                parts.push(part.text.parse::<f64>().map_err(|_| format!("Invalid score part {}", part.text))?);
+            }
+            scores.push(Score {
+                cause: ScoreCause::parse(score.attribute("cause").unwrap_or("")),
+                reason: String::from(score.attribute("reason").unwrap_or("")),
+                parts,
+            });
+        }
+        let winner = match data.child("winner") {
+            Some(winner) => Some(Winner {
+                display_name: String::from(winner.attribute("displayName").unwrap_or("")),
+                color: parse_color(required(winner, "color")?)?,
+            }),
+            None => None,
+        };
+        Ok(GameResult { fragments, scores, winner })
+    }
+
+    pub fn outcome(&self, my_color: GameColor) -> GameOutcome {
+        match &self.winner {
+            Some(winner) if winner.color == my_color => GameOutcome::Win,
+            Some(_) => GameOutcome::Loss,
+            None => GameOutcome::Draw,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let mut res = match &self.winner {
+            Some(winner) => format!("Winner: {} ({})\n", winner.display_name, color_name(winner.color)),
+            None => String::from("Draw\n"),
+        };
+        for (score, color) in self.scores.iter().zip([GameColor::Red, GameColor::Blue].iter()) {
+            let parts: Vec<String> = self.fragments.iter().zip(score.parts.iter()).map(|(name, part)| format!("{}={}", name, part)).collect();
+            res.push_str(&format!("{}: {} [{}]", color_name(*color), score.cause.as_str(), parts.join(", ")));
+            if !score.reason.is_empty() {
+                res.push_str(&format!(" ({})", score.reason));
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    pub fn to_element(&self) -> Element {
+        let mut data = Element::new("data").with_attribute("class", "result");
+        let mut definition = Element::new("definition");
+        for fragment in &self.fragments {
+            definition = definition.with_child(Element::new("fragment").with_attribute("name", fragment));
+        }
+        data = data.with_child(definition);
+        for score in &self.scores {
+            let mut element = Element::new("score").with_attribute("cause", score.cause.as_str()).with_attribute("reason", &score.reason);
+            for part in &score.parts {
+                element = element.with_child(Element::new("part").with_text(&part.to_string()));
+            }
+            data = data.with_child(element);
+        }
+        if let Some(winner) = &self.winner {
+            data = data.with_child(Element::new("winner").with_attribute("displayName", &winner.display_name).with_attribute("color", color_name(winner.color)));
+        }
+        data
+    }
+}
+
+impl RoomData {
+    pub fn from_element(data: &Element) -> Result<RoomData, String> {
+        let class = required(data, "class")?;
+        match class {
+            "welcomeMessage" | "welcome" => Ok(RoomData::Welcome { color: parse_color(required(data, "color")?)? }),
+            "memento" => {
+                let state = data.child("state").ok_or("Memento without <state>")?;
+                Ok(RoomData::Memento(StateMessage::from_element(state)?))
+            }
+            "sc.framework.plugins.protocol.MoveRequest" => Ok(RoomData::MoveRequest),
+            "result" => Ok(RoomData::Result(GameResult::from_element(data)?)),
+            "error" => Ok(RoomData::Error { message: String::from(data.attribute("message").unwrap_or("")) }),
+            _ => Ok(RoomData::Other { class: String::from(class) }),
+        }
+    }
+
+    pub fn to_element(&self) -> Element {
+        let data = Element::new("data");
+        match self {
+            RoomData::Welcome { color } => data.with_attribute("class", "welcomeMessage").with_attribute("color", &color_name(*color).to_lowercase()),
+            RoomData::Memento(state) => data.with_attribute("class", "memento").with_child(state.to_element()),
+            RoomData::MoveRequest => data.with_attribute("class", "sc.framework.plugins.protocol.MoveRequest"),
+            RoomData::Result(result) => result.to_element(),
+            RoomData::Error { message } => data.with_attribute("class", "error").with_attribute("message", message),
+            RoomData::Other { class } => data.with_attribute("class", class),
+        }
+    }
+}
+
+impl ServerMessage {
+    pub fn from_element(element: &Element) -> Result<ServerMessage, String> {
+        match &element.name[..] {
+            "joined" => Ok(ServerMessage::Joined { room_id: String::from(required(element, "roomId")?) }),
+            "left" => Ok(ServerMessage::Left { room_id: String::from(required(element, "roomId")?) }),
+            "room" => {
+                let data = element.child("data").ok_or("<room> without <data>")?;
+                Ok(ServerMessage::Room { room_id: String::from(required(element, "roomId")?), data: RoomData::from_element(data)? })
+            }
+            "error" | "sc.protocol.responses.ProtocolErrorMessage" => Ok(ServerMessage::Error { message: String::from(element.attribute("message").unwrap_or("")) }),
+            _ => Ok(ServerMessage::Other { name: element.name.clone() }),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        match self {
+            ServerMessage::Joined { room_id } => Element::new("joined").with_attribute("roomId", room_id),
+            ServerMessage::Left { room_id } => Element::new("left").with_attribute("roomId", room_id),
+            ServerMessage::Room { room_id, data } => Element::new("room").with_attribute("roomId", room_id).with_child(data.to_element()),
+            ServerMessage::Error { message } => Element::new("error").with_attribute("message", message),
+            ServerMessage::Other { name } => Element::new(name),
+        }.to_xml()
+    }
+}
+
+impl ClientMessage {
+    //Protocol and CloseProtocol are consumed by the XmlReader and never parsed
+    pub fn from_element(element: &Element) -> Result<ClientMessage, String> {
+        match &element.name[..] {
+            "joinPrepared" => Ok(ClientMessage::JoinPrepared { reservation: String::from(required(element, "reservationCode")?) }),
+            "join" => Ok(ClientMessage::Join { game_type: String::from(required(element, "gameType")?) }),
+            "room" => {
+                let data = element.child("data").ok_or("<room> without <data>")?;
+                if required(data, "class")? != "move" {
+                    return Err(format!("Unexpected data class {}", required(data, "class")?));
+                }
+                let mv = ServerMove::from_element(data)?;
+                Ok(ClientMessage::Move { room_id: String::from(required(element, "roomId")?), mv })
+            }
+            _ => Err(format!("Unknown client message <{}>", element.name)),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        match self {
+            ClientMessage::Protocol => String::from("<protocol>"),
+            ClientMessage::JoinPrepared { reservation } => Element::new("joinPrepared").with_attribute("reservationCode", reservation).to_xml(),
+            ClientMessage::Join { game_type } => Element::new("join").with_attribute("gameType", game_type).to_xml(),
+            ClientMessage::Move { room_id, mv } => Element::new("room").with_attribute("roomId", room_id).with_child(mv.add_to(Element::new("data").with_attribute("class", "move"))).to_xml(),
+            ClientMessage::CloseProtocol => String::from("</protocol>"),
+        }
+    }
+}
+
+//Parses a complete transcript of messages wrapped in <protocol>
+pub fn parse_transcript<T>(transcript: &str, parse: fn(&Element) -> Result<T, String>) -> Result<Vec<T>, String> {
+    let mut reader = XmlReader::new();
+    reader.feed(transcript);
+    let mut res = Vec::new();
+    while let Some(element) = reader.next_element().map_err(|e| format!("{:?}", e))? {
+        res.push(parse(&element)?);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+pub const SERVER_TRANSCRIPT: &str = include_str!("../../transcripts/server_game.xml");
+#[cfg(test)]
+pub const CLIENT_TRANSCRIPT: &str = include_str!("../../transcripts/client_game.xml");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{self, random_positions};
+
+    //Feeds the transcript in chunks of chunk_size bytes, as if it arrived over several TCP reads
+    fn parse_server_transcript(transcript: &str, chunk_size: usize) -> Result<Vec<ServerMessage>, String> {
+        let mut reader = XmlReader::new();
+        let mut res = Vec::new();
+        for chunk in transcript.as_bytes().chunks(chunk_size) {
+            reader.feed_bytes(chunk).map_err(|e| format!("{:?}", e))?;
+            while let Some(element) = reader.next_element().map_err(|e| format!("{:?}", e))? {
+                res.push(ServerMessage::from_element(&element)?);
+            }
+        }
+        if !reader.root_closed {
+            return Err(String::from("<protocol> was not closed"));
+        }
+        Ok(res)
+    }
+
+    #[test]
+    fn server_transcript_parses() -> Result<(), String> {
+        let messages = parse_server_transcript(SERVER_TRANSCRIPT, SERVER_TRANSCRIPT.len())?;
+        for chunk_size in (1..64).chain(vec![2048]) {
+            if parse_server_transcript(SERVER_TRANSCRIPT, chunk_size)? != messages {
+                return Err(format!("Reading in chunks of {} bytes changes the messages", chunk_size));
+            }
+        }
+        let datas: Vec<&RoomData> = messages.iter().filter_map(|m| if let ServerMessage::Room { data, .. } = m { Some(data) } else { None }).collect();
+        if messages.len() != 9 || datas.len() != 7 {
+            return Err(format!("Expected 9 messages and 7 room messages, got {:?}", messages));
+        }
+        if datas[0] != &(RoomData::Welcome { color: GameColor::Red }) || datas[2] != &RoomData::MoveRequest {
+            return Err(format!("Unexpected welcome or move request {:?} {:?}", datas[0], datas[2]));
+        }
+        let kraken = (Square::from_server(4, 3).unwrap().bitboard() | Square::from_server(6, 6).unwrap().bitboard()).0;
+        let standard = GameState::standard_with_kraken(kraken);
+        match datas[1] {
+            RoomData::Memento(state) => {
+                let game_state = state.to_game_state();
+                if game_state.rote_fische != standard.rote_fische || game_state.blaue_fische != standard.blaue_fische || game_state.kraken != kraken || game_state.hash != standard.hash {
+                    return Err(format!("First state is not the standard position: {}", game_state.to_fen()));
+                }
+                if state.blue_name.as_deref() != Some("Opponent & Co") {
+                    return Err(format!("Unexpected name {:?}", state.blue_name));
+                }
+            }
+            other => return Err(format!("Expected memento, got {:?}", other)),
+        }
+        match datas[3] {
+            RoomData::Memento(state) => {
+                let mv = state.last_move.as_ref().ok_or("Missing last move")?;
+                if state.turn != 1 || state.current_player != GameColor::Blue || (mv.x, mv.y, mv.direction) != (0, 1, Direction::Right) {
+                    return Err(format!("Unexpected second state {:?}", state));
+                }
+            }
+            other => return Err(format!("Expected memento, got {:?}", other)),
+        }
+        match datas[6] {
+            RoomData::Result(result) => {
+                let winner = result.winner.as_ref().ok_or("Missing winner")?;
+                if result.fragments.len() != 2 || result.scores.len() != 2 || result.scores[1].cause != ScoreCause::SoftTimeout || result.scores[0].parts != vec![2.0, 11.0] || winner.color != GameColor::Red {
+                    return Err(format!("Unexpected result {:?}", result));
+                }
+                if result.outcome(GameColor::Red) != GameOutcome::Win || result.outcome(GameColor::Blue) != GameOutcome::Loss {
+                    return Err(format!("Wrong outcome for {:?}", result));
+                }
+            }
+            other => return Err(format!("Expected result, got {:?}", other)),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn messages_round_trip() -> Result<(), String> {
+        for message in parse_transcript(SERVER_TRANSCRIPT, ServerMessage::from_element)? {
+            let xml = format!("<protocol>{}</protocol>", message.to_xml());
+            let parsed = parse_transcript(&xml, ServerMessage::from_element)?;
+            if parsed != vec![message.clone()] {
+                return Err(format!("{:?} was serialized as\n{}\nand parsed as {:?}", message, xml, parsed));
+            }
+        }
+        let client_messages = parse_transcript(CLIENT_TRANSCRIPT, ClientMessage::from_element)?;
+        if client_messages.len() != 3 {
+            return Err(format!("Expected 3 client messages, got {:?}", client_messages));
+        }
+        let mut xml = ClientMessage::Protocol.to_xml();
+        for message in &client_messages {
+            xml.push_str(&message.to_xml());
+        }
+        xml.push_str(&ClientMessage::CloseProtocol.to_xml());
+        let parsed = parse_transcript(&xml, ClientMessage::from_element)?;
+        if parsed != client_messages {
+            return Err(format!("{:?} was serialized as\n{}\nand parsed as {:?}", client_messages, xml, parsed));
+        }
+        //Coordinates off the board are rejected in moves of both sides, as in fields
+        let off_board = r#"<protocol><room roomId="r"><data class="move" x="10" y="0" direction="UP"/></room></protocol>"#;
+        if parse_transcript(off_board, ClientMessage::from_element).is_ok() {
+            return Err(String::from("Move from x=10 is accepted"));
+        }
+        if parse_transcript(&SERVER_TRANSCRIPT.replacen(r#"<lastMove x="0" y="1""#, r#"<lastMove x="0" y="30""#, 1), ServerMessage::from_element).is_ok() {
+            return Err(String::from("Last move from y=30 is accepted"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn directions_match_moves() -> Result<(), String> {
+        for game_state in random_positions(50) {
+            for mv in game_logic::get_possible_moves(&game_state, &game_state.move_color, false) {
+                let server_move = ServerMove::from_game_move(&mv);
+                if server_move.to_game_move(&game_state) != Some(mv) {
+                    return Err(format!("{} became {:?} in {}", mv, server_move, game_state.to_fen()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
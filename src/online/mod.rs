@@ -1 +1,5 @@
-pub mod protocol;
\ No newline at end of file
+pub mod protocol;
+pub mod xml;
+pub mod messages;
+pub mod tracking;
+pub mod server;
@@ -1,193 +1,343 @@
 use crate::logging::Logger;
+use crate::cli;
 use std::env;
-use std::io::prelude::*;
+use std::io::{self, prelude::*};
 use std::net::TcpStream;
 use std::time::{Duration, Instant};
 use crate::game_state::{GameState, GameColor};
 use crate::search::{Search, TimeControl};
+use super::xml::XmlReader;
+use super::messages::{ServerMessage, ClientMessage, RoomData, ServerMove, ScoreCause, color_name};
+use super::tracking::GameTracker;
+use crate::game_record::MoveInfo;
+use crate::game_state::GameStatus;
 
 
-pub enum FieldState {
-    Empty,
-    Obstructed,
-    Red,
-    Blue,
+//Exit code if the game ended without a result we could attribute to us
+pub const EXIT_NO_RESULT: i32 = 3;
+
+pub const GAME_TYPE: &str = "swc_2019_piranhas";
+
+pub enum JoinMode {
+    //Join the room reserved by the server administration with this code
+    Prepared(String),
+    //Join any open game of this game type
+    Any(String),
+}
+
+pub struct ClientConfig {
+    pub host: String,
+    pub port: u16,
+    pub join: JoinMode,
+    //Further connection attempts if the connection fails or breaks before a room was joined
+    pub reconnect_attempts: u32,
+    pub reconnect_delay: Duration,
+    //Search time per move in milliseconds
+    pub move_time: u64,
+    //File the game record is appended to
+    pub record_path: String,
 }
 
-pub fn go() {
-    let mut search = Search::new(TimeControl::MoveTime(1700));
+impl ClientConfig {
+    pub fn from_args(args: &[String]) -> Result<ClientConfig, String> {
+        let mut config = ClientConfig {
+            host: String::from("localhost"),
+            port: 13050,
+            //Like the original client: without a reservation code joinPrepared is sent with an empty one
+            join: JoinMode::Prepared(String::new()),
+            reconnect_attempts: 0,
+            reconnect_delay: Duration::from_millis(1000),
+            move_time: 1700,
+            record_path: String::from("client_games.txt"),
+        };
+        let mut index: usize = 0;
+        while index < args.len() {
+            match &args[index][..] {
+                "-r" | "--reservation" => {
+                    config.join = JoinMode::Prepared(cli::value(args, index)?);
+                    index += 2;
+                }
+                "-h" | "--host" => {
+                    config.host = cli::value(args, index)?;
+                    index += 2;
+                }
+                "-p" | "--port" => {
+                    config.port = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--game-type" => {
+                    config.join = JoinMode::Any(cli::value(args, index)?);
+                    index += 2;
+                }
+                "--reconnect" => {
+                    config.reconnect_attempts = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--record" => {
+                    config.record_path = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--movetime" => {
+                    config.move_time = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--reconnect-delay" => {
+                    config.reconnect_delay = Duration::from_millis(cli::value(args, index)?);
+                    index += 2;
+                }
+                _ => { index += 1; }
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn join_message(&self) -> ClientMessage {
+        match &self.join {
+            JoinMode::Prepared(reservation) => ClientMessage::JoinPrepared { reservation: reservation.clone() },
+            JoinMode::Any(game_type) => ClientMessage::Join { game_type: game_type.clone() },
+        }
+    }
+}
+
+pub enum ConnectionState {
+    //Connected and sent the join request, waiting for <joined>
+    Joining,
+    Playing { room_id: String },
+    Finished { exit_code: i32 },
+    //The server closed the connection or it broke
+    Disconnected { joined: bool },
+}
+
+//Plays one game and returns the process exit code, see GameOutcome::exit_code
+pub fn go() -> i32 {
     let log = Logger::new("client_log.txt", false);
     log.log("Finished initializing!\n", false);
     let args: Vec<String> = env::args().collect();
     log.log(&format!("Arguments: {:?}\n", args), false);
-    let mut host = "localhost";
-    let mut port = 13050;
-    let mut reservation = "";
-    let mut index: usize = 0;
-    while index < args.len() {
-        match &args[index][..] {
-            "-r" | "--reservation" => {
-                reservation = &args[index + 1][..];
-                index += 2;
+    let config = ClientConfig::from_args(&args).unwrap_or_else(|e| {
+        log.log(&format!("Invalid arguments: {}\n", e), false);
+        cli::exit_with_usage(&e, "[-r reservation] [-h host] [-p port] [--game-type type] [--reconnect n] [--reconnect-delay ms] [--movetime ms] [--record file]")
+    });
+    run(&config, &log)
+}
+
+pub fn run(config: &ClientConfig, log: &Logger) -> i32 {
+    let mut search = Search::new(TimeControl::MoveTime(config.move_time));
+    let mut attempt = 0;
+    loop {
+        let state = match TcpStream::connect(format!("{}:{}", config.host, config.port)) {
+            Ok(stream) => play(stream, config, log, &mut search),
+            Err(e) => {
+                log.log(&format!("Could not connect to {}:{}: {}\n", config.host, config.port, e), true);
+                ConnectionState::Disconnected { joined: false }
             }
-            "-h" | "--host" => {
-                host = &args[index + 1][..];
-                index += 2;
+        };
+        match state {
+            ConnectionState::Finished { exit_code } => return exit_code,
+            ConnectionState::Disconnected { joined: false } if attempt < config.reconnect_attempts => {
+                attempt += 1;
+                log.log(&format!("Reconnecting in {}ms (attempt {} of {})\n", config.reconnect_delay.as_millis(), attempt, config.reconnect_attempts), true);
+                std::thread::sleep(config.reconnect_delay);
             }
-            "-p" | "--port" => {
-                port = args[index + 1].parse::<i64>().unwrap();
-                index += 2;
+            _ => {
+                log.log("Connection lost without a game result\n", true);
+                return EXIT_NO_RESULT;
             }
-            _ => { index += 1; }
         }
     }
-    let mut my_gamestate = GameState::standard();
+}
 
-    let mut stream = TcpStream::connect(&format!("{}:{}", host, port)).expect("Could not connect!");
-    write_to_stream(&mut stream, &log, "<protocol>");
-    write_to_stream(&mut stream, &log, &format!("<joinPrepared reservationCode=\"{}\"/>", reservation));
-    let mut current_parsing = String::new();
+pub fn play(mut stream: TcpStream, config: &ClientConfig, log: &Logger, search: &mut Search) -> ConnectionState {
+    let mut state = ConnectionState::Joining;
+    if write_to_stream(&mut stream, log, &ClientMessage::Protocol.to_xml()).is_err() || write_to_stream(&mut stream, log, &config.join_message().to_xml()).is_err() {
+        return ConnectionState::Disconnected { joined: false };
+    }
+    let mut tracker = GameTracker::new();
+    let mut reader = XmlReader::new();
+    let mut my_color: Option<GameColor> = None;
     loop {
         let mut buf = [0; 2048];
-        let bytes = stream.read(&mut buf).expect("Could not read from stream!");
-        let line = std::str::from_utf8(&buf[0..bytes]).expect("Could not convert to line");
-        current_parsing.push_str(line);
-        //log.log(&format!("Read from Stream:\n{}\n", line), false);
-        if line.contains("<data class=\"result\">") {
-            break;
+        let bytes = match stream.read(&mut buf) {
+            Ok(0) => {
+                log.log("Server closed the connection\n", true);
+                return disconnected(&state);
+            }
+            Ok(bytes) => bytes,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                log.log(&format!("Could not read from stream: {}\n", e), true);
+                return disconnected(&state);
+            }
+        };
+        if let Err(e) = reader.feed_bytes(&buf[0..bytes]) {
+            log.log(&format!("Invalid data from server: {:?}\n", e), true);
+            return disconnected(&state);
         }
-        while current_parsing.contains("<state") && current_parsing.contains("</state>") {
-            let x = current_parsing.clone();
-            let halves: Vec<&str> = x.split("</state>").collect::<Vec<&str>>();
-            current_parsing = String::from(halves[1]);
-            let first_state = halves[0];
-            let lines: Vec<&str> = first_state.split("\n").collect::<Vec<&str>>();
-            let mut rote_fische = 0u128;
-            let mut blaue_fische = 0u128;
-            let mut kraken = 0u128;
-            let mut plies_played = 0;
-            let mut rounds_played = 0;
-            let mut move_color = GameColor::Blue;
-            for line in lines {
-                //log.log(&format!("Parsing Line:\n{}\n", line), false);
-                if line.contains("<state") {
-                    let stripped = line.trim().replace("<state class=\"sc.plugin2019.GameState\" ", "").replace("startPlayerColor=\"RED\"", "").replace("\"", "").replace(">", "");
-                    let args: Vec<&str> = stripped.split(" ").collect::<Vec<&str>>();
-                    for arg in args {
-                        if arg.contains("turn") {
-                            plies_played = arg.split("=").collect::<Vec<&str>>()[1].parse::<u64>().unwrap();
-                            rounds_played = plies_played / 2;
-                        } else if arg.contains("currentPlayerColor") {
-                            let color = arg.split("=").collect::<Vec<&str>>()[1];
-                            if color == "RED" {
-                                move_color = GameColor::Red;
-                            } else if color == "BLUE" {
-                                move_color = GameColor::Blue;
-                            } else {
-                                panic!("invalid color!");
-                            }
+        loop {
+            let element = match reader.next_element() {
+                Ok(Some(element)) => element,
+                Ok(None) => break,
+                Err(e) => {
+                    log.log(&format!("Invalid XML from server: {:?}\n", e), true);
+                    return disconnected(&state);
+                }
+            };
+            let message = match ServerMessage::from_element(&element) {
+                Ok(message) => message,
+                Err(e) => {
+                    log.log(&format!("Could not parse <{}>: {}\n", element.name, e), false);
+                    continue;
+                }
+            };
+            let (id, data) = match message {
+                ServerMessage::Joined { room_id } => {
+                    log.log(&format!("Joined room {}\n", room_id), false);
+                    state = ConnectionState::Playing { room_id };
+                    continue;
+                }
+                ServerMessage::Left { room_id } => {
+                    if let ConnectionState::Playing { room_id: my_room } = &state {
+                        if *my_room == room_id {
+                            log.log(&format!("Left room {} without a result\n", room_id), true);
+                            return ConnectionState::Finished { exit_code: EXIT_NO_RESULT };
+                        }
+                    }
+                    log.log(&format!("Room {} was left\n", room_id), false);
+                    continue;
+                }
+                ServerMessage::Error { message } => {
+                    log.log(&format!("Protocol error: {}\n", message), true);
+                    continue;
+                }
+                ServerMessage::Other { name } => {
+                    log.log(&format!("Ignoring unknown message <{}>\n", name), false);
+                    continue;
+                }
+                ServerMessage::Room { room_id, data } => (room_id, data),
+            };
+            match &state {
+                ConnectionState::Playing { room_id } if *room_id != id => {
+                    log.log(&format!("Ignoring message for foreign room {}\n", id), false);
+                    continue;
+                }
+                ConnectionState::Joining => state = ConnectionState::Playing { room_id: id.clone() },
+                _ => {}
+            }
+            match data {
+                RoomData::Welcome { color } => {
+                    log.log(&format!("Playing as {}\n", color_name(color)), false);
+                    my_color = Some(color);
+                }
+                RoomData::Memento(state_message) => {
+                    let problems = tracker.update(&state_message);
+                    log.log("Succesfully read GameState!\n", false);
+                    if !problems.is_empty() {
+                        log.log(&format!("!!!!!!!!!! STATE DISCREPANCY IN TURN {} !!!!!!!!!!\n", state_message.turn), true);
+                        for problem in &problems {
+                            log.log(&format!("!!! {}\n", problem), true);
+                        }
+                        log.log("!!! Continuing from the server's state\n", true);
+                    }
+                    if let Some(game_state) = &tracker.game_state {
+                        log.log(&format!("FEN:\n{}\n", game_state.to_fen()), false);
+                    }
+                }
+                RoomData::MoveRequest => {
+                    log.log("Got a move request!\n", false);
+                    let game_state = match tracker.game_state.as_mut() {
+                        Some(game_state) => game_state,
+                        None => {
+                            log.log("Got a move request before any state, ignoring it\n", true);
+                            continue;
                         }
+                    };
+                    match send_move(&mut stream, log, search, game_state, &id) {
+                        Ok((mv, info)) => tracker.move_sent(mv, info),
+                        Err(_) => return disconnected(&state),
+                    }
+                }
+                RoomData::Result(result) => {
+                    log.log(&format!("Game over!\n{}", result.summary()), true);
+                    if tracker.discrepancies > 0 {
+                        log.log(&format!("!!! {} state discrepancies during this game, no game record written\n", tracker.discrepancies), true);
                     }
-                } else if line.contains("<field ") {
-                    let stripped = line.trim().replace("<field ", "").replace("\"", "").replace("/>", "");
-                    let args: Vec<&str> = stripped.split(" ").collect::<Vec<&str>>();
-                    let x = 9 - args[0].split("=").collect::<Vec<&str>>()[1].parse::<u64>().unwrap();
-                    let y = args[1].split("=").collect::<Vec<&str>>()[1].parse::<u64>().unwrap();
-                    let field_desc = args[2].split("=").collect::<Vec<&str>>()[1];
-                    let field_type =
-                        if field_desc == "EMPTY" {
-                            FieldState::Empty
-                        } else if field_desc == "RED" {
-                            FieldState::Red
-                        } else if field_desc == "BLUE" {
-                            FieldState::Blue
-                        } else if field_desc == "OBSTRUCTED" {
-                            FieldState::Obstructed
-                        } else {
-                            panic!("Invalid field desc!");
+                    if let Some(mut record) = tracker.record.take() {
+                        let status = match &result.winner {
+                            Some(winner) if winner.color == GameColor::Red => GameStatus::RedWin,
+                            Some(_) => GameStatus::BlueWin,
+                            None => GameStatus::Draw,
+                        };
+                        let termination = match result.scores.iter().find(|score| score.cause != ScoreCause::Regular) {
+                            Some(score) => score.cause.as_str(),
+                            None => "regular",
                         };
-                    let index = 10 * y + x;
-                    if let FieldState::Red = field_type {
-                        rote_fische |= 1u128 << index;
-                    } else if let FieldState::Blue = field_type {
-                        blaue_fische |= 1u128 << index;
-                    } else if let FieldState::Obstructed = field_type {
-                        kraken |= 1u128 << index;
+                        record.finish(status, termination);
+                        if let Err(e) = record.append_to(&config.record_path) {
+                            log.log(&format!("Could not write game record: {}\n", e), true);
+                        }
                     }
+                    let exit_code = match my_color {
+                        Some(color) => {
+                            let outcome = result.outcome(color);
+                            log.log(&format!("Outcome: {:?}\n", outcome), true);
+                            outcome.exit_code()
+                        }
+                        None => {
+                            log.log("Never got a welcome message, cannot tell who won\n", true);
+                            EXIT_NO_RESULT
+                        }
+                    };
+                    //Say goodbye, the server may already be gone
+                    let _ = write_to_stream(&mut stream, log, &ClientMessage::CloseProtocol.to_xml());
+                    return ConnectionState::Finished { exit_code };
                 }
-            }
-            let hash = GameState::calculate_hash(rote_fische, blaue_fische, kraken, &move_color);
-            my_gamestate = GameState::new(rote_fische, blaue_fische, kraken, plies_played as u8, rounds_played as u8, move_color, hash);
-            log.log("Succesfully read GameState!\n", false);
-            log.log(&format!("FEN:\n{}\n", my_gamestate.to_fen()), false);
-        }
-        if current_parsing.contains("MoveRequest") {
-            log.log("Got a move request!\n", false);
-            let x = current_parsing.clone();
-            let lines: Vec<&str> = x.split("\n").collect::<Vec<&str>>();
-            let mut id = String::new();
-            for line in lines {
-                //log.log(&format!("Parsing line:\n{}\n", line), false);
-                if line.contains("<room roomId=\"") {
-                    let x = line.trim().replace("\t", "").replace(" ", "").replace(">", "").replace("\"", "").replace("<roomroomId=", "");
-                    //log.log(&format!("\n{:?}\n", x), false);
-                    id = format!("{}", x);
+                RoomData::Error { message } => {
+                    log.log(&format!("Error in room {}: {}\n", id, message), true);
                 }
-            }
-            current_parsing.clear();
-            let before = Instant::now();
-            //Search
-            let result = search.run(100, &mut my_gamestate);
-            let duration = Instant::now().duration_since(before).as_millis();
-            let mv = result.stack[0];
-            let x = 9 - mv.from % 10;
-            let y = mv.from / 10;
-            let newx = 9 - mv.to % 10;
-            let newy = mv.to / 10;
-            let mut direction = "";
-            if newx > x {
-                if newy > y {
-                    direction = "UP_RIGHT";
-                } else if newy == y {
-                    direction = "RIGHT";
-                } else if newy < y {
-                    direction = "DOWN_RIGHT";
-                }
-            } else if newx == x {
-                if newy > y {
-                    direction = "UP";
-                } else if newy < y {
-                    direction = "DOWN";
-                } else {
-                    direction = "INVALID";
-                }
-            } else if newx < x {
-                if newy > y {
-                    direction = "UP_LEFT";
-                } else if newy == y {
-                    direction = "LEFT";
-                } else if newy < y {
-                    direction = "DOWN_LEFT";
+                RoomData::Other { class } => {
+                    log.log(&format!("Ignoring data of class {}\n", class), false);
                 }
             }
-            let inner_statement = &format!("\t<data class=\"move\" x=\"{}\" y=\"{}\" direction=\"{}\"/>", x, y, direction);
-            let statement = &format!("<room roomId=\"{}\">\n{}\n</room>", id, inner_statement);
-            write_to_stream(&mut stream, &log, statement);
-            log.log(&format!("Succesfully sent move after {}ms\n", duration), false);
-            log.log(&format!("Nodes analyzed: {}\n", search.nodes_analyzed), false);
-            log.log(&format!("Searched to depth: {}\n", result.depth), false);
-            log.log(&format!("Score: {}\n", result.score), false);
-            log.log("PV:\n", false);
-            for mv in result.stack {
-                log.log(&format!("{}\n", mv), false);
-            }
+        }
+        if reader.root_closed {
+            log.log("Server closed the protocol\n", true);
+            return disconnected(&state);
         }
 
         std::thread::sleep(Duration::from_millis(5));
     }
 }
 
-pub fn write_to_stream(stream: &mut TcpStream, log: &Logger, msg: &str) {
-    stream.write(msg.as_bytes()).expect("Could not write to stream!");
+fn disconnected(state: &ConnectionState) -> ConnectionState {
+    ConnectionState::Disconnected { joined: matches!(state, ConnectionState::Playing { .. }) }
+}
+
+pub fn send_move(stream: &mut TcpStream, log: &Logger, search: &mut Search, my_gamestate: &mut GameState, id: &str) -> io::Result<(ServerMove, MoveInfo)> {
+    let before = Instant::now();
+    //Search
+    let result = search.run(100, my_gamestate);
+    let duration = Instant::now().duration_since(before).as_millis();
+    let mv = result.stack[0];
+    let server_move = ServerMove::from_game_move(&mv);
+    let statement = &ClientMessage::Move { room_id: String::from(id), mv: server_move.clone() }.to_xml();
+    write_to_stream(stream, log, statement)?;
+    log.log(&format!("Succesfully sent move after {}ms\n", duration), false);
+    log.log(&format!("Nodes analyzed: {}\n", search.nodes_analyzed), false);
+    log.log(&format!("Searched to depth: {}\n", result.depth), false);
+    log.log(&format!("Score: {}\n", result.score), false);
+    log.log("PV:\n", false);
+    for mv in result.stack {
+        log.log(&format!("{}\n", mv), false);
+    }
+    let info = MoveInfo { score: Some(result.score), depth: Some(result.depth), time_ms: Some(duration as u64) };
+    Ok((server_move, info))
+}
+
+pub fn write_to_stream(stream: &mut TcpStream, log: &Logger, msg: &str) -> io::Result<()> {
+    if let Err(e) = stream.write_all(msg.as_bytes()) {
+        log.log(&format!("Could not write to stream: {}\n", e), true);
+        return Err(e);
+    }
     log.log(&format!("Wrote to stream:\n{}\n", msg), false);
-}
\ No newline at end of file
+    Ok(())
+}
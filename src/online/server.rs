@@ -0,0 +1,322 @@
+use crate::cli;
+use crate::logging::Logger;
+use crate::game_state::{GameState, GameColor, GameStatus, color_index};
+use crate::game_logic::{get_possible_moves, make_move};
+use std::io::{self, prelude::*};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+use super::xml::XmlReader;
+use super::messages::{ServerMessage, ClientMessage, RoomData, StateMessage, ServerMove, GameResult, Score, ScoreCause, Winner, color_name};
+
+//Stand-in for the Java game server: hosts games between two clients connecting over TCP
+
+pub struct ServerConfig {
+    pub port: u16,
+    pub games: usize,
+    //Time a client has to answer a move request before it loses by timeout
+    pub move_timeout: Duration,
+    //Reservation codes of red and blue, empty if the clients play in the order they join
+    pub reservations: Vec<String>,
+}
+
+impl ServerConfig {
+    pub fn from_args(args: &[String]) -> Result<ServerConfig, String> {
+        let mut config = ServerConfig {
+            port: 13050,
+            games: 1,
+            move_timeout: Duration::from_millis(2000),
+            reservations: Vec::new(),
+        };
+        let mut index: usize = 0;
+        while index < args.len() {
+            match &args[index][..] {
+                "-p" | "--port" => {
+                    config.port = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--games" => {
+                    config.games = cli::value(args, index)?;
+                    index += 2;
+                }
+                "--timeout" => {
+                    config.move_timeout = Duration::from_millis(cli::value(args, index)?);
+                    index += 2;
+                }
+                "--reservations" => {
+                    //--reservations <red code> <blue code>
+                    config.reservations = vec![cli::value(args, index)?, cli::value(args, index + 1)?];
+                    index += 3;
+                }
+                _ => { index += 1; }
+            }
+        }
+        Ok(config)
+    }
+}
+
+//Why a client could not deliver the message we waited for
+enum ClientFailure {
+    Timeout,
+    Closed,
+    Invalid(String),
+}
+
+struct Player {
+    stream: TcpStream,
+    reader: XmlReader,
+    name: String,
+}
+
+impl Player {
+    fn send(&mut self, msg: &str) -> io::Result<()> {
+        self.stream.write_all(msg.as_bytes())?;
+        self.stream.write_all(b"\n")
+    }
+
+    fn next_message(&mut self, deadline: Instant) -> Result<ClientMessage, ClientFailure> {
+        loop {
+            match self.reader.next_element() {
+                Ok(Some(element)) => return ClientMessage::from_element(&element).map_err(ClientFailure::Invalid),
+                Ok(None) => {}
+                Err(e) => return Err(ClientFailure::Invalid(format!("{:?}", e))),
+            }
+            if self.reader.root_closed {
+                return Err(ClientFailure::Closed);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(ClientFailure::Timeout);
+            }
+            self.stream.set_read_timeout(Some(deadline - now)).map_err(|_| ClientFailure::Closed)?;
+            let mut buf = [0; 2048];
+            match self.stream.read(&mut buf) {
+                Ok(0) => return Err(ClientFailure::Closed),
+                Ok(bytes) => self.reader.feed_bytes(&buf[0..bytes]).map_err(|e| ClientFailure::Invalid(format!("{:?}", e)))?,
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => return Err(ClientFailure::Timeout),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => return Err(ClientFailure::Closed),
+            }
+        }
+    }
+}
+
+//Hosts config.games games one after another and returns their results
+pub fn serve(config: &ServerConfig, log: &Logger) -> io::Result<Vec<GameResult>> {
+    let listener = TcpListener::bind(("127.0.0.1", config.port))?;
+    log.log(&format!("Listening on {}\n", listener.local_addr()?), true);
+    let mut results = Vec::with_capacity(config.games);
+    for game in 0..config.games {
+        let result = host_game(&listener, &format!("local-{}", game), &config.reservations, config.move_timeout, log)?;
+        log.log(&format!("Game {} over!\n{}", game + 1, result.summary()), true);
+        results.push(result);
+    }
+    Ok(results)
+}
+
+//Waits for two clients on listener. A client sending joinPrepared with the reservation code of a color
+//(reservations[0] red, reservations[1] blue) plays that color, any other client gets the first free color without a code
+pub fn host_game(listener: &TcpListener, room_id: &str, reservations: &[String], move_timeout: Duration, log: &Logger) -> io::Result<GameResult> {
+    let reserved = |slot: usize| reservations.get(slot).is_some_and(|code| !code.is_empty());
+    let mut slots: [Option<Player>; 2] = [None, None];
+    while slots.iter().any(|slot| slot.is_none()) {
+        let (stream, address) = listener.accept()?;
+        let mut player = Player { stream, reader: XmlReader::new(), name: String::new() };
+        let slot = match player.next_message(Instant::now() + move_timeout) {
+            Ok(ClientMessage::JoinPrepared { reservation }) if reservations.contains(&reservation) && !reservation.is_empty() => {
+                reservations.iter().position(|code| *code == reservation).filter(|slot| slots[*slot].is_none())
+            }
+            Ok(ClientMessage::Join { .. }) | Ok(ClientMessage::JoinPrepared { .. }) => (0..2).find(|slot| slots[*slot].is_none() && !reserved(*slot)),
+            _ => {
+                log.log(&format!("Connection from {} did not join, dropping it\n", address), true);
+                continue;
+            }
+        };
+        match slot {
+            Some(slot) => {
+                player.name = format!("Client {}", slot + 1);
+                log.log(&format!("{} joined from {}\n", player.name, address), false);
+                player.send("<protocol>")?;
+                player.send(&ServerMessage::Joined { room_id: String::from(room_id) }.to_xml())?;
+                slots[slot] = Some(player);
+            }
+            None => log.log(&format!("No free seat for the connection from {}, dropping it\n", address), true),
+        }
+    }
+    let [red, blue] = slots;
+    let mut players = [red.unwrap(), blue.unwrap()];
+    for (player, color) in players.iter_mut().zip([GameColor::Red, GameColor::Blue].iter()) {
+        player.send(&room(room_id, RoomData::Welcome { color: *color }))?;
+    }
+
+    let mut game_state = GameState::standard();
+    let mut last_move: Option<ServerMove> = None;
+    let mut violation: Option<(GameColor, ScoreCause, String)> = None;
+    loop {
+        let moves = get_possible_moves(&game_state, &game_state.move_color, false);
+        game_state.analyze(&moves);
+        let memento = room(room_id, RoomData::Memento(StateMessage::from_game_state(&game_state, last_move.clone(), &players[0].name, &players[1].name)));
+        for player in players.iter_mut() {
+            player.send(&memento)?;
+        }
+        if game_state.game_over() {
+            break;
+        }
+        let color = game_state.move_color;
+        let player = &mut players[color_index(&color)];
+        player.send(&room(room_id, RoomData::MoveRequest))?;
+        let answer = player.next_message(Instant::now() + move_timeout);
+        let mv = match answer {
+            Ok(ClientMessage::Move { room_id: id, mv }) if id == room_id => mv,
+            Ok(_) => {
+                violation = Some((color, ScoreCause::RuleViolation, String::from("Unerwartete Nachricht statt eines Zuges")));
+                break;
+            }
+            Err(ClientFailure::Timeout) => {
+                violation = Some((color, ScoreCause::HardTimeout, format!("Der Spieler hat innerhalb von {} Millisekunden nach Aufforderung keinen Zug gesendet", move_timeout.as_millis())));
+                break;
+            }
+            Err(ClientFailure::Closed) => {
+                violation = Some((color, ScoreCause::Left, String::from("Der Spieler hat das Spiel verlassen")));
+                break;
+            }
+            Err(ClientFailure::Invalid(e)) => {
+                violation = Some((color, ScoreCause::RuleViolation, format!("Ungültige Nachricht: {}", e)));
+                break;
+            }
+        };
+        match mv.to_game_move(&game_state) {
+            Some(game_move) => {
+                log.log(&format!("{} plays {}\n", color_name(color), game_move), false);
+                game_state = make_move(&game_state, &game_move);
+                last_move = Some(mv);
+            }
+            None => {
+                violation = Some((color, ScoreCause::RuleViolation, format!("Ungültiger Zug {:?}", mv)));
+                break;
+            }
+        }
+    }
+
+    let result = game_result(&game_state, violation, &players[0].name, &players[1].name);
+    let result_message = room(room_id, RoomData::Result(result.clone()));
+    for player in players.iter_mut() {
+        //A client that already left cannot be told anymore
+        let _ = player.send(&result_message);
+        let _ = player.send(&ServerMessage::Left { room_id: String::from(room_id) }.to_xml());
+        let _ = player.send("</protocol>");
+    }
+    Ok(result)
+}
+
+fn room(room_id: &str, data: RoomData) -> String {
+    ServerMessage::Room { room_id: String::from(room_id), data }.to_xml()
+}
+
+//Scores the finished game like the Java server: win points, then the size of the biggest swarm
+fn game_result(game_state: &GameState, violation: Option<(GameColor, ScoreCause, String)>, red_name: &str, blue_name: &str) -> GameResult {
+    let winner_color = match &violation {
+        Some((GameColor::Red, _, _)) => Some(GameColor::Blue),
+        Some((GameColor::Blue, _, _)) => Some(GameColor::Red),
+        None => match game_state.game_status {
+            Some(GameStatus::RedWin) => Some(GameColor::Red),
+            Some(GameStatus::BlueWin) => Some(GameColor::Blue),
+            _ => None,
+        },
+    };
+    let mut scores = Vec::with_capacity(2);
+    for color in [GameColor::Red, GameColor::Blue].iter() {
+        let siegpunkte = match winner_color {
+            Some(winner) if winner == *color => 2.0,
+            Some(_) => 0.0,
+            None => 1.0,
+        };
+        let (cause, reason) = match &violation {
+            Some((loser, cause, reason)) if loser == color => (*cause, reason.clone()),
+            _ => (ScoreCause::Regular, String::new()),
+        };
+        scores.push(Score { cause, reason, parts: vec![siegpunkte, game_state.biggest_schwarm(color) as f64] });
+    }
+    GameResult {
+        fragments: vec![String::from("Siegpunkte"), String::from("Schwarmgröße")],
+        scores,
+        winner: winner_color.map(|color| Winner {
+            display_name: String::from(if color == GameColor::Red { red_name } else { blue_name }),
+            color,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::online::protocol::{self, ClientConfig};
+    use crate::game_record::GameRecord;
+
+    //Plays a full game between two online clients with a short search time over localhost
+    #[test]
+    fn local_server_game() -> Result<(), String> {
+        let listener = TcpListener::bind("127.0.0.1:0").map_err(|e| e.to_string())?;
+        let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+        let dir = std::env::temp_dir();
+        //The reservation codes decide the colors, client 0 plays red
+        let reservations = vec![String::from("test-red"), String::from("test-blue")];
+        let codes = reservations.clone();
+        let server_log = Logger::quiet(dir.join("server_test_server_log.txt").to_str().unwrap());
+        let server = std::thread::spawn(move || host_game(&listener, "test", &reservations, Duration::from_millis(2000), &server_log));
+        let mut clients = Vec::new();
+        let record_path = dir.join("server_test_client_games.txt");
+        let _ = std::fs::remove_file(&record_path);
+        for (i, code) in codes.iter().enumerate() {
+            let log_path = dir.join(format!("server_test_client_{}_log.txt", i));
+            let args: Vec<String> = vec![String::from("-r"), code.clone(), String::from("-p"), port.to_string(), String::from("--movetime"), String::from("20"), String::from("--record"), String::from(record_path.to_str().unwrap())];
+            let config = ClientConfig::from_args(&args)?;
+            //The search recurses deeply, so give it the stack of a main thread
+            let client = std::thread::Builder::new().stack_size(32 * 1024 * 1024).spawn(move || {
+                let log = Logger::quiet(log_path.to_str().unwrap());
+                protocol::run(&config, &log)
+            }).map_err(|e| e.to_string())?;
+            clients.push(client);
+        }
+        let exit_codes: Vec<i32> = clients.into_iter().map(|c| c.join().unwrap_or(-1)).collect();
+        let result = server.join().map_err(|_| String::from("Server panicked"))?.map_err(|e| e.to_string())?;
+        if result.scores.iter().any(|score| score.cause != ScoreCause::Regular) {
+            return Err(format!("Game did not end regularly:\n{}", result.summary()));
+        }
+        let expected: Vec<i32> = [GameColor::Red, GameColor::Blue].iter().map(|color| result.outcome(*color).exit_code()).collect();
+        if exit_codes != expected {
+            return Err(format!("Clients exited with {:?} but expected {:?} for\n{}", exit_codes, expected, result.summary()));
+        }
+        //Both clients append their record of the game
+        let records = GameRecord::parse_all(&std::fs::read_to_string(&record_path).map_err(|e| e.to_string())?)?;
+        if records.len() != 2 || records[0].kraken != records[1].kraken || records[0].moves.iter().map(|m| m.mv).ne(records[1].moves.iter().map(|m| m.mv)) {
+            return Err(format!("Expected two records of the same game, got {}", records.len()));
+        }
+        for record in &records {
+            record.replay()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn local_server_rejects_malformed_move() -> Result<(), String> {
+        let listener = TcpListener::bind("127.0.0.1:0").map_err(|e| e.to_string())?;
+        let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+        let reservations = vec![String::from("test-red"), String::from("test-blue")];
+        let codes = reservations.clone();
+        let server_log = Logger::quiet(std::env::temp_dir().join("server_test_malformed_server_log.txt").to_str().unwrap());
+        let server = std::thread::spawn(move || host_game(&listener, "test", &reservations, Duration::from_millis(2000), &server_log));
+        //Raw clients: red answers its move request with a square that does not exist, blue only joins
+        let mut streams = Vec::new();
+        for (code, rest) in codes.iter().zip([r#"<room roomId="test"><data class="move" x="10" y="0" direction="UP"/></room>"#, ""].iter()) {
+            let mut stream = TcpStream::connect(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+            let xml = format!("<protocol>{}{}", ClientMessage::JoinPrepared { reservation: code.clone() }.to_xml(), rest);
+            stream.write_all(xml.as_bytes()).map_err(|e| e.to_string())?;
+            streams.push(stream);
+        }
+        let result = server.join().map_err(|_| String::from("Server panicked"))?.map_err(|e| e.to_string())?;
+        if result.scores[0].cause != ScoreCause::RuleViolation || result.winner.as_ref().map(|winner| winner.color) != Some(GameColor::Blue) {
+            return Err(format!("Red was not disqualified:\n{}", result.summary()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use crate::game_state::GameState;
+use crate::game_logic::make_move;
+use crate::game_record::{GameRecord, MoveInfo};
+use crate::bitboard::Bitboard;
+use super::messages::{StateMessage, ServerMove, color_name};
+
+//Follows the game by applying moves to our own GameState and compares it to every state the server sends
+pub struct GameTracker {
+    pub game_state: Option<GameState>,
+    pub sent_move: Option<(ServerMove, MoveInfo)>,
+    pub discrepancies: usize,
+    //Record of the game, only kept if we saw it from the start and never lost track of it
+    pub record: Option<GameRecord>,
+}
+
+impl Default for GameTracker {
+    fn default() -> Self {
+        GameTracker::new()
+    }
+}
+
+impl GameTracker {
+    pub fn new() -> GameTracker {
+        GameTracker { game_state: None, sent_move: None, discrepancies: 0, record: None }
+    }
+
+    pub fn move_sent(&mut self, mv: ServerMove, info: MoveInfo) {
+        self.sent_move = Some((mv, info));
+    }
+
+    //Advances the tracked game by the server's last move and returns a description of every difference found.
+    //The server's state always wins, so after a discrepancy we continue from its position
+    pub fn update(&mut self, message: &StateMessage) -> Vec<String> {
+        let server_state = message.to_game_state();
+        let mut problems = Vec::new();
+        let mut info = MoveInfo::default();
+        if self.game_state.is_none() && message.turn == 0 {
+            self.record = Some(GameRecord::new(message.kraken, message.red_name.as_deref().unwrap_or("?"), message.blue_name.as_deref().unwrap_or("?")));
+        }
+        let tracked = match (self.game_state.take(), &message.last_move) {
+            (Some(previous), Some(last_move)) => {
+                if previous.move_color != message.current_player {
+                    if let Some((sent, sent_info)) = &self.sent_move {
+                        if sent != last_move {
+                            problems.push(format!("We sent {:?}, but the server reports {:?} as last move", sent, last_move));
+                        } else {
+                            info = *sent_info;
+                        }
+                    }
+                }
+                match last_move.to_game_move(&previous) {
+                    Some(mv) => {
+                        if let Some(record) = &mut self.record {
+                            record.push(mv, info);
+                        }
+                        Some(make_move(&previous, &mv))
+                    }
+                    None => {
+                        problems.push(format!("Last move {:?} is not legal in our position {}", last_move, previous.to_fen()));
+                        None
+                    }
+                }
+            }
+            (Some(previous), None) => {
+                if previous.plies_played != message.turn {
+                    problems.push(format!("Server state of turn {} has no last move, we are at ply {}", message.turn, previous.plies_played));
+                }
+                Some(previous)
+            }
+            (None, _) => None,
+        };
+        if let Some(tracked) = &tracked {
+            problems.append(&mut compare(tracked, &server_state));
+        }
+        self.sent_move = None;
+        self.discrepancies += problems.len();
+        if !problems.is_empty() {
+            self.record = None;
+        }
+        self.game_state = Some(if problems.is_empty() { tracked.unwrap_or(server_state) } else { server_state });
+        problems
+    }
+}
+
+fn describe_squares(board: u128) -> String {
+    let res: Vec<String> = Bitboard(board).iter().map(|square| format!("{} [square {}]", square, square.index())).collect();
+    res.join(", ")
+}
+
+pub fn compare(tracked: &GameState, server: &GameState) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (name, ours, theirs) in [("Red fish", tracked.rote_fische, server.rote_fische), ("Blue fish", tracked.blaue_fische, server.blaue_fische), ("Kraken", tracked.kraken, server.kraken)].iter() {
+        if ours != theirs {
+            problems.push(format!("{} differ. Only we have: {}. Only the server has: {}", name, describe_squares(ours & !theirs), describe_squares(theirs & !ours)));
+        }
+    }
+    if tracked.move_color != server.move_color {
+        problems.push(format!("We expect {} to move, the server says {}", color_name(tracked.move_color), color_name(server.move_color)));
+    }
+    if tracked.plies_played != server.plies_played || tracked.rounds_played != server.rounds_played {
+        problems.push(format!("We are at ply {} round {}, the server at ply {} round {}", tracked.plies_played, tracked.rounds_played, server.plies_played, server.rounds_played));
+    }
+    if tracked.hash != server.hash {
+        problems.push(format!("Hash {} differs from the server's {}", tracked.hash, server.hash));
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::online::messages::{self, ServerMessage, RoomData, SERVER_TRANSCRIPT};
+
+    fn transcript_states() -> Result<Vec<messages::StateMessage>, String> {
+        let mut res = Vec::new();
+        for message in messages::parse_transcript(SERVER_TRANSCRIPT, ServerMessage::from_element)? {
+            if let ServerMessage::Room { data: RoomData::Memento(state), .. } = message {
+                res.push(state);
+            }
+        }
+        Ok(res)
+    }
+
+    #[test]
+    fn tracking_follows_transcript() -> Result<(), String> {
+        let states = transcript_states()?;
+        let mut tracker = GameTracker::new();
+        for state in &states {
+            let problems = tracker.update(state);
+            if !problems.is_empty() {
+                return Err(format!("Turn {}: {:?}", state.turn, problems));
+            }
+        }
+        //A move with mirrored x coordinate has to be reported
+        let mut tracker = GameTracker::new();
+        tracker.update(&states[0]);
+        let mut mirrored = states[1].clone();
+        if let Some(mv) = &mut mirrored.last_move {
+            mv.x = 9 - mv.x;
+        }
+        if tracker.update(&mirrored).is_empty() {
+            return Err(String::from("A mirrored last move went unnoticed"));
+        }
+        Ok(())
+    }
+}
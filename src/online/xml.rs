@@ -0,0 +1,274 @@
+//Minimal streaming XML reader for the Software-Challenge protocol.
+//The server never closes the outer <protocol> tag while the game runs, so every element directly below it is a message.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    pub fn new(name: &str) -> Element {
+        Element {
+            name: String::from(name),
+            attributes: Vec::new(),
+            children: Vec::new(),
+            text: String::new(),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| &value[..])
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    pub fn with_attribute(mut self, name: &str, value: &str) -> Element {
+        self.attributes.push((String::from(name), String::from(value)));
+        self
+    }
+
+    pub fn with_child(mut self, child: Element) -> Element {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_text(mut self, text: &str) -> Element {
+        self.text = String::from(text);
+        self
+    }
+
+    //Serializes the element indented by two spaces per level, like the server does
+    pub fn to_xml(&self) -> String {
+        let mut res = String::new();
+        self.write_xml(&mut res, 0);
+        res
+    }
+
+    fn write_xml(&self, res: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        res.push_str(&indent);
+        res.push('<');
+        res.push_str(&self.name);
+        for (key, value) in &self.attributes {
+            res.push_str(&format!(" {}=\"{}\"", key, escape(value)));
+        }
+        if self.children.is_empty() && self.text.is_empty() {
+            res.push_str("/>");
+        } else if self.children.is_empty() {
+            res.push_str(&format!(">{}</{}>", escape(&self.text), self.name));
+        } else {
+            res.push_str(">\n");
+            if !self.text.is_empty() {
+                res.push_str(&format!("{}  {}\n", indent, escape(&self.text)));
+            }
+            for child in &self.children {
+                child.write_xml(res, depth + 1);
+                res.push('\n');
+            }
+            res.push_str(&format!("{}</{}>", indent, self.name));
+        }
+    }
+
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item=&'a Element> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum XmlError {
+    UnexpectedClosingTag(String),
+    Malformed(String),
+}
+
+pub struct XmlReader {
+    buffer: String,
+    //Trailing bytes of an incomplete UTF-8 sequence from the last read
+    pending: Vec<u8>,
+    //Elements that were opened but not yet closed, outermost first
+    open: Vec<Element>,
+    //Name of the enclosing root element (<protocol>), once seen
+    pub root: Option<String>,
+    pub root_closed: bool,
+}
+
+impl Default for XmlReader {
+    fn default() -> Self {
+        XmlReader::new()
+    }
+}
+
+impl XmlReader {
+    pub fn new() -> XmlReader {
+        XmlReader {
+            buffer: String::new(),
+            pending: Vec::new(),
+            open: Vec::new(),
+            root: None,
+            root_closed: false,
+        }
+    }
+
+    pub fn feed(&mut self, data: &str) {
+        self.buffer.push_str(data);
+    }
+
+    //Like feed, but for raw reads which may end in the middle of a character
+    pub fn feed_bytes(&mut self, data: &[u8]) -> Result<(), XmlError> {
+        self.pending.extend_from_slice(data);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(e) => {
+                if e.error_len().is_some() {
+                    return Err(XmlError::Malformed(String::from("Invalid UTF-8")));
+                }
+                e.valid_up_to()
+            }
+        };
+        let rest = self.pending.split_off(valid);
+        self.buffer.push_str(std::str::from_utf8(&self.pending).unwrap());
+        self.pending = rest;
+        Ok(())
+    }
+
+    //Returns the next complete element below the root, or None if more input is needed
+    pub fn next_element(&mut self) -> Result<Option<Element>, XmlError> {
+        loop {
+            let start = match self.buffer.find('<') {
+                Some(start) => start,
+                None => return Ok(None),
+            };
+            self.take_text(start);
+            let end = match tag_end(&self.buffer) {
+                Some(end) => end,
+                None => return Ok(None),
+            };
+            let tag: String = self.buffer[1..end].to_string();
+            self.buffer.drain(..end + 1);
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue;
+            }
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                match self.open.pop() {
+                    Some(element) => {
+                        if element.name != name {
+                            return Err(XmlError::UnexpectedClosingTag(String::from(name)));
+                        }
+                        if let Some(done) = self.close(element) {
+                            return Ok(Some(done));
+                        }
+                    }
+                    None => {
+                        if self.root.as_deref() == Some(name) {
+                            self.root_closed = true;
+                            continue;
+                        }
+                        return Err(XmlError::UnexpectedClosingTag(String::from(name)));
+                    }
+                }
+                continue;
+            }
+            let self_closing = tag.ends_with('/');
+            let element = parse_tag(if self_closing { &tag[..tag.len() - 1] } else { &tag[..] })?;
+            if self.root.is_none() && self.open.is_empty() && !self_closing {
+                self.root = Some(element.name);
+                continue;
+            }
+            if self_closing {
+                if let Some(done) = self.close(element) {
+                    return Ok(Some(done));
+                }
+            } else {
+                self.open.push(element);
+            }
+        }
+    }
+
+    //Attaches a finished element to its parent, or hands it out if it is a message
+    fn close(&mut self, element: Element) -> Option<Element> {
+        match self.open.last_mut() {
+            Some(parent) => {
+                parent.children.push(element);
+                None
+            }
+            None => Some(element),
+        }
+    }
+
+    fn take_text(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let text: String = self.buffer.drain(..len).collect();
+        if let Some(current) = self.open.last_mut() {
+            let trimmed = text.trim();
+            if !trimmed.is_empty() {
+                current.text.push_str(&unescape(trimmed));
+            }
+        }
+    }
+}
+
+//Position of the '>' ending the tag at the start of buffer, ignoring '>' inside quoted attribute values
+fn tag_end(buffer: &str) -> Option<usize> {
+    if buffer.starts_with("<!--") {
+        return buffer.find("-->").map(|i| i + 2);
+    }
+    let mut quote: Option<char> = None;
+    for (i, c) in buffer.char_indices() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+            None => {
+                if c == '"' || c == '\'' {
+                    quote = Some(c);
+                } else if c == '>' {
+                    return Some(i);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn parse_tag(tag: &str) -> Result<Element, XmlError> {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let mut element = Element::new(&tag[..name_end]);
+    if element.name.is_empty() {
+        return Err(XmlError::Malformed(String::from(tag)));
+    }
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let eq = rest.find('=').ok_or_else(|| XmlError::Malformed(String::from(tag)))?;
+        let key = rest[..eq].trim();
+        let after = rest[eq + 1..].trim_start();
+        let quote = after.chars().next().ok_or_else(|| XmlError::Malformed(String::from(tag)))?;
+        if quote != '"' && quote != '\'' {
+            return Err(XmlError::Malformed(String::from(tag)));
+        }
+        let value_end = after[1..].find(quote).ok_or_else(|| XmlError::Malformed(String::from(tag)))? + 1;
+        element.attributes.push((String::from(key), unescape(&after[1..value_end])));
+        rest = after[value_end + 1..].trim_start();
+    }
+    Ok(element)
+}
+
+pub fn unescape(text: &str) -> String {
+    if !text.contains('&') {
+        return String::from(text);
+    }
+    text.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&apos;", "'").replace("&amp;", "&")
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
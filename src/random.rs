@@ -0,0 +1,32 @@
+use std::sync::{Mutex, OnceLock};
+
+extern crate rand;
+
+use rand::{Rng, SeedableRng};
+use rand::distributions::uniform::SampleUniform;
+use rand::rngs::StdRng;
+
+//Engine-wide random numbers. Everything random goes through this generator, so a run
+//started with --seed <n> draws the same numbers again
+static SEED: OnceLock<u64> = OnceLock::new();
+static RNG: Mutex<Option<StdRng>> = Mutex::new(None);
+
+//Fixes the seed. Returns false if random numbers were already drawn with another seed
+pub fn set_seed(seed: u64) -> bool {
+    SEED.set(seed).is_ok()
+}
+
+//The seed of this run, chosen at random on first use if none was set
+pub fn seed() -> u64 {
+    *SEED.get_or_init(rand::random)
+}
+
+pub fn with_rng<T, F: FnOnce(&mut StdRng) -> T>(f: F) -> T {
+    let mut rng = RNG.lock().unwrap();
+    f(rng.get_or_insert_with(|| StdRng::seed_from_u64(seed())))
+}
+
+//Uniform in [low, high)
+pub fn gen_range<T: SampleUniform>(low: T, high: T) -> T {
+    with_rng(|rng| rng.gen_range(low, high))
+}
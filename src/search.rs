@@ -1,18 +1,23 @@
-use super::game_state::{GameMove, GameState, GameStatus};
-use super::game_logic::{get_possible_moves, make_move, get_schwarm, make_null_move};
-use super::board_rating::{Schwarm, rating};
+use super::game_state::{GameMove, GameState, GameStatus, MAX_PLIES};
+use super::game_logic::{get_possible_moves, make_move, make_null_move};
+use super::board_rating::rating;
 use std::time::Instant;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use crate::game_state::GameColor;
-use crate::game_logic::get_schwarm_board;
 use crate::constants;
+use crate::nnue;
+use crate::book;
+use crate::endgame::{self, Solver};
 
 
-pub const CACHE_MASK: i64 = 16 * 2097152 - 1;
+pub const CACHE_MASK: u64 = 16 * 2097152 - 1;
+pub const EVAL_CACHE_MASK: u64 = 1048576 - 1;
 
 
 pub struct PrincipialVariation {
     pub stack: Vec<GameMove>,
-    pub hash_stack: Vec<i64>,
+    pub hash_stack: Vec<u64>,
     pub score: f64,
     pub depth: usize,
 }
@@ -47,7 +52,7 @@ impl TimeControl {
 
 #[derive(Copy, Clone)]
 pub struct CacheEntry {
-    pub hash: i64,
+    pub hash: u64,
     pub score: f64,
     pub plies_played: u8,
     pub depth: u8,
@@ -58,7 +63,7 @@ pub struct CacheEntry {
 }
 
 impl CacheEntry {
-    pub fn new(hash: i64, score: f64, plies_played: u8, depth: u8, gm: GameMove, pv_node: bool, beta_node: bool, alpha_node: bool) -> CacheEntry {
+    pub fn new(hash: u64, score: f64, plies_played: u8, depth: u8, gm: GameMove, pv_node: bool, beta_node: bool, alpha_node: bool) -> CacheEntry {
         CacheEntry {
             hash,
             score,
@@ -70,12 +75,51 @@ impl CacheEntry {
             alpha_node,
         }
     }
+
+    //The search below this entry reached the end of the game, so its score only holds at the same ply
+    pub fn reaches_horizon(&self) -> bool {
+        self.plies_played + self.depth >= MAX_PLIES
+    }
+
+    pub fn usable_at(&self, game_state: &GameState, depth_left: u8) -> bool {
+        if self.depth < depth_left {
+            return false;
+        }
+        if self.reaches_horizon() || depth_left >= game_state.plies_left() {
+            return self.plies_played == game_state.plies_played;
+        }
+        true
+    }
+}
+
+//Static evaluation of a leaf, from red's point of view. The evaluation depends on the game phase,
+//so the ply is part of the key
+#[derive(Copy, Clone)]
+pub struct EvalEntry {
+    pub hash: u64,
+    pub plies_played: u8,
+    pub score: f64,
 }
 
 pub struct Search {
     pub stop: bool,
     pub tc: TimeControl,
+    //Stop after this many nodes
+    pub max_nodes: Option<usize>,
+    //Set from another thread to abort the search
+    pub stop_signal: Option<Arc<AtomicBool>>,
+    //Print an info line to stdout after every iteration
+    pub print_info: bool,
+    //Play moves from the opening book (see book.rs) without searching, if one is loaded
+    pub use_book: bool,
+    //Hand positions near the end of the game to the exact solver (see endgame.rs)
+    pub use_solver: bool,
+    pub solver: Solver,
+    //Depth of the last finished iteration. Limits are only checked once depth 1 is done, so there always is a move
+    pub completed_depth: u8,
     pub cache: Vec<Option<CacheEntry>>,
+    pub cache_mask: u64,
+    pub eval_cache: Vec<Option<EvalEntry>>,
     pub killer_moves: [[Option<GameMove>; 3]; 100],
     pub hh_score: [[usize; 100]; 100],
     pub bf_score: [[usize; 100]; 100],
@@ -87,7 +131,16 @@ impl Search {
         Search {
             stop: false,
             tc,
+            max_nodes: None,
+            stop_signal: None,
+            print_info: false,
+            use_book: true,
+            use_solver: true,
+            solver: Solver::new(),
+            completed_depth: 0,
             cache: vec![None; 16 * 2097152],
+            cache_mask: CACHE_MASK,
+            eval_cache: vec![None; 1048576],
             killer_moves: [[None; 3]; 100],
             hh_score: [[8; 100]; 100],
             bf_score: [[8; 100]; 100],
@@ -95,9 +148,52 @@ impl Search {
         }
     }
 
+    //Resizes the transposition table to the biggest power of two of entries fitting into megabytes
+    pub fn resize_cache(&mut self, megabytes: usize) {
+        let entries = (megabytes * 1024 * 1024 / std::mem::size_of::<Option<CacheEntry>>()).max(1);
+        let entries = if entries.is_power_of_two() { entries } else { entries.next_power_of_two() / 2 };
+        self.cache = vec![None; entries];
+        self.cache_mask = entries as u64 - 1;
+    }
+
+    pub fn clear(&mut self) {
+        for entry in self.cache.iter_mut() {
+            *entry = None;
+        }
+        for entry in self.eval_cache.iter_mut() {
+            *entry = None;
+        }
+    }
+
+    pub fn limits_reached(&self, start_time: &Instant) -> bool {
+        if self.completed_depth == 0 {
+            return false;
+        }
+        if let Some(signal) = &self.stop_signal {
+            if signal.load(Ordering::Relaxed) {
+                return true;
+            }
+        }
+        if let Some(max_nodes) = self.max_nodes {
+            if self.nodes_analyzed >= max_nodes {
+                return true;
+            }
+        }
+        checkup(start_time, &self.tc)
+    }
+
     pub fn run(&mut self, depth: u8, game_state: &mut GameState) -> PrincipialVariation {
         //Reset killers and trim history scores
         self.nodes_analyzed = 0;
+        self.completed_depth = 0;
+        if let Some(mv) = book::book().filter(|_| self.use_book).and_then(|b| b.choose(game_state)) {
+            let mut pv = PrincipialVariation::new(1);
+            pv.stack.push(mv);
+            pv.hash_stack.push(game_state.hash);
+            pv.score = 0.0;
+            pv.depth = 0;
+            return pv;
+        }
         self.killer_moves = [[None; 3]; 100];
         for i in 0..100 {
             for j in 0..100 {
@@ -105,8 +201,36 @@ impl Search {
                 self.bf_score[i][j] /= 8;
             }
         }
+        if nnue::network().is_some() && game_state.accumulator.is_none() {
+            game_state.refresh_accumulator();
+        }
+        if game_state.schwaerme.is_none() {
+            game_state.refresh_schwaerme();
+        }
         let mut best_pv: Option<PrincipialVariation> = None;
         let time = Instant::now();
+        if self.use_solver && Solver::applies(game_state) {
+            //The solver gets half of the time, if it cannot prove the result the normal search takes the rest
+            let deadline = match self.tc {
+                TimeControl::MoveTime(move_time) => Some(time + std::time::Duration::from_millis(move_time / 2)),
+                TimeControl::Infinite => None,
+            };
+            let max_nodes = self.max_nodes.unwrap_or(endgame::SOLVER_NODES);
+            if let Some(solution) = self.solver.solve(game_state, max_nodes, deadline, self.stop_signal.as_deref()) {
+                self.nodes_analyzed = self.solver.nodes;
+                if self.print_info {
+                    let moves: Vec<String> = solution.line.iter().map(|mv| format!("{}-{}", mv.from, mv.to)).collect();
+                    println!("info string solved: {}", solution.outcome);
+                    println!("info depth {} score {} nodes {} time {} pv {}", solution.line.len(), solution.score(), self.nodes_analyzed, time.elapsed().as_millis(), moves.join(" "));
+                }
+                let mut pv = PrincipialVariation::new(solution.line.len());
+                pv.score = solution.score();
+                pv.depth = solution.line.len();
+                pv.stack = solution.line;
+                pv.hash_stack = solution.hashes;
+                return pv;
+            }
+        }
         for d in 1..depth + 1 {
             let pv = alpha_beta(self, -100000.0, 100000.0, game_state, d, 0, if let GameColor::Red = game_state.move_color { 1 } else { -1 }, &time);
             if self.stop {
@@ -115,7 +239,7 @@ impl Search {
             //Delete current best pv out of tt
             if let Some(last_pv) = best_pv {
                 for i in 0..last_pv.stack.len() {
-                    let mut entry = self.cache[(last_pv.hash_stack[i] & CACHE_MASK) as usize];
+                    let mut entry = self.cache[(last_pv.hash_stack[i] & self.cache_mask) as usize];
                     if let Some(content) = &mut entry {
                         content.pv_node = false;
                     } else {
@@ -131,17 +255,29 @@ impl Search {
             }*/
             //Place pv in tt
             for i in 0..pv.stack.len() {
-                self.cache[(pv.hash_stack[i] & CACHE_MASK) as usize] = Some(CacheEntry::new(pv.hash_stack[i], pv.score * if (i % 2) == 0 { 1.0 } else { -1.0 }, game_state.plies_played + 1, d - i as u8, pv.stack[i].clone(), true, false, false));
+                self.cache[(pv.hash_stack[i] & self.cache_mask) as usize] = Some(CacheEntry::new(pv.hash_stack[i], pv.score * if (i % 2) == 0 { 1.0 } else { -1.0 }, game_state.plies_played + i as u8, d - i as u8, pv.stack[i].clone(), true, false, false));
+            }
+            self.completed_depth = d;
+            if self.print_info {
+                let moves: Vec<String> = pv.stack.iter().map(|mv| format!("{}-{}", mv.from, mv.to)).collect();
+                println!("info depth {} score {} nodes {} time {} pv {}", d, pv.score, self.nodes_analyzed, time.elapsed().as_millis(), moves.join(" "));
             }
             best_pv = Some(pv);
+            //Every line already reaches the end of the game, deeper iterations cannot change anything
+            if d >= game_state.plies_left() {
+                break;
+            }
         }
         best_pv.unwrap()
     }
 }
 
 pub fn alpha_beta(search: &mut Search, mut alpha: f64, mut beta: f64, game_state: &mut GameState, mut depth_left: u8, current_depth: u8, maximizing_player: isize, start_time: &Instant) -> PrincipialVariation {
-    if (search.nodes_analyzed % 1024) == 0 {
-        search.stop = checkup(&start_time, &search.tc)
+    //The node limit is exact, the clock and the stop signal are only looked at every 1024 nodes
+    if search.completed_depth > 0 && search.max_nodes.is_some_and(|max_nodes| search.nodes_analyzed >= max_nodes) {
+        search.stop = true;
+    } else if (search.nodes_analyzed % 1024) == 0 {
+        search.stop = search.limits_reached(start_time)
     }
     let original_alpha = alpha;
     let mut curr_pv = PrincipialVariation::new(depth_left as usize);
@@ -155,10 +291,17 @@ pub fn alpha_beta(search: &mut Search, mut alpha: f64, mut beta: f64, game_state
     if depth_left == 0 && match game_state.move_color {
         GameColor::Red => false,
         GameColor::Blue => true
-    } && is_one_fish_missing(game_state.blaue_fische) {
+    } && game_state.is_one_fish_missing(&GameColor::Blue) {
         depth_left += 1;
     }
 
+    //Nothing happens after the last round. The last ply of the game is always searched, the final rule
+    //in analyze then scores it exactly instead of the evaluation
+    if depth_left == 0 && game_state.plies_left() == 1 {
+        depth_left = 1;
+    }
+    depth_left = depth_left.min(game_state.plies_left());
+
     //Use early-return, if we are in d0 nodes, as we only need to know if there is one legal move
     let mut move_list = get_possible_moves(&game_state, &game_state.move_color, depth_left == 0);
     game_state.analyze(&move_list);
@@ -168,97 +311,87 @@ pub fn alpha_beta(search: &mut Search, mut alpha: f64, mut beta: f64, game_state
             curr_pv.score = 0.0;
             return curr_pv;
         } else if let Some(GameStatus::RedWin) = game_state.game_status {
-            let diff = (Schwarm::berechne_schwaerme(game_state.rote_fische).1).size as isize - (Schwarm::berechne_schwaerme(game_state.blaue_fische).1).size as isize;
+            let diff = game_state.biggest_schwarm(&GameColor::Red) as isize - game_state.biggest_schwarm(&GameColor::Blue) as isize;
             curr_pv.score = maximizing_player as f64 * (30000.0 - game_state.plies_played as f64 + diff as f64 / 100.0);
             return curr_pv;
         } else if let Some(GameStatus::BlueWin) = game_state.game_status {
-            let diff = (Schwarm::berechne_schwaerme(game_state.blaue_fische).1).size as isize - (Schwarm::berechne_schwaerme(game_state.rote_fische).1).size as isize;
+            let diff = game_state.biggest_schwarm(&GameColor::Blue) as isize - game_state.biggest_schwarm(&GameColor::Red) as isize;
             curr_pv.score = maximizing_player as f64 * (-30000.0 + game_state.plies_played as f64 - diff as f64 / 100.0);
             return curr_pv;
         } else {
             panic!("Invalid game over situation");
         }
     }
+    //Proven by the endgame solver, e.g. during the search of an earlier move
+    if current_depth > 0 && search.use_solver {
+        if let Some(value) = search.solver.probe(game_state) {
+            curr_pv.score = endgame::value_to_score(value);
+            return curr_pv;
+        }
+    }
     //Probe TB
     let mut move_ordering_index = 0;
     let mut id_pv_move_found = false;
     {
-        let ce: Option<CacheEntry> = search.cache[(game_state.hash & CACHE_MASK) as usize];
+        let ce: Option<CacheEntry> = search.cache[(game_state.hash & search.cache_mask) as usize];
         if let Some(content) = ce {
-            if content.hash == game_state.hash {
-                if depth_left == 0 {
-                    if content.depth == 0 {
+            if content.hash == game_state.hash && depth_left > 0 {
+                //Cache-Hit
+                if content.usable_at(game_state, depth_left) {
+                    if !content.beta_node && !content.alpha_node {
+                        curr_pv.stack.push(content.gm.clone());
+                        curr_pv.hash_stack.push(content.hash);
                         curr_pv.score = content.score;
                         return curr_pv;
-                    }
-                } else {
-                    //Cache-Hit
-                    if content.depth >= depth_left && !(game_state.plies_played + depth_left >= 60 && content.plies_played + content.depth < 60) {
-                        if !content.beta_node && !content.alpha_node {
-                            curr_pv.stack.push(content.gm.clone());
-                            curr_pv.hash_stack.push(content.hash);
-                            curr_pv.score = content.score;
-                            return curr_pv;
-                        } else {
-                            if content.beta_node {
-                                if content.score > alpha {
-                                    alpha = content.score;
-                                }
-                            } else if content.alpha_node {
-                                if content.score < beta {
-                                    beta = content.score;
-                                }
+                    } else {
+                        if content.beta_node {
+                            if content.score > alpha {
+                                alpha = content.score;
                             }
-                        }
-                        if alpha >= beta {
-                            curr_pv.score = alpha;
-                            curr_pv.stack.push(content.gm.clone());
-                            curr_pv.hash_stack.push(content.hash);
-                            return curr_pv;
-                        }
-                    }
-                    if content.depth != 0 {
-                        //Move ordering
-                        move_ordering_index = 1;
-                        let mut index = 256;
-                        for (i, mv) in move_list.iter().enumerate() {
-                            if mv.from == content.gm.from && mv.to == content.gm.to {
-                                index = i;
-                                break;
+                        } else if content.alpha_node {
+                            if content.score < beta {
+                                beta = content.score;
                             }
                         }
-
-                        id_pv_move_found = content.pv_node;
-                        let mv0 = move_list[0];
-                        move_list[0] = move_list[index];
-                        move_list[index] = mv0;
+                    }
+                    if alpha >= beta {
+                        curr_pv.score = alpha;
+                        curr_pv.stack.push(content.gm.clone());
+                        curr_pv.hash_stack.push(content.hash);
+                        return curr_pv;
                     }
                 }
+                //Move ordering
+                move_ordering_index = 1;
+                let mut index = 256;
+                for (i, mv) in move_list.iter().enumerate() {
+                    if mv.from == content.gm.from && mv.to == content.gm.to {
+                        index = i;
+                        break;
+                    }
+                }
+
+                id_pv_move_found = content.pv_node;
+                let mv0 = move_list[0];
+                move_list[0] = move_list[index];
+                move_list[index] = mv0;
             }
         }
     }
 
     //Search ends
     if depth_left == 0 {
-        curr_pv.score = rating(&game_state, false) * maximizing_player as f64;
-        let content = search.cache[(game_state.hash & CACHE_MASK) as usize];
-        if let None = content {
-            let new_entry = CacheEntry::new(game_state.hash, curr_pv.score, 0, 0, GameMove::new(101, 101), false, false, false);
-            search.cache[(game_state.hash & CACHE_MASK) as usize] = Some(new_entry);
-        } else if content.unwrap().depth == 0 {
-            let new_entry = CacheEntry::new(game_state.hash, curr_pv.score, 0, 0, GameMove::new(101, 101), false, false, false);
-            search.cache[(game_state.hash & CACHE_MASK) as usize] = Some(new_entry);
-        }
+        curr_pv.score = static_eval(search, game_state) * maximizing_player as f64;
         return curr_pv;
     }
 
     let not_in_check = match game_state.move_color {
         GameColor::Red => true,
         GameColor::Blue => false
-    } || get_schwarm(&game_state, &GameColor::Red) < game_state.rote_fische.count_ones() as u8;
+    } || !game_state.is_connected(&GameColor::Red);
 
     //Null Move Pruning
-    if !id_pv_move_found && depth_left > 3 && current_depth > 0 && depth_left + game_state.plies_played < 60 && not_in_check {
+    if !id_pv_move_found && depth_left > 3 && current_depth > 0 && depth_left < game_state.plies_left() && not_in_check {
         let rat = -alpha_beta(search, -beta, -beta + 0.0001, &mut make_null_move(&game_state), depth_left - 3, current_depth + 1, -maximizing_player, &start_time).score;
         if rat >= beta {
             curr_pv.score = rat;
@@ -416,7 +549,7 @@ pub fn alpha_beta(search: &mut Search, mut alpha: f64, mut beta: f64, game_state
         let beta_node = curr_pv.score >= beta;
         let alpha_node = curr_pv.score <= original_alpha;
 
-        let cache_index = (game_state.hash & CACHE_MASK) as usize;
+        let cache_index = (game_state.hash & search.cache_mask) as usize;
         if let None = search.cache[cache_index] {
             search.cache[cache_index] = Some(CacheEntry::new(game_state.hash, curr_pv.score, game_state.plies_played as u8, depth_left, curr_pv.stack[0].clone(), false, beta_node, alpha_node));
         } else if let Some(other) = search.cache[cache_index] {
@@ -428,6 +561,21 @@ pub fn alpha_beta(search: &mut Search, mut alpha: f64, mut beta: f64, game_state
     curr_pv
 }
 
+pub fn static_eval(search: &mut Search, game_state: &GameState) -> f64 {
+    let index = (game_state.hash & EVAL_CACHE_MASK) as usize;
+    if let Some(entry) = search.eval_cache[index] {
+        if entry.hash == game_state.hash && entry.plies_played == game_state.plies_played {
+            return entry.score;
+        }
+    }
+    let score = match nnue::network() {
+        Some(net) => net.evaluate(game_state),
+        None => rating(game_state, false),
+    };
+    search.eval_cache[index] = Some(EvalEntry { hash: game_state.hash, plies_played: game_state.plies_played, score });
+    score
+}
+
 pub fn is_in_heuristics(mv: &GameMove, search: &mut Search, depth: u8) -> bool {
     if let Some(other) = search.killer_moves[depth as usize][0] {
         if other == *mv {
@@ -453,13 +601,6 @@ pub fn checkup(start_time: &Instant, tc: &TimeControl) -> bool {
     tc.time_over(dur as u64)
 }
 
-pub fn is_one_fish_missing(fische: u128) -> bool {
-    let fisch_anz = fische.count_ones() as usize;
-    let board_one = get_schwarm_board(fische);
-    let board_fische = board_one.count_ones() as usize;
-    board_fische >= fisch_anz - 1 || (board_fische == 1 && get_schwarm_board(fische & !board_one).count_ones() as usize == fisch_anz - 1)
-}
-
 pub fn sort_next_move(to_position: usize, from_array: &mut Vec<GameMove>, ratings_array: &mut Vec<f64>) {
     let mut max_index = to_position;
     let mut max_val = ratings_array[max_index];
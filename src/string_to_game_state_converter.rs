@@ -1,4 +1,5 @@
 use super::game_state;
+use super::bitboard::Square;
 
 #[allow(dead_code)]
 pub const STANDARD_GAME_STATE: [[&str; 10]; 10] = [
@@ -34,13 +35,13 @@ pub fn string_to_game_state(arr: [[&str; 10]; 10], plies_played: u8, rounds_play
     for y in 0..10 {
         for x in 0..10 {
             let s: String = arr[y][x].to_lowercase();
-            let shift = 99 - (y * 10 + x);
+            let square = Square::from_grid(y as u8, x as u8).bitboard().0;
             if s == "b" {
-                blaue_fische |= 1u128 << shift;
+                blaue_fische |= square;
             } else if s == "r" {
-                rote_fische |= 1u128 << shift;
+                rote_fische |= square;
             } else if s == "k" {
-                kraken |= 1u128 << shift;
+                kraken |= square;
             }
         }
     }
@@ -0,0 +1,104 @@
+use crate::game_state::{GameState, GameColor};
+use crate::bitboard::{Bitboard, Square};
+
+//The seven non-trivial symmetries of the square board. Squares are indexed as 10 * y + x
+#[derive(Copy, Clone, Debug)]
+pub enum Symmetry {
+    MirrorX,
+    MirrorY,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    Diagonal,
+    AntiDiagonal,
+}
+
+pub const SYMMETRIES: [Symmetry; 7] = [Symmetry::MirrorX, Symmetry::MirrorY, Symmetry::Rotate90, Symmetry::Rotate180, Symmetry::Rotate270, Symmetry::Diagonal, Symmetry::AntiDiagonal];
+
+impl Symmetry {
+    pub fn apply_square(self, square: Square) -> Square {
+        let (x, y) = (square.x(), square.y());
+        let (new_x, new_y) = match self {
+            Symmetry::MirrorX => (9 - x, y),
+            Symmetry::MirrorY => (x, 9 - y),
+            Symmetry::Rotate90 => (9 - y, x),
+            Symmetry::Rotate180 => (9 - x, 9 - y),
+            Symmetry::Rotate270 => (y, 9 - x),
+            Symmetry::Diagonal => (y, x),
+            Symmetry::AntiDiagonal => (9 - y, 9 - x),
+        };
+        Square::from_coordinates(new_x, new_y)
+    }
+
+    pub fn apply_board(self, board: u128) -> u128 {
+        Bitboard(board).iter().fold(Bitboard::EMPTY, |res, square| res | self.apply_square(square).bitboard()).0
+    }
+}
+
+//Applies symmetry (if any) and optionally swaps the colors of all fish and of the side to move
+pub fn transform(game_state: &GameState, symmetry: Option<Symmetry>, swap_colors: bool) -> GameState {
+    let board = |b: u128| match symmetry {
+        Some(s) => s.apply_board(b),
+        None => b,
+    };
+    let (rote_fische, blaue_fische) = if swap_colors {
+        (board(game_state.blaue_fische), board(game_state.rote_fische))
+    } else {
+        (board(game_state.rote_fische), board(game_state.blaue_fische))
+    };
+    let kraken = board(game_state.kraken);
+    let move_color = match (&game_state.move_color, swap_colors) {
+        (GameColor::Red, false) | (GameColor::Blue, true) => GameColor::Red,
+        _ => GameColor::Blue,
+    };
+    let hash = GameState::calculate_hash(rote_fische, blaue_fische, kraken, &move_color);
+    GameState::new(rote_fische, blaue_fische, kraken, game_state.plies_played, game_state.rounds_played, move_color, hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{self, random_positions};
+    use crate::game_state::GameStatus;
+    use crate::board_rating;
+
+    fn swapped_status(status: &GameStatus) -> GameStatus {
+        match status {
+            GameStatus::Ingame => GameStatus::Ingame,
+            GameStatus::Draw => GameStatus::Draw,
+            GameStatus::RedWin => GameStatus::BlueWin,
+            GameStatus::BlueWin => GameStatus::RedWin,
+        }
+    }
+
+    #[test]
+    fn evaluation_is_symmetric() -> Result<(), String> {
+        for game_state in random_positions(200) {
+            if board_rating::biggest_schwarm_is_ambiguous(game_state.rote_fische) || board_rating::biggest_schwarm_is_ambiguous(game_state.blaue_fische) {
+                continue;
+            }
+            let score = board_rating::rating(&game_state, false);
+            let status = game_state.game_status.as_ref().unwrap();
+            let mut transformations: Vec<(Option<Symmetry>, bool)> = vec![(None, true)];
+            for s in SYMMETRIES.iter() {
+                transformations.push((Some(*s), false));
+                transformations.push((Some(*s), true));
+            }
+            for (sym, swap_colors) in transformations {
+                let mut other = transform(&game_state, sym, swap_colors);
+                let other_score = board_rating::rating(&other, false);
+                let expected_score = if swap_colors { -score } else { score };
+                if (other_score - expected_score).abs() > 1e-9 * (1.0 + score.abs()) {
+                    return Err(format!("{:?} swap={} of {}: rating {} but expected {}", sym, swap_colors, game_state.to_fen(), other_score, expected_score));
+                }
+                let moves = game_logic::get_possible_moves(&other, &other.move_color, false);
+                other.analyze(&moves);
+                let expected_status = if swap_colors { swapped_status(status) } else { *status };
+                if other.game_status.as_ref() != Some(&expected_status) {
+                    return Err(format!("{:?} swap={} of {}: status {:?} but expected {:?}", sym, swap_colors, game_state.to_fen(), other.game_status, expected_status));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,64 @@
-pub const ZOBRIST_KEYS: [[[i64; 3]; 10]; 10] = [[[-6875583225211644615i64, -2202106748614934540i64, -7042440635863519155i64, ], [8470596899187324521i64, -740173592436623872i64, 590536988490030818i64, ], [-2942484810037183560i64, 987682146830119997i64, -2850647773573273875i64, ], [-1823989907638853299i64, -1556073387379844643i64, -4756586346455455012i64, ], [-4304822051711296151i64, 7288222974914477277i64, 2090340953659918128i64, ], [7409552122530235169i64, -3763521322531205043i64, 2178489520740104366i64, ], [-5041579042929140580i64, -5152231468712339385i64, -8044152093904506262i64, ], [607066933218768929i64, 1633298695718428870i64, -7507048742997654489i64, ], [8542699751189039313i64, 1506366936177625618i64, 1471331598248268528i64, ], [186789512460090979i64, -5688359830143040836i64, -1005180902728154110i64, ], ], [[-2836966867612610961i64, -1874661786439035989i64, -562965675127561601i64, ], [3385058548501540691i64, -6075978881544037680i64, 6793453061459388376i64, ], [4275889488622379657i64, -2999859668038094441i64, -7355386990194501659i64, ], [-1609752943451402984i64, 605203787047660853i64, -5232092240177483553i64, ], [-3014199997053863073i64, -224762059707193662i64, 6450289348193462653i64, ], [3792657257148938920i64, -5689139048885384643i64, -3954070161407325372i64, ], [-3388209666120096570i64, -8939028392526228585i64, -2747722528225623736i64, ], [6669227474045278398i64, 2407678429276268167i64, 6949425089956474057i64, ], [2597656294819225586i64, 4958500098439293337i64, -2262455694080458926i64, ], [-8755059203490044303i64, 3212403147908037413i64, 834398463112062938i64, ], ], [[2773331725168480439i64, -4343313607895295692i64, -2466163608607601238i64, ], [1650650933448334884i64, -3036818733504791709i64, -5309372330678429743i64, ], [4263670707373453168i64, -8439596397081691280i64, -7947083104499396033i64, ], [6287315903551599078i64, 2492766787872809572i64, -5582649885216772588i64, ], [-429038508715803761i64, -7951624588070814036i64, 5455796173132482755i64, ], [-436728648532530578i64, 5107779596166608050i64, 4198286061295274688i64, ], [8282788486165018139i64, 5106140042286009921i64, 3426454064584018602i64, ], [8620321407669788771i64, -3591938570293252602i64, -6611064293044496472i64, ], [8145817275904606661i64, -4182669998545181890i64, -8954216371985285965i64, ], [304833166258971498i64, -3430606314802797095i64, -3229369154036455755i64, ], ], [[-3038560128114114067i64, -6859018520955724004i64, -6568757603782894107i64, ], [-5265306960932821670i64, -3987994783883709563i64, -1691331169559083563i64, ], [-1259712139834546674i64, -2710132894983841113i64, -4152403404818036642i64, ], [-1702691796549837915i64, -4262123304793538012i64, -9070457321100883561i64, ], [3996557472289973738i64, 422568575124321383i64, 7435854122605567024i64, ], [-6804550765669697394i64, 3483498628082865732i64, 6631758393551653513i64, ], [-2851167635939471128i64, 7385898688337362976i64, 738114045788623545i64, ], [7245693325792587923i64, 4714952466837879432i64, -4232406503260750432i64, ], [-4423915146522020136i64, -3035342515024539831i64, -4598475483175003506i64, ], [-1067426541425079909i64, 2903856199630351771i64, 2996092614030467103i64, ], ], [[-8553791645721506461i64, -519104876534290320i64, 897387798278675120i64, ], [-4274247719400903966i64, -8157612148325810753i64, -4845746004416227755i64, ], [-6102130722410014015i64, 7661873625396834570i64, -3024512273803153075i64, ], [-9037673572486464161i64, -4568273564484992508i64, -7472455006276895769i64, ], [3924044098478529801i64, 8727537724113235434i64, -5720859355800770593i64, ], [7425116830346060784i64, 2292496858830517396i64, -5423357575583736129i64, ], [1622441500308030112i64, -8949080294715151105i64, -1454545891001659227i64, ], [2353617203801419657i64, 4144509952303880016i64, -2576832979653161554i64, ], [-2662311003563887477i64, -2144888672331237330i64, -4090406826028210477i64, ], [1275820315265285116i64, 8491268019901187225i64, 1690670653713868238i64, ], ], [[6928900194211549500i64, 2287613242692591555i64, 5214148027121215398i64, ], [-72642833661328735i64, 2815298210019646935i64, 2511490237190724209i64, ], [4337864779543168632i64, -2748981446203931134i64, -3329508665390786643i64, ], [-780766008516612808i64, -1306481462497200619i64, 5660763956367307684i64, ], [-6026100241647430079i64, 4873837198135215469i64, 2926213903920430293i64, ], [-1529924855946113479i64, -1747538977676040962i64, 106275572846109963i64, ], [6598697860096783337i64, 8630927786597907911i64, 6797411647391810056i64, ], [-3837232257070637348i64, -1468102829535559552i64, -4651142758279355606i64, ], [-1435188860356665552i64, -775167774893428391i64, 7298129069072769364i64, ], [-7231079327775352577i64, -3384138114659796362i64, -3791293578554558591i64, ], ], [[-7327751119943435257i64, -360366083865877991i64, 5021683055829897240i64, ], [-1308001811905950333i64, -4330540715568161734i64, 6544102604156836191i64, ], [-7080148465774761988i64, 7196357442158337824i64, -8443508239365765083i64, ], [3803589002243871520i64, 4513867951628741624i64, 5219204391592869436i64, ], [-8158031401617720976i64, -5886796111212738362i64, -8414231592291542321i64, ], [7995682919701747378i64, 7978126159178105305i64, -4164214084672660646i64, ], [8960822612772037901i64, -8188716355047524151i64, -6852451993546491451i64, ], [-6478561395650423591i64, -2054897608020908700i64, 8001636598324328829i64, ], [6420148829996976586i64, 7773544307604731173i64, -5661733077341080163i64, ], [-4509904026415644392i64, -5764474380788744603i64, -5859539084762638154i64, ], ], [[8361770188674906723i64, 4409649462846136557i64, 4418935270357880014i64, ], [1428787866099494171i64, -3326811629929761913i64, -978646361633833370i64, ], [5964138600777965792i64, 6385538880221239408i64, 8162855780374120170i64, ], [-3944446869016999745i64, 843451028283391386i64, 3381072102918104915i64, ], [126258697899433212i64, -1506092988979491678i64, -1487454930958227429i64, ], [-5111243883504960857i64, -1480646925854253088i64, 379117400958961652i64, ], [-4013918476990313516i64, -8111739193329514084i64, -8068487656543543456i64, ], [-5157209637642946145i64, 328323878440108576i64, -5782710157916858106i64, ], [2228525451043808914i64, 356942925189968607i64, 8610564605768183869i64, ], [-2767336473018231839i64, 3346129512601973785i64, -8540127017959697064i64, ], ], [[-8840096822531480141i64, -7398624187775736873i64, 2431946242233000864i64, ], [448715453858939002i64, -1870576705028515570i64, -3790877104091274645i64, ], [-1538867799886372543i64, -6517108718214381473i64, -6914055606710617986i64, ], [-2237700013400480897i64, 373128089093097426i64, -1835277871180798809i64, ], [-923621990317002794i64, -73215393016396541i64, 9215976186878873934i64, ], [1812025048446697117i64, -8727654975393269886i64, 3271909179056271585i64, ], [2625299447962940379i64, -5677915722796202522i64, -7532703757718776997i64, ], [5870837916455420518i64, 789989965261101093i64, -217051869478711730i64, ], [-5418965391008309249i64, -6403041953720818126i64, -4142571843028316308i64, ], [-1787874040788054807i64, -1700048793766760204i64, -6446052200055147726i64, ], ], [[-62345682198917873i64, 5023251379149445237i64, -2845565419174255127i64, ], [2220738374667029606i64, 6965173662406093345i64, 8023982818932261862i64, ], [9019675627328631442i64, -2899842775537877444i64, -872094574525096717i64, ], [1860635164306628589i64, 8423525855719168445i64, -2785592252203724933i64, ], [-4078660278328377616i64, 2073929463351098165i64, -8241684036879371125i64, ], [1511030416508616634i64, -1149020330912710811i64, -8120777099321512162i64, ], [-7304254241747638230i64, -63694418613107300i64, -1625296954850973046i64, ], [-2695631090013655797i64, -8245609376544996993i64, -7150791946644278581i64, ], [8185884190149354124i64, -9135245420740749233i64, -5222968767566656756i64, ], [-8353652676663534141i64, 4940546323428078475i64, -3804856142803885224i64, ], ], ];
+//Zobrist keys per square (10 * y + x): red fish, blue fish, kraken.
+//They are generated at compile time with splitmix64 from a fixed seed, so every build hashes the same way
+//and opening books stay valid across builds
+pub const SEED: u64 = 0x5357_434c_2019_0601;
 
-pub const SIDE_TO_MOVE_IS_BLUE: i64 = -7445440108512177531i64;
\ No newline at end of file
+const KEYS: ([[u64; 3]; 100], u64) = generate_keys(SEED);
+pub const ZOBRIST_KEYS: [[u64; 3]; 100] = KEYS.0;
+pub const SIDE_TO_MOVE_IS_BLUE: u64 = KEYS.1;
+
+//Returns the advanced state and the next output
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (state, z ^ (z >> 31))
+}
+
+const fn generate_keys(seed: u64) -> ([[u64; 3]; 100], u64) {
+    let mut keys = [[0u64; 3]; 100];
+    let mut state = seed;
+    let mut square = 0;
+    while square < 100 {
+        let mut plane = 0;
+        while plane < 3 {
+            let (next_state, key) = splitmix64(state);
+            state = next_state;
+            keys[square][plane] = key;
+            plane += 1;
+        }
+        square += 1;
+    }
+    let (_, side_to_move) = splitmix64(state);
+    (keys, side_to_move)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{self, random_positions};
+    use crate::game_state::GameState;
+    use std::collections::HashSet;
+
+    #[test]
+    fn hashes_are_consistent() -> Result<(), String> {
+        let mut keys: Vec<u64> = ZOBRIST_KEYS.iter().flat_map(|square| square.iter().cloned()).collect();
+        keys.push(SIDE_TO_MOVE_IS_BLUE);
+        let distinct: HashSet<u64> = keys.iter().cloned().collect();
+        if distinct.len() != keys.len() || distinct.contains(&0u64) {
+            return Err(String::from("Zobrist keys are not distinct and non-zero"));
+        }
+        let from_scratch = |game_state: &GameState| GameState::calculate_hash(game_state.rote_fische, game_state.blaue_fische, game_state.kraken, &game_state.move_color);
+        for game_state in random_positions(50) {
+            if game_state.hash != from_scratch(&game_state) {
+                return Err(format!("Incremental hash {} differs from calculate_hash {} in {}", game_state.hash, from_scratch(&game_state), game_state.to_fen()));
+            }
+            let null_move = game_logic::make_null_move(&game_state);
+            if null_move.hash != from_scratch(&null_move) {
+                return Err(format!("Null move hash differs from calculate_hash in {}", game_state.to_fen()));
+            }
+        }
+        Ok(())
+    }
+}